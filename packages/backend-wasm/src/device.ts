@@ -18,11 +18,12 @@ import type {
   WasmTensor,
   WasmDType,
   WasmOperation,
+  WasmRoundingMode,
   WASMCapabilities,
   WASMMemoryStats,
   WASMLoadOptions,
 } from './types';
-import { OPS, DTYPES } from './types';
+import { OPS, DTYPES, ROUNDING_MODES } from './types';
 import {
   WASMBoundsError,
   WASMAllocationError,
@@ -35,6 +36,7 @@ export class WASMDevice implements Device {
   readonly id = 'wasm:0';
 
   private executor: WasmExecutor | null = null;
+  private wasmModule: typeof import('../wasm/pkg/typetensor_wasm.js') | null = null;
   private initialized = false;
   private useCustomPatternCache?: { maxPatterns: number; maxMemoryMB: number };
 
@@ -92,6 +94,7 @@ export class WASMDevice implements Device {
       // Import and initialize WASM module
       const wasmModule = await import('../wasm/pkg/typetensor_wasm.js');
       await wasmModule.default(); // Initialize the WASM module
+      this.wasmModule = wasmModule;
 
       // Create WasmExecutor with optional pattern cache settings
       if (this.useCustomPatternCache) {
@@ -138,6 +141,20 @@ export class WASMDevice implements Device {
       return wasmTensorData.wasmTensor;
     });
 
+    // Shape-only view operations share the input's storage (no output allocation)
+    if (!output && this.isZeroCopyViewOperation(op.__op) && inputs.length === 1) {
+      try {
+        const view = this.executor!.execute_view(
+          this.mapOperation(op.__op),
+          wasmInputs[0]!,
+          new Uint32Array(op.__output.__shape),
+        );
+        return createWASMTensorData(this, view);
+      } catch (error) {
+        throw new Error(`WASM operation failed: ${error}`);
+      }
+    }
+
     // Allocate output tensor if not provided
     const outputTensor = output
       ? (output as WASMTensorData).wasmTensor
@@ -148,12 +165,18 @@ export class WASMDevice implements Device {
 
     try {
       // Dispatch based on operation category
-      if (this.isUnaryOperation(op.__op) && inputs.length === 1) {
-        // Unary operations (neg, abs, sin, cos, exp, log, sqrt, square)
+      if (this.isRoundingOperation(op.__op) && inputs.length === 1) {
+        // Rounding (floor, ceil, round) of floats into core's integer dtype
+        this.executeRoundingOperation(wasmOp, wasmInputs[0]!, outputTensor, op);
+      } else if (this.isUnaryOperation(op.__op) && inputs.length === 1) {
+        // Unary operations (math, activations, rounding, logical_not)
         this.executor!.execute_unary(wasmOp, wasmInputs[0]!, outputTensor);
       } else if (this.isBinaryOperation(op.__op) && inputs.length === 2) {
-        // Binary operations (add, sub, mul, div)
+        // Binary operations (arithmetic, pow/maximum/minimum/mod, comparisons, logical and/or/xor)
         this.executor!.execute_binary(wasmOp, wasmInputs[0]!, wasmInputs[1]!, outputTensor);
+      } else if (this.isSelectOperation(op.__op) && inputs.length === 3) {
+        // Where: condition, value if true, value if false (broadcast together)
+        this.executor!.execute_where(wasmInputs[0]!, wasmInputs[1]!, wasmInputs[2]!, outputTensor);
       } else if (op.__op === 'matmul' && inputs.length === 2) {
         // Matrix multiplication
         this.executor!.execute_matmul(wasmInputs[0]!, wasmInputs[1]!, outputTensor);
@@ -397,13 +420,9 @@ export class WASMDevice implements Device {
    * Check if device supports non-contiguous tensors
    */
   supportsNonContiguous(op: AnyStorageTransformation['__op']): boolean {
-    // WASM backend currently assumes C-contiguous data for most operations
-    // Operations that don't properly handle stride-based memory access need contiguous data
-
-    // Operations that properly handle non-contiguous data via strides
+    // Element-wise, reduction, softmax and matmul kernels read inputs through
+    // their strides and offset (contiguous fast path, strided gather otherwise)
     const strideAwareOps = new Set([
-      // Only simple unary operations that process elements linearly
-      // These work because they just transform each element in place
       'neg',
       'abs',
       'sin',
@@ -412,10 +431,53 @@ export class WASMDevice implements Device {
       'log',
       'sqrt',
       'square',
+      'tan',
+      'tanh',
+      'sigmoid',
+      'relu',
+      'leaky_relu',
+      'gelu',
+      'gelu_tanh',
+      'silu',
+      'erf',
+      'reciprocal',
+      'rsqrt',
+      'floor',
+      'ceil',
+      'round',
+      'sign',
+      'log1p',
+      'expm1',
+      'add',
+      'sub',
+      'mul',
+      'div',
+      'pow',
+      'maximum',
+      'minimum',
+      'mod',
+      'eq',
+      'ne',
+      'lt',
+      'le',
+      'gt',
+      'ge',
+      'logical_and',
+      'logical_or',
+      'logical_xor',
+      'logical_not',
+      'where',
+      'sum',
+      'mean',
+      'max',
+      'min',
+      'prod',
+      'softmax',
+      'log_softmax',
+      'matmul',
     ]);
 
-    // For all other operations, require contiguous data
-    // This includes: tile, expand, slice, transpose, binary ops, reductions, etc.
+    // Other operations are copied to contiguous data first
     return strideAwareOps.has(op);
   }
 
@@ -535,14 +597,73 @@ export class WASMDevice implements Device {
    * Check if operation is a unary operation
    */
   private isUnaryOperation(op: string): boolean {
-    return ['neg', 'abs', 'sin', 'cos', 'exp', 'log', 'sqrt', 'square'].includes(op);
+    return [
+      'neg',
+      'abs',
+      'sin',
+      'cos',
+      'exp',
+      'log',
+      'sqrt',
+      'square',
+      'tan',
+      'tanh',
+      'sigmoid',
+      'relu',
+      'leaky_relu',
+      'gelu',
+      'gelu_tanh',
+      'silu',
+      'erf',
+      'reciprocal',
+      'rsqrt',
+      'floor',
+      'ceil',
+      'round',
+      'sign',
+      'log1p',
+      'expm1',
+      'logical_not',
+    ].includes(op);
+  }
+
+  /**
+   * Check if operation is a rounding operation
+   */
+  private isRoundingOperation(op: string): boolean {
+    return ['floor', 'ceil', 'round'].includes(op);
   }
 
   /**
    * Check if operation is a binary operation
    */
   private isBinaryOperation(op: string): boolean {
-    return ['add', 'sub', 'mul', 'div'].includes(op);
+    return [
+      'add',
+      'sub',
+      'mul',
+      'div',
+      'eq',
+      'ne',
+      'lt',
+      'le',
+      'gt',
+      'ge',
+      'logical_and',
+      'logical_or',
+      'logical_xor',
+      'pow',
+      'maximum',
+      'minimum',
+      'mod',
+    ].includes(op);
+  }
+
+  /**
+   * Check if operation is a where/select operation
+   */
+  private isSelectOperation(op: string): boolean {
+    return op === 'where';
   }
 
   /**
@@ -563,6 +684,13 @@ export class WASMDevice implements Device {
     ].includes(op);
   }
 
+  /**
+   * Check if view operation only changes shape/strides (executed without copying)
+   */
+  private isZeroCopyViewOperation(op: string): boolean {
+    return ['reshape', 'view', 'flatten', 'squeeze', 'unsqueeze', 'expand'].includes(op);
+  }
+
   /**
    * Check if operation is a reduction operation
    */
@@ -586,56 +714,35 @@ export class WASMDevice implements Device {
     output: WasmTensor,
     op?: AnyStorageTransformation,
   ): void {
-    // Check if this is a slice operation and we have operation metadata
-    if (wasmOp === OPS.slice && op?.__output && '__sliceIndices' in op.__output) {
-      // Extract slice indices from operation metadata
-      const sliceIndices = (op.__output as any).__sliceIndices;
-
-      if (Array.isArray(sliceIndices) && sliceIndices.length > 0) {
-        // Extract start offsets for row and column dimensions
-        const rowStart = sliceIndices[0]?.start || 0;
-        const colStart = sliceIndices[1]?.start || 0;
-
-        // Check if the input tensor is non-contiguous by looking at its layout
-        // For non-contiguous tensors, we need to pass stride information
-        const inputMeta = input.meta;
-
-        const isContiguous =
-          inputMeta.shape.length <= 1 ||
-          (inputMeta.strides &&
-            this.isContiguousStrides(Array.from(inputMeta.shape), Array.from(inputMeta.strides)));
-
-        if (!isContiguous && inputMeta.strides) {
-          // Non-contiguous tensor: pass stride information
-          const strides = Array.from(inputMeta.strides);
-          this.executor!.execute_slice(input, output, rowStart, colStart, new Uint32Array(strides));
+    // Slices need the per-axis spec from the operation metadata
+    if (wasmOp === OPS.slice) {
+      const sliceIndices =
+        op?.__output && '__sliceIndices' in op.__output ? (op.__output as any).__sliceIndices : [];
+      const spec = new this.wasmModule!.WasmSliceSpec();
+
+      for (const index of sliceIndices as readonly unknown[]) {
+        if (index === null || index === undefined) {
+          spec.full();
+        } else if (typeof index === 'number') {
+          spec.index(index);
         } else {
-          // Contiguous tensor: use fast path without strides
-          this.executor!.execute_slice(input, output, rowStart, colStart, undefined);
+          const { start, stop, step } = index as { start?: number; stop?: number; step?: number };
+          spec.range(start, stop, step ?? 1);
         }
-        return;
       }
+
+      try {
+        this.executor!.execute_slice(input, output, spec);
+      } finally {
+        spec.free();
+      }
+      return;
     }
 
     // For all other view operations, use the standard view operation handler
     this.executor!.execute_unary(wasmOp, input, output);
   }
 
-  /**
-   * Check if strides represent contiguous memory layout
-   */
-  private isContiguousStrides(shape: readonly number[], strides: readonly number[]): boolean {
-    // C-contiguous: strides should be [product(shape[i+1:]), ..., shape[-2], 1]
-    let expectedStride = 1;
-    for (let i = shape.length - 1; i >= 0; i--) {
-      if (strides[i] !== expectedStride) {
-        return false;
-      }
-      expectedStride *= shape[i]!;
-    }
-    return true;
-  }
-
   /**
    * Execute reduction operation using WASM reduction operations
    */
@@ -721,6 +828,33 @@ export class WASMDevice implements Device {
     return wasmOp;
   }
 
+  /**
+   * Execute rounding operation with core's output dtype
+   *
+   * The WASM kernels keep floats floating (floor of float32 is float32), while
+   * core types the result as int32 / int64. Round into a temporary of the input
+   * dtype, then cast the already-integral values into the output.
+   */
+  private executeRoundingOperation(
+    wasmOp: WasmOperation,
+    input: WasmTensor,
+    output: WasmTensor,
+    op: AnyStorageTransformation,
+  ): void {
+    const inputDType = op.__inputs[0]!.__dtype;
+    if (inputDType.__dtype === op.__output.__dtype.__dtype) {
+      this.executor!.execute_unary(wasmOp, input, output);
+      return;
+    }
+
+    const rounded = this.executor!.alloc_temp_tensor(
+      this.mapDType(inputDType),
+      new Uint32Array(op.__output.__shape),
+    );
+    this.executor!.execute_unary(wasmOp, input, rounded);
+    this.executor!.execute_cast(rounded, output, ROUNDING_MODES.truncate as WasmRoundingMode, false);
+  }
+
   /**
    * Map TypeTensor dtype to WasmDType
    */
@@ -13,6 +13,7 @@ export type {
   WasmMemoryStats,
   WasmDType,
   WasmOperation,
+  WasmRoundingMode,
   PatternCacheStats,
 } from '../wasm/pkg/typetensor_wasm';
 
@@ -66,6 +67,48 @@ export const OPS = {
   // Einops operations
   rearrange: 60,
   reduce: 61,
+
+  // Comparison operations, Bool output (implemented in comparison.rs)
+  eq: 90,
+  ne: 91,
+  lt: 92,
+  le: 93,
+  gt: 94,
+  ge: 95,
+
+  // Logical operations, Bool output (implemented in comparison.rs)
+  logical_and: 96,
+  logical_or: 97,
+  logical_xor: 98,
+  logical_not: 99,
+
+  // Selection operations (implemented in select.rs)
+  where: 100,
+
+  // Activations, rounding and extended math (implemented in unary.rs / fast_math.rs)
+  tan: 110,
+  tanh: 111,
+  sigmoid: 112,
+  relu: 113,
+  leaky_relu: 114,
+  gelu: 115,
+  gelu_tanh: 116,
+  silu: 117,
+  erf: 118,
+  reciprocal: 119,
+  rsqrt: 120,
+  floor: 121,
+  ceil: 122,
+  round: 123,
+  sign: 124,
+  log1p: 125,
+  expm1: 126,
+
+  // Extended binary operations (implemented in binary.rs)
+  pow: 130,
+  maximum: 131,
+  minimum: 132,
+  mod: 133,
 } as const;
 
 // Data type mapping - matches WasmDType enum exactly
@@ -86,6 +129,14 @@ export const DTYPES = {
   uint64: 10, // alias for biguint64
 } as const;
 
+// Rounding mode mapping - matches WasmRoundingMode enum exactly
+export const ROUNDING_MODES = {
+  truncate: 0,
+  nearest: 1,
+  floor: 2,
+  ceil: 3,
+} as const;
+
 // Type helpers for operation and dtype mapping
 export type OperationName = keyof typeof OPS;
 export type DTypeName = keyof typeof DTYPES;
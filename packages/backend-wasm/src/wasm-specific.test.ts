@@ -226,6 +226,23 @@ describe('WASM-Specific Functionality Tests', () => {
     });
   });
 
+  describe('Rounding Operations', () => {
+    it('should floor float32 into the int32 output core expects', async () => {
+      const values = new Float32Array([1.5, -1.5, 2.0, -0.25]);
+      const input = device.createDataWithBufferAndShape(values.buffer, float32, [4]);
+      const op = {
+        __op: 'floor',
+        __output: { __dtype: int32, __shape: [4] },
+        __inputs: [{ __dtype: float32, __shape: [4] }],
+      } as any;
+
+      const result = await device.execute(op, [input]);
+
+      expect(result.byteLength).toBe(4 * 4);
+      expect(Array.from(new Int32Array(await device.readData(result)))).toEqual([1, -2, 2, -1]);
+    });
+  });
+
   describe('Error Handling and Edge Cases', () => {
     it('should handle invalid operation gracefully', () => {
       // Try to call methods on uninitialized device
@@ -52,6 +52,7 @@ pub struct TempArena {
     limit: usize,
     checkpoints: Vec<usize>,
     total_allocated: usize,
+    peak: usize,
 }
 
 impl TempArena {
@@ -67,6 +68,7 @@ impl TempArena {
             limit: INITIAL_ARENA_SIZE,
             checkpoints: Vec::new(),
             total_allocated: 0,
+            peak: 0,
         }
     }
     
@@ -92,6 +94,7 @@ impl TempArena {
         let offset = aligned_current;
         self.current = aligned_current + aligned_size;
         self.total_allocated += aligned_size;
+        self.peak = self.peak.max(self.current);
         
         Ok(ArenaOffset::new(offset, size))
     }
@@ -139,11 +142,22 @@ impl TempArena {
         Ok(())
     }
     
+    /// Drop a checkpoint (and any later ones) without freeing memory
+    pub fn release(&mut self, checkpoint: CheckpointId) {
+        self.checkpoints.truncate(checkpoint.0);
+    }
+    
+    /// Number of live checkpoints
+    pub fn checkpoint_depth(&self) -> usize {
+        self.checkpoints.len()
+    }
+    
     /// Reset entire arena (deallocate everything)
     pub fn reset(&mut self) {
         self.current = 0;
         self.checkpoints.clear();
         self.total_allocated = 0;
+        self.peak = 0;
     }
     
     /// Get memory usage statistics
@@ -154,6 +168,11 @@ impl TempArena {
         (used, capacity, utilization)
     }
     
+    /// Get highest arena usage since creation or last reset
+    pub fn peak_usage(&self) -> usize {
+        self.peak
+    }
+    
     /// Grow arena to accommodate larger allocations
     fn grow_arena(&mut self, needed_size: usize) -> Result<(), String> {
         // Calculate new size (double current or fit needed size, whichever is larger)
@@ -201,6 +220,7 @@ impl TempArena {
         let offset = aligned_current;
         self.current = aligned_current + aligned_size;
         self.total_allocated += aligned_size;
+        self.peak = self.peak.max(self.current);
         
         Ok(ArenaOffset::new(offset, size))
     }
@@ -334,6 +354,24 @@ mod tests {
         assert!(used_after < used_before);
     }
     
+    #[test]
+    fn test_arena_peak_usage() {
+        let mut arena = TempArena::new();
+        
+        let checkpoint = arena.checkpoint();
+        arena.alloc(100).unwrap();
+        arena.alloc(100).unwrap();
+        arena.restore(checkpoint).unwrap();
+        arena.alloc(50).unwrap();
+        
+        // Peak survives restore; current usage does not
+        assert_eq!(arena.peak_usage(), 2 * align_up(100, SIMD_ALIGNMENT));
+        assert_eq!(arena.memory_usage().0, align_up(50, SIMD_ALIGNMENT));
+        
+        arena.reset();
+        assert_eq!(arena.peak_usage(), 0);
+    }
+    
     #[test]
     fn test_simd_alignment() {
         let mut arena = TempArena::new();
@@ -9,15 +9,32 @@ use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 use crate::memory::{WasmMemorySystem, WasmTensor, WasmMemoryStats};
 use crate::arena::CheckpointId;
-use crate::types::{WasmOperation, WasmDType, WasmResult, WasmError};
-use crate::operations::{unary, binary, matmul};
-use crate::pattern::{PatternCache, PatternBuilder, OperationDesc, AllocationRequirement, PatternCacheStats, PatternSignature, PatternId};
+use crate::types::{WasmOperation, WasmDType, WasmTensorMeta, WasmResult, WasmError, WasmRoundingMode, WasmPrecision};
+use crate::operations::{unary, binary, matmul, fused, cast, quantized, q4, permute, slice, select, view, einops, scan};
+use crate::operations::strided::contiguous_strides;
+use crate::operations::fused::WasmFusedProgram;
+use crate::operations::cast::CastOptions;
+use crate::operations::quantized::WasmQuantizationParams;
+use crate::operations::slice::WasmSliceSpec;
+use crate::operations::einops::WasmEinopsPlan;
+use crate::operations::scan::ScanOptions;
+use crate::pattern::{PatternCache, PatternBuilder, OperationDesc, AllocationRequirement, PatternCacheStats, PatternSignature, ProducerTracker};
+use crate::planner;
+use crate::graph::{OperationGraph, GraphOp};
+
+/// Longest operation sequence recorded as a single pattern
+const MAX_PATTERN_SEQUENCE_LENGTH: usize = 256;
 
 /// Result of pattern-based execution
+/// 
+/// Holds the bulk pre-allocated outputs of the remaining operations in a
+/// cached sequence; `alloc_temp_tensor` hands each one out at most once, to an
+/// allocation made while its operation is the next one to be recorded.
 struct PatternExecution {
-    pattern_id: PatternId,
-    pre_allocated_tensors: Vec<WasmTensor>,
-    estimated_speedup: f32,
+    operations: Vec<OperationDesc>,
+    /// Output of operation `i + 1`, until it is handed out
+    pre_allocated_tensors: Vec<Option<WasmTensor>>,
+    pending: usize,
 }
 
 /// Main tensor operation executor
@@ -31,6 +48,13 @@ pub struct WasmExecutor {
     enable_pattern_optimization: bool,
     checkpoint_counter: usize,
     active_checkpoints: HashMap<usize, CheckpointId>,
+    recording_graph: Option<OperationGraph>,
+    captured_graph: Option<OperationGraph>,
+    current_sequence: PatternBuilder,
+    sequence_producers: ProducerTracker,
+    active_replay: Option<PatternExecution>,
+    enable_memory_planning: bool,
+    precision: WasmPrecision,
 }
 
 #[wasm_bindgen]
@@ -44,6 +68,13 @@ impl WasmExecutor {
             enable_pattern_optimization: true,
             checkpoint_counter: 0,
             active_checkpoints: HashMap::new(),
+            recording_graph: None,
+            captured_graph: None,
+            current_sequence: PatternBuilder::new(),
+            sequence_producers: ProducerTracker::new(),
+            active_replay: None,
+            enable_memory_planning: false,
+            precision: WasmPrecision::default(),
         }
     }
     
@@ -55,12 +86,24 @@ impl WasmExecutor {
             enable_pattern_optimization: true,
             checkpoint_counter: 0,
             active_checkpoints: HashMap::new(),
+            recording_graph: None,
+            captured_graph: None,
+            current_sequence: PatternBuilder::new(),
+            sequence_producers: ProducerTracker::new(),
+            active_replay: None,
+            enable_memory_planning: false,
+            precision: WasmPrecision::default(),
         }
     }
     
     /// Allocate temporary tensor (arena-based, fast cleanup)
     #[wasm_bindgen]
     pub fn alloc_temp_tensor(&mut self, dtype: WasmDType, shape: Box<[usize]>) -> Result<WasmTensor, JsValue> {
+        // Hand back pre-allocated output when replaying a cached sequence
+        if let Some(tensor) = self.take_pre_allocated_tensor(dtype, &shape) {
+            return Ok(tensor);
+        }
+        
         self.memory.alloc_temp_tensor(dtype, &shape)
             .map_err(|e| JsValue::from_str(&e))
     }
@@ -82,6 +125,9 @@ impl WasmExecutor {
     /// Create checkpoint for scoped memory management
     #[wasm_bindgen]
     pub fn checkpoint(&mut self) -> usize {
+        // Operations since the previous boundary form one pattern
+        self.flush_operation_sequence();
+        
         // Create checkpoint in memory system
         let checkpoint = self.memory.checkpoint();
         
@@ -96,6 +142,9 @@ impl WasmExecutor {
     /// Restore to checkpoint (bulk cleanup of temporaries)
     #[wasm_bindgen]
     pub fn restore(&mut self, checkpoint_id: usize) -> Result<(), JsValue> {
+        // Operations inside the checkpoint scope form one pattern
+        self.flush_operation_sequence();
+        
         // Look up the CheckpointId from our mapping
         if let Some(&checkpoint) = self.active_checkpoints.get(&checkpoint_id) {
             // Restore to the actual checkpoint
@@ -124,17 +173,49 @@ impl WasmExecutor {
         input: &WasmTensor,
         output: &WasmTensor
     ) -> Result<(), JsValue> {
+        let op = GraphOp::Unary { operation, input: input.clone(), precision: self.precision };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
         // Record pattern for optimization
         self.record_operation_pattern(operation, &[input], output);
         
-        unary::execute_unary_op(
+        unary::execute_unary_op_with_precision(
             operation,
             input,
             output,
             self.memory.arena(),
+            self.precision,
         ).map_err(|e| self.map_wasm_error(e))
     }
     
+    /// Execute LeakyRelu with an explicit negative-side slope
+    ///
+    /// `execute_unary` with `LeakyRelu` uses the default slope of 0.01.
+    #[wasm_bindgen]
+    pub fn execute_leaky_relu(&mut self,
+        input: &WasmTensor,
+        output: &WasmTensor,
+        negative_slope: f64
+    ) -> Result<(), JsValue> {
+        let op = GraphOp::LeakyRelu { input: input.clone(), negative_slope, precision: self.precision };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+
+        // Record pattern for optimization
+        self.record_operation_pattern(WasmOperation::LeakyRelu, &[input], output);
+
+        unary::execute_leaky_relu_op(
+            input,
+            output,
+            self.memory.arena(),
+            negative_slope,
+            self.precision,
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+
     /// Execute binary operation
     #[wasm_bindgen]
     pub fn execute_binary(&mut self,
@@ -143,6 +224,11 @@ impl WasmExecutor {
         input_b: &WasmTensor,
         output: &WasmTensor
     ) -> Result<(), JsValue> {
+        let op = GraphOp::Binary { operation, input_a: input_a.clone(), input_b: input_b.clone() };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
         // Record pattern for optimization
         self.record_operation_pattern(operation, &[input_a, input_b], output);
         
@@ -155,6 +241,35 @@ impl WasmExecutor {
         ).map_err(|e| self.map_wasm_error(e))
     }
     
+    /// Execute where: `output = condition ? input_a : input_b` element-wise
+    /// 
+    /// `condition` must be Bool. All three inputs broadcast to the output
+    /// shape, and the output dtype is the promoted dtype of `input_a` and
+    /// `input_b` (e.g. masking scores with a scalar `-inf`).
+    #[wasm_bindgen]
+    pub fn execute_where(&mut self,
+        condition: &WasmTensor,
+        input_a: &WasmTensor,
+        input_b: &WasmTensor,
+        output: &WasmTensor
+    ) -> Result<(), JsValue> {
+        let op = GraphOp::Where { condition: condition.clone(), input_a: input_a.clone(), input_b: input_b.clone() };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(WasmOperation::Where, &[condition, input_a, input_b], output);
+        
+        select::execute_where_op(
+            condition,
+            input_a,
+            input_b,
+            output,
+            self.memory.arena(),
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
     /// Execute matrix multiplication
     #[wasm_bindgen]
     pub fn execute_matmul(&mut self,
@@ -162,6 +277,11 @@ impl WasmExecutor {
         input_b: &WasmTensor,
         output: &WasmTensor
     ) -> Result<(), JsValue> {
+        let op = GraphOp::Matmul { input_a: input_a.clone(), input_b: input_b.clone() };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
         // Record pattern for optimization
         self.record_operation_pattern(WasmOperation::Matmul, &[input_a, input_b], output);
         
@@ -174,40 +294,240 @@ impl WasmExecutor {
         ).map_err(|e| self.map_wasm_error(e))
     }
     
-    /// Execute slice operation with explicit offset parameters and optional stride information
+    /// Execute affine-quantized int8 matrix multiplication with f32 output
+    /// 
+    /// Both inputs are Int8; accumulation happens in i32 and the result is
+    /// dequantized with `a_scale * b_scale[j]` after subtracting zero points.
+    #[wasm_bindgen]
+    pub fn execute_quantized_matmul(&mut self,
+        input_a: &WasmTensor,
+        input_b: &WasmTensor,
+        output: &WasmTensor,
+        params: &WasmQuantizationParams
+    ) -> Result<(), JsValue> {
+        let op = GraphOp::QuantizedMatmul { input_a: input_a.clone(), input_b: input_b.clone(), params: params.clone() };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(WasmOperation::QuantizedMatmul, &[input_a, input_b], output);
+        
+        quantized::execute_quantized_matmul_op(
+            input_a,
+            input_b,
+            output,
+            self.memory.arena(),
+            params,
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Quantize a 2D Float32 tensor `[rows, cols]` into Q4 weights
+    /// 
+    /// `output` is a Uint8 tensor of shape `[rows, q4_row_bytes(cols)]`.
+    #[wasm_bindgen]
+    pub fn execute_quantize_q4(&mut self,
+        input: &WasmTensor,
+        output: &WasmTensor
+    ) -> Result<(), JsValue> {
+        if self.capture_graph_op(GraphOp::QuantizeQ4 { input: input.clone() }, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(WasmOperation::QuantizeQ4, &[input], output);
+        
+        q4::execute_quantize_q4_op(
+            input,
+            output,
+            self.memory.arena(),
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Execute `output = input × weightsᵀ` against Q4 weights `[out_features, ...]`
+    /// 
+    /// Weight blocks are dequantized on the fly; `input` and `output` are Float32.
+    #[wasm_bindgen]
+    pub fn execute_q4_matmul(&mut self,
+        input: &WasmTensor,
+        weights: &WasmTensor,
+        output: &WasmTensor
+    ) -> Result<(), JsValue> {
+        let op = GraphOp::Q4Matmul { input: input.clone(), weights: weights.clone() };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(WasmOperation::Q4Matmul, &[input, weights], output);
+        
+        q4::execute_q4_matmul_op(
+            input,
+            weights,
+            output,
+            self.memory.arena(),
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Execute fused element-wise program in a single pass
+    /// 
+    /// Evaluates chains like `neg(exp((a + b) * c))` without allocating
+    /// intermediate tensors. `inputs[i]` is the tensor loaded by `program.load(i)`.
+    #[wasm_bindgen]
+    pub fn execute_fused(&mut self,
+        program: &WasmFusedProgram,
+        inputs: Vec<WasmTensor>,
+        output: &WasmTensor
+    ) -> Result<(), JsValue> {
+        let op = GraphOp::Fused { program: program.clone(), inputs: inputs.clone(), precision: self.precision };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        let input_refs: Vec<&WasmTensor> = inputs.iter().collect();
+        self.record_operation_pattern(WasmOperation::Fused, &input_refs, output);
+        
+        fused::execute_fused_op(
+            program,
+            &inputs,
+            output,
+            self.memory.arena(),
+            self.precision,
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Execute dtype cast from `input` to the dtype of `output`
+    /// 
+    /// `rounding` applies to float -> integer casts. With `saturate`, values
+    /// outside the target range are clamped instead of wrapped modulo 2^bits.
+    #[wasm_bindgen]
+    pub fn execute_cast(&mut self,
+        input: &WasmTensor,
+        output: &WasmTensor,
+        rounding: WasmRoundingMode,
+        saturate: bool
+    ) -> Result<(), JsValue> {
+        let options = CastOptions { rounding, saturate };
+        if self.capture_graph_op(GraphOp::Cast { input: input.clone(), options }, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(WasmOperation::Cast, &[input], output);
+        
+        cast::execute_cast_op(
+            input,
+            output,
+            self.memory.arena(),
+            options,
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Execute permute: output dimension `i` is input dimension `axes[i]`
+    /// 
+    /// Works for any rank and dtype; e.g. `[0, 2, 1, 3]` turns attention heads
+    /// `[B, H, S, D]` into `[B, S, H, D]`.
+    #[wasm_bindgen]
+    pub fn execute_permute(&mut self,
+        input: &WasmTensor,
+        output: &WasmTensor,
+        axes: &[u32]
+    ) -> Result<(), JsValue> {
+        let axes: Vec<usize> = axes.iter().map(|&axis| axis as usize).collect();
+        if self.capture_graph_op(GraphOp::Permute { input: input.clone(), axes: axes.clone() }, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(WasmOperation::Permute, &[input], output);
+        
+        permute::execute_permute_op(
+            input,
+            output,
+            self.memory.arena(),
+            &axes,
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Execute slice described by a per-axis `spec` (any rank and dtype)
+    /// 
+    /// `output` must have the resolved slice shape; indexed axes are removed.
     #[wasm_bindgen]
     pub fn execute_slice(&mut self,
         input: &WasmTensor,
         output: &WasmTensor,
-        row_start: usize,
-        col_start: usize,
-        input_strides: Option<Vec<usize>>,
+        spec: &WasmSliceSpec
     ) -> Result<(), JsValue> {
-        use crate::operations::view;
-        
-        // If we have stride information, use stride-aware slicing
-        if let Some(strides) = input_strides {
-            view::execute_slice_with_strides(
-                input,
-                output,
-                row_start,
-                col_start,
-                &strides,
-                self.memory.arena(),
-            ).map_err(|e| self.map_wasm_error(e))
-        } else {
-            // Use the original offset-based slicing for contiguous tensors
-            view::execute_slice_with_offsets(
-                input,
-                output,
-                row_start,
-                col_start,
-                self.memory.arena(),
-            ).map_err(|e| self.map_wasm_error(e))
+        if self.capture_graph_op(GraphOp::Slice { input: input.clone(), spec: spec.clone() }, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(WasmOperation::Slice, &[input], output);
+        
+        slice::execute_slice_op(
+            input,
+            output,
+            self.memory.arena(),
+            spec,
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Execute a compiled einops rearrange, repeat or reduce `plan` in one pass
+    /// 
+    /// `output` must have the plan's output shape and `input`'s dtype. Reduce
+    /// plans support the dtypes of `execute_reduction`.
+    #[wasm_bindgen]
+    pub fn execute_einops(&mut self,
+        input: &WasmTensor,
+        output: &WasmTensor,
+        plan: &WasmEinopsPlan
+    ) -> Result<(), JsValue> {
+        if self.capture_graph_op(GraphOp::Einops { input: input.clone(), plan: plan.clone() }, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(plan.operation(), &[input], output);
+        
+        einops::execute_einops_op(
+            input,
+            output,
+            self.memory.arena(),
+            plan,
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Zero-copy reshape, view, flatten, squeeze, unsqueeze or expand of `input`
+    /// 
+    /// Returns a view sharing `input`'s storage; expanded axes get stride 0.
+    /// A reshape that cannot be expressed over the existing strides (e.g.
+    /// flattening a transposed view) copies into a contiguous temp first.
+    #[wasm_bindgen]
+    pub fn execute_view(&mut self,
+        operation: WasmOperation,
+        input: &WasmTensor,
+        shape: &[u32]
+    ) -> Result<WasmTensor, JsValue> {
+        let shape: Vec<usize> = shape.iter().map(|&dim| dim as usize).collect();
+        let meta = view::view_meta(operation, input.metadata(), &shape)
+            .map_err(|e| self.map_wasm_error(e))?;
+        if let Some(meta) = meta {
+            return Ok(input.create_view(meta));
         }
+        
+        let copy = self.materialize(input)?;
+        let meta = copy.metadata();
+        let size = meta.size();
+        let meta = WasmTensorMeta::new(meta.dtype(), shape.clone(), contiguous_strides(&shape), size, 0);
+        Ok(copy.create_view(meta))
     }
     
     /// Execute reduction operation with optional axis parameter
+    /// 
+    /// `ArgMax` / `ArgMin` accept any dtype and require an Int32 `output`;
+    /// `Any` / `All` write Bool and `CountNonzero` writes Int32.
     #[wasm_bindgen]
     pub fn execute_reduction(&mut self,
         operation: WasmOperation,
@@ -218,6 +538,11 @@ impl WasmExecutor {
     ) -> Result<(), JsValue> {
         use crate::operations::reduction;
         
+        let op = GraphOp::Reduction { operation, input: input.clone(), axes: axis.clone(), keep_dims };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
         // Record pattern for optimization
         self.record_operation_pattern(operation, &[input], output);
         
@@ -233,6 +558,70 @@ impl WasmExecutor {
         ).map_err(|e| self.map_wasm_error(e))
     }
     
+    /// Execute Var, Std, NormL1, NormL2, NormLinf or LogSumExp over `axis` (all axes if `None`)
+    /// 
+    /// Var and Std divide by `count - correction` (1 for the unbiased
+    /// estimate). `output` must be Float32 or Float64 for any input dtype.
+    #[wasm_bindgen]
+    pub fn execute_statistic(&mut self,
+        operation: WasmOperation,
+        input: &WasmTensor,
+        output: &WasmTensor,
+        axis: Option<Vec<usize>>,
+        correction: f64
+    ) -> Result<(), JsValue> {
+        use crate::operations::reduction;
+        
+        let op = GraphOp::Statistic { operation, input: input.clone(), axes: axis.clone(), correction };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(operation, &[input], output);
+        
+        reduction::execute_statistic_op(
+            operation,
+            input,
+            output,
+            self.memory.arena(),
+            axis.as_deref(),
+            correction,
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Execute CumSum, CumProd, CumMax or CumMin of `input` along `axis`
+    /// 
+    /// Negative axes count from the end. `exclusive` leaves each element out
+    /// of its own output (the first output is the identity) and `reverse`
+    /// scans from the end of the axis. Supports Float32, Float64 and Int32.
+    #[wasm_bindgen]
+    pub fn execute_scan(&mut self,
+        operation: WasmOperation,
+        input: &WasmTensor,
+        output: &WasmTensor,
+        axis: i32,
+        exclusive: bool,
+        reverse: bool
+    ) -> Result<(), JsValue> {
+        let options = ScanOptions { exclusive, reverse };
+        if self.capture_graph_op(GraphOp::Scan { operation, input: input.clone(), axis, options }, output) {
+            return Ok(());
+        }
+        
+        // Record pattern for optimization
+        self.record_operation_pattern(operation, &[input], output);
+        
+        scan::execute_scan_op(
+            operation,
+            input,
+            output,
+            self.memory.arena(),
+            axis,
+            options,
+        ).map_err(|e| self.map_wasm_error(e))
+    }
+    
     /// Create a view of a tensor with a different shape (same data, different metadata)
     #[wasm_bindgen]
     pub fn create_view_with_shape(&mut self, tensor: &WasmTensor, new_shape: &[u32]) -> WasmTensor {
@@ -301,6 +690,11 @@ impl WasmExecutor {
     ) -> Result<(), JsValue> {
         use crate::operations::softmax;
         
+        let op = GraphOp::Softmax { operation, input: input.clone(), axis, precision: self.precision };
+        if self.capture_graph_op(op, output) {
+            return Ok(());
+        }
+        
         // Record pattern for optimization
         self.record_operation_pattern(operation, &[input], output);
         
@@ -310,9 +704,74 @@ impl WasmExecutor {
             output,
             self.memory.arena(),
             axis,
+            self.precision,
         ).map_err(|e| self.map_wasm_error(e))
     }
     
+    /// Start recording operations into a graph instead of executing them
+    /// 
+    /// While recording, every `execute_*` call that writes an output tensor only
+    /// captures the call; outputs are not written until `run_graph` is called.
+    /// `execute_view` is not captured: it returns its view immediately, and a
+    /// reshape that needs a contiguous copy records that copy as a permute.
+    #[wasm_bindgen]
+    pub fn begin_graph(&mut self) -> Result<(), JsValue> {
+        if self.recording_graph.is_some() {
+            return Err(JsValue::from_str("Graph recording already in progress"));
+        }
+        
+        self.recording_graph = Some(OperationGraph::new());
+        Ok(())
+    }
+    
+    /// Stop recording and keep the captured graph for `run_graph`
+    /// 
+    /// Returns the number of captured operations. The whole sequence is also
+    /// recorded in the pattern cache as a single multi-op pattern.
+    #[wasm_bindgen]
+    pub fn end_graph(&mut self) -> Result<usize, JsValue> {
+        let graph = self.recording_graph.take()
+            .ok_or_else(|| JsValue::from_str("No graph recording in progress"))?;
+        
+        self.record_graph_pattern(&graph);
+        
+        let node_count = graph.len();
+        self.captured_graph = Some(graph);
+        Ok(node_count)
+    }
+    
+    /// Execute the captured graph in a single call
+    /// 
+    /// Can be called repeatedly; inputs are re-read on every run. Temporary
+    /// tensors referenced by the graph must not have been released by `restore`.
+    #[wasm_bindgen]
+    pub fn run_graph(&mut self) -> Result<(), JsValue> {
+        let graph = self.captured_graph.as_ref()
+            .ok_or_else(|| JsValue::from_str("No graph captured"))?;
+        
+        graph.execute(self.memory.arena())
+            .map_err(|e| self.map_wasm_error(e))
+    }
+    
+    /// Check if operations are currently being recorded
+    #[wasm_bindgen(getter)]
+    pub fn is_recording(&self) -> bool {
+        self.recording_graph.is_some()
+    }
+    
+    /// Number of operations in the captured graph (0 if none)
+    #[wasm_bindgen(getter)]
+    pub fn graph_length(&self) -> usize {
+        self.captured_graph.as_ref().map_or(0, |graph| graph.len())
+    }
+    
+    /// Discard the captured graph and any recording in progress
+    #[wasm_bindgen]
+    pub fn clear_graph(&mut self) {
+        self.recording_graph = None;
+        self.captured_graph = None;
+    }
+    
     /// Garbage collect persistent tensors
     #[wasm_bindgen]
     pub fn gc(&mut self) -> usize {
@@ -331,10 +790,47 @@ impl WasmExecutor {
         self.enable_pattern_optimization = enabled;
     }
     
+    /// Select fast or accurate f32 transcendental functions for subsequent operations
+    /// 
+    /// Defaults to `Accurate`. Applies to element-wise unary, fused and softmax
+    /// operations; operations already captured in a graph keep the precision
+    /// they were recorded with.
+    #[wasm_bindgen]
+    pub fn set_precision(&mut self, precision: WasmPrecision) {
+        self.precision = precision;
+    }
+    
+    /// Current precision of f32 transcendental functions
+    #[wasm_bindgen(getter)]
+    pub fn precision(&self) -> WasmPrecision {
+        self.precision
+    }
+    
+    /// Enable/disable buffer reuse when replaying cached sequences (off by default)
+    /// 
+    /// With planning enabled, intermediates of a replayed sequence share
+    /// arena memory once their last recorded reader has run. Lifetimes only
+    /// see reads inside the sequence, so only opt in when intermediates are
+    /// never read afterwards (e.g. copied to JS once the sequence finishes).
+    #[wasm_bindgen]
+    pub fn set_memory_planning(&mut self, enabled: bool) {
+        self.enable_memory_planning = enabled;
+    }
+    
     /// Clear pattern cache
     #[wasm_bindgen]
     pub fn clear_pattern_cache(&mut self) {
         self.pattern_cache.clear();
+        self.current_sequence = PatternBuilder::new();
+        self.sequence_producers.clear();
+        self.active_replay = None;
+    }
+    
+    /// Number of pre-allocated tensors still waiting to be handed out by the active replay
+    #[wasm_bindgen(getter)]
+    pub fn pending_pre_allocations(&self) -> usize {
+        self.active_replay.as_ref()
+            .map_or(0, |replay| replay.pending)
     }
     
     /// Copy tensor data to JavaScript Uint8Array (for TypeScript readData)
@@ -388,10 +884,17 @@ impl WasmExecutor {
     }
     
     /// Try to execute operation using cached pattern (bulk allocation optimization)
+    /// 
+    /// Called for the first operation after a checkpoint boundary. If a cached
+    /// sequence starts with this operation, the outputs of all its remaining
+    /// operations are allocated in one go with their recorded dtypes and shapes.
+    /// With memory planning enabled they are placed by the static planner, so
+    /// intermediates with disjoint lifetimes share memory.
     fn try_pattern_execution(
         &mut self,
         operation: WasmOperation,
         inputs: &[&WasmTensor],
+        op_desc: &OperationDesc,
     ) -> Option<PatternExecution> {
         if !self.enable_pattern_optimization {
             return None;
@@ -401,28 +904,60 @@ impl WasmExecutor {
         let signature = self.build_pattern_signature(operation, inputs);
         
         // Look for matching pattern
-        if let Some(pattern_id) = self.pattern_cache.find_matching_pattern(&signature) {
-            // Try to get the pattern and perform bulk allocation
-            if let Some(pattern) = self.pattern_cache.get_pattern(pattern_id) {
-                let pattern_clone = pattern.clone(); // Clone to avoid borrow conflicts
-                
-                match self.memory.bulk_allocate_for_pattern(&pattern_clone) {
-                    Ok(pre_allocated_tensors) => {
-                        return Some(PatternExecution {
-                            pattern_id,
-                            pre_allocated_tensors,
-                            estimated_speedup: pattern_clone.estimated_speedup,
-                        });
-                    }
-                    Err(_) => {
-                        // Bulk allocation failed, fall back to individual allocation
-                        return None;
-                    }
-                }
-            }
+        let pattern_id = self.pattern_cache.find_matching_pattern(&signature)?;
+        let pattern = self.pattern_cache.get_pattern(pattern_id)?;
+        
+        // First output is already allocated by the caller; nothing to gain from single-op patterns
+        if pattern.allocations.len() < 2 || pattern.operations.first() != Some(op_desc) {
+            return None;
+        }
+        let pattern = pattern.clone(); // Clone to avoid borrow conflicts
+        let remaining = &pattern.allocations[1..];
+        
+        let plan = if self.enable_memory_planning {
+            planner::compute_lifetimes(&pattern.operations, &pattern.allocations)
+                .map(|lifetimes| planner::plan_memory(&lifetimes[1..]))
+        } else {
+            None
+        };
+        
+        // Fall back to individual allocation if bulk allocation fails
+        let pre_allocated_tensors = match plan {
+            Some(plan) => self.memory.bulk_allocate_planned(remaining, &plan).ok()?,
+            None => self.memory.bulk_allocate_requirements(remaining).ok()?,
+        };
+        
+        Some(PatternExecution {
+            operations: pattern.operations,
+            pending: pre_allocated_tensors.len(),
+            pre_allocated_tensors: pre_allocated_tensors.into_iter().map(Some).collect(),
+        })
+    }
+    
+    /// Take the pre-allocated output of the operation about to be recorded
+    /// 
+    /// Keyed on the operation's position in the replayed sequence, so a second
+    /// allocation before that operation runs never receives a buffer planned
+    /// for a later operation (which may share memory with a live one).
+    fn take_pre_allocated_tensor(&mut self, dtype: WasmDType, shape: &[usize]) -> Option<WasmTensor> {
+        let op_index = self.current_sequence.len();
+        let replay = self.active_replay.as_mut()?;
+        // The first operation's output was allocated before the replay started
+        let slot = replay.pre_allocated_tensors.get_mut(op_index.checked_sub(1)?)?;
+        
+        let meta = slot.as_ref()?.metadata();
+        if meta.dtype() != dtype || meta.shape() != shape {
+            // Sequence diverged from the cached pattern - allocate normally
+            return None;
+        }
+        
+        let tensor = slot.take();
+        replay.pending -= 1;
+        if replay.pending == 0 {
+            self.active_replay = None;
         }
         
-        None
+        tensor
     }
     
     /// Build pattern signature for current operation
@@ -468,6 +1003,7 @@ impl WasmExecutor {
             operation,
             input_shapes,
             input_dtypes,
+            input_producers: self.sequence_producers.input_producers(inputs),
             output_shape: output.metadata().shape().clone(),
             output_dtype: output.metadata().dtype(),
         }
@@ -484,22 +1020,103 @@ impl WasmExecutor {
         }
         
         let op_desc = self.create_operation_desc(operation, inputs, output);
-        let mut builder = PatternBuilder::new();
-        builder.add_operation(op_desc);
+        let op_index = self.current_sequence.len();
+        
+        if op_index == 0 && self.active_replay.is_none() {
+            // First operation after a boundary: replay a cached sequence if one matches
+            self.active_replay = self.try_pattern_execution(operation, inputs, &op_desc);
+        } else if let Some(replay) = &self.active_replay {
+            // Stop handing out pre-allocated (possibly shared) buffers once the sequence diverges
+            if replay.operations.get(op_index) != Some(&op_desc) {
+                self.active_replay = None;
+            }
+        }
+        
+        self.current_sequence.add_operation(op_desc);
+        self.sequence_producers.record_output(output, op_index);
         
         // Add allocation requirement for output tensor
-        builder.add_allocation(AllocationRequirement {
-            size_bytes: output.byte_size(),
-            alignment: 16, // SIMD alignment
-            is_output: true,
-        });
+        let output_meta = output.metadata();
+        self.current_sequence.add_allocation(AllocationRequirement::for_tensor(
+            output_meta.dtype(),
+            &output_meta.shape(),
+            true,
+        ));
+        
+        if self.current_sequence.len() >= MAX_PATTERN_SEQUENCE_LENGTH {
+            self.flush_operation_sequence();
+        }
+    }
+    
+    /// Store operations recorded since the last checkpoint boundary as one pattern
+    fn flush_operation_sequence(&mut self) {
+        let builder = std::mem::replace(&mut self.current_sequence, PatternBuilder::new());
+        self.sequence_producers.clear();
+        self.active_replay = None;
         
-        let pattern = builder.build(&self.pattern_cache);
+        if builder.is_empty() {
+            return;
+        }
         
-        // Try to store pattern (ignore errors for now)
-        let _ = self.pattern_cache.store_pattern(pattern);
+        let pattern = builder.build(&self.pattern_cache);
+        if self.pattern_cache.contains_pattern(pattern.pattern_id) {
+            // Repeated sequence - count as a hit
+            self.pattern_cache.get_pattern(pattern.pattern_id);
+        } else {
+            // Try to store pattern (ignore errors for now)
+            let _ = self.pattern_cache.store_pattern(pattern);
+        }
     }
-}
+    
+    /// Copy `tensor` (any view) into a new contiguous temp tensor
+    /// 
+    /// Goes through `execute_permute` with the identity permutation, so the
+    /// copy is captured like any other operation while recording a graph.
+    fn materialize(&mut self, tensor: &WasmTensor) -> Result<WasmTensor, JsValue> {
+        let meta = tensor.metadata();
+        let shape = meta.shape();
+        let copy = self.alloc_temp_tensor(meta.dtype(), shape.clone().into_boxed_slice())?;
+        let identity: Vec<u32> = (0..shape.len() as u32).collect();
+        self.execute_permute(tensor, &copy, &identity)?;
+        Ok(copy)
+    }
+    
+    /// Capture operation into the graph being recorded
+    /// 
+    /// Returns true if the operation was captured (and must not be executed now)
+    fn capture_graph_op(&mut self, op: GraphOp, output: &WasmTensor) -> bool {
+        match self.recording_graph.as_mut() {
+            Some(graph) => {
+                graph.push(op, output);
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// Record a captured graph as a single multi-op pattern
+    fn record_graph_pattern(&mut self, graph: &OperationGraph) {
+        if !self.enable_pattern_optimization || graph.is_empty() {
+            return;
+        }
+        
+        let mut builder = PatternBuilder::new();
+        for (node, op_desc) in graph.nodes().iter().zip(graph.operation_descs()) {
+            builder.add_operation(op_desc);
+            let output_meta = node.output.metadata();
+            builder.add_allocation(AllocationRequirement::for_tensor(
+                output_meta.dtype(),
+                &output_meta.shape(),
+                true,
+            ));
+        }
+        
+        let pattern = builder.build(&self.pattern_cache);
+        
+        // Try to store pattern (ignore errors for now)
+        let _ = self.pattern_cache.store_pattern(pattern);
+    }
+}
 
 // Clean implementation - no complex adapters needed!
 
@@ -734,4 +1351,1083 @@ mod tests {
         let matmul_result = executor.execute_matmul(&mat_a, &mat_b, &mat_output);
         assert!(matmul_result.is_ok());
     }
-}
\ No newline at end of file
+    
+    fn f32_bytes(data: &[f32]) -> Vec<u8> {
+        data.iter().flat_map(|&f| f.to_le_bytes()).collect()
+    }
+    
+    fn bytes_to_f32(bytes: &[u8]) -> Vec<f32> {
+        bytes.chunks_exact(4).map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect()
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_graph_capture_and_run() {
+        let mut executor = WasmExecutor::new();
+        
+        let a = executor.tensor_from_data(f32_bytes(&[1.0, 2.0, 3.0, 4.0]), WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        let b = executor.tensor_from_data(f32_bytes(&[1.0, 0.0, 0.0, 1.0]), WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        let sum = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        let product = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        
+        executor.begin_graph().unwrap();
+        assert!(executor.is_recording());
+        executor.execute_binary(WasmOperation::Add, &a, &b, &sum).unwrap();
+        executor.execute_matmul(&sum, &b, &product).unwrap();
+        executor.execute_unary(WasmOperation::Neg, &product, &output).unwrap();
+        assert_eq!(executor.end_graph().unwrap(), 3);
+        assert!(!executor.is_recording());
+        assert_eq!(executor.graph_length(), 3);
+        
+        // Nothing executed while recording
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), vec![0.0; 4]);
+        
+        executor.run_graph().unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), vec![-2.0, -2.0, -3.0, -5.0]);
+        
+        // Graph re-reads inputs on every run
+        executor.copy_js_data_to_tensor(&a, f32_bytes(&[0.0, 0.0, 0.0, 0.0])).unwrap();
+        executor.run_graph().unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), vec![-1.0, 0.0, 0.0, -1.0]);
+        
+        // Whole sequence recorded as one pattern
+        assert_eq!(executor.pattern_cache_stats().pattern_count(), 1);
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_graph_state_errors() {
+        let mut executor = WasmExecutor::new();
+        
+        assert!(executor.end_graph().is_err());
+        assert!(executor.run_graph().is_err());
+        
+        executor.begin_graph().unwrap();
+        assert!(executor.begin_graph().is_err());
+        assert_eq!(executor.end_graph().unwrap(), 0);
+        
+        executor.run_graph().unwrap();
+        executor.clear_graph();
+        assert!(executor.run_graph().is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_execute_fused() {
+        let mut executor = WasmExecutor::new();
+        
+        let a = executor.tensor_from_data(f32_bytes(&[0.0, 1.0, 2.0, 3.0]), WasmDType::Float32, vec![4].into_boxed_slice()).unwrap();
+        let b = executor.tensor_from_data(f32_bytes(&[1.0, 1.0, 1.0, 1.0]), WasmDType::Float32, vec![4].into_boxed_slice()).unwrap();
+        let c = executor.tensor_from_data(f32_bytes(&[0.5]), WasmDType::Float32, vec![1].into_boxed_slice()).unwrap();
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![4].into_boxed_slice()).unwrap();
+        
+        // neg((a + b) * c)
+        let mut program = WasmFusedProgram::new();
+        program.load(0);
+        program.load(1);
+        program.binary(WasmOperation::Add).unwrap();
+        program.load(2);
+        program.binary(WasmOperation::Mul).unwrap();
+        program.unary(WasmOperation::Neg).unwrap();
+        
+        let used_before = executor.memory_stats().arena_used();
+        executor.execute_fused(&program, vec![a, b, c], &output).unwrap();
+        
+        // No intermediate tensors allocated
+        assert_eq!(executor.memory_stats().arena_used(), used_before);
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), vec![-0.5, -1.0, -1.5, -2.0]);
+
+        // A [3] row broadcasts against a [2, 3] matrix
+        let values: Vec<f32> = (0..6).map(|i| i as f32).collect();
+        let matrix = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        let row = executor.tensor_from_data(f32_bytes(&[10.0, 20.0, 30.0]), WasmDType::Float32, vec![3].into_boxed_slice()).unwrap();
+        let mut add = WasmFusedProgram::new();
+        add.load(0);
+        add.load(1);
+        add.binary(WasmOperation::Add).unwrap();
+        let sum = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        executor.execute_fused(&add, vec![matrix.clone(), row], &sum).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&sum)),
+            vec![10.0, 21.0, 32.0, 13.0, 24.0, 35.0]
+        );
+
+        // A transposed [3, 2] view has the output's size but not its shape
+        let transposed = executor.create_view_with_shape_and_strides(&matrix, &[3, 2], &[1, 3]);
+        let result = fused::execute_fused_op(&add, &[matrix, transposed], &sum, executor.memory.arena(), WasmPrecision::Accurate);
+        assert!(matches!(result, Err(WasmError::InvalidShape)));
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_pattern_replay_pre_allocates_outputs() {
+        let mut executor = WasmExecutor::new();
+        
+        let a = executor.tensor_from_data(f32_bytes(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        let b = executor.tensor_from_data(f32_bytes(&[1.0; 6]), WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        
+        // First iteration records the sequence add -> sum(axis 0)
+        let checkpoint = executor.checkpoint();
+        let sum = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::Add, &a, &b, &sum).unwrap();
+        let reduced = executor.alloc_temp_tensor(WasmDType::Float32, vec![3].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::Sum, &sum, &reduced, Some(vec![0]), false).unwrap();
+        executor.restore(checkpoint).unwrap();
+        
+        assert_eq!(executor.pattern_cache_stats().pattern_count(), 1);
+        
+        // Second iteration replays it: the reduction output is pre-allocated with its real shape
+        let checkpoint = executor.checkpoint();
+        let sum = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::Add, &a, &b, &sum).unwrap();
+        assert_eq!(executor.pending_pre_allocations(), 1);
+        
+        let used_before = executor.memory_stats().arena_used();
+        let reduced = executor.alloc_temp_tensor(WasmDType::Float32, vec![3].into_boxed_slice()).unwrap();
+        assert_eq!(executor.memory_stats().arena_used(), used_before);
+        assert_eq!(executor.pending_pre_allocations(), 0);
+        assert_eq!(reduced.metadata().shape(), vec![3]);
+        
+        executor.execute_reduction(WasmOperation::Sum, &sum, &reduced, Some(vec![0]), false).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&reduced)), vec![7.0, 9.0, 11.0]);
+        executor.restore(checkpoint).unwrap();
+        
+        // Same sequence again - no new pattern, but it counts as a hot pattern
+        let stats = executor.pattern_cache_stats();
+        assert_eq!(stats.pattern_count(), 1);
+        assert_eq!(stats.hot_patterns(), 1);
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_memory_planning_reuses_buffers() {
+        let mut executor = WasmExecutor::new();
+        executor.set_memory_planning(true);
+        
+        let input: Vec<f32> = (0..256).map(|i| i as f32).collect();
+        let x = executor.tensor_from_data(f32_bytes(&input), WasmDType::Float32, vec![256].into_boxed_slice()).unwrap();
+        let chain = [WasmOperation::Neg, WasmOperation::Square, WasmOperation::Neg, WasmOperation::Abs];
+        
+        let mut arena_growth = Vec::new();
+        for _ in 0..2 {
+            let checkpoint = executor.checkpoint();
+            let used_before = executor.memory_stats().arena_used();
+            
+            let mut current = x.clone();
+            for &operation in &chain {
+                let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![256].into_boxed_slice()).unwrap();
+                executor.execute_unary(operation, &current, &output).unwrap();
+                current = output;
+            }
+            
+            let expected: Vec<f32> = input.iter().map(|v| v * v).collect();
+            assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&current)), expected);
+            
+            arena_growth.push(executor.memory_stats().arena_used() - used_before);
+            executor.restore(checkpoint).unwrap();
+        }
+        
+        // First run bump-allocates 4 outputs; the replay needs the first output
+        // plus a planned block where the 3 remaining outputs share 2 buffers
+        assert_eq!(arena_growth[0], 4 * 1024);
+        assert_eq!(arena_growth[1], 3 * 1024);
+        
+        let stats = executor.memory_stats();
+        assert_eq!(stats.planned_peak(), 2 * 1024);
+        assert!(stats.actual_peak() >= 4 * 1024);
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_replay_pre_allocations_follow_operation_position() {
+        let mut executor = WasmExecutor::new();
+        executor.set_memory_planning(true);
+        
+        let input: Vec<f32> = (0..256).map(|i| i as f32).collect();
+        let x = executor.tensor_from_data(f32_bytes(&input), WasmDType::Float32, vec![256].into_boxed_slice()).unwrap();
+        let chain = [WasmOperation::Neg, WasmOperation::Square, WasmOperation::Neg, WasmOperation::Abs];
+        
+        // Record the chain, where the planner lets the outputs of ops 1 and 3 share a buffer
+        let checkpoint = executor.checkpoint();
+        let mut current = x.clone();
+        for &operation in &chain {
+            let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![256].into_boxed_slice()).unwrap();
+            executor.execute_unary(operation, &current, &output).unwrap();
+            current = output;
+        }
+        executor.restore(checkpoint).unwrap();
+        
+        // Replay, allocating the three remaining same-shaped outputs before op 1 runs
+        let checkpoint = executor.checkpoint();
+        let first = executor.alloc_temp_tensor(WasmDType::Float32, vec![256].into_boxed_slice()).unwrap();
+        executor.execute_unary(chain[0], &x, &first).unwrap();
+        assert_eq!(executor.pending_pre_allocations(), 3);
+        let outputs: Vec<WasmTensor> = (0..3)
+            .map(|_| executor.alloc_temp_tensor(WasmDType::Float32, vec![256].into_boxed_slice()).unwrap())
+            .collect();
+        assert_eq!(executor.pending_pre_allocations(), 2);
+        
+        let arena = executor.memory.arena();
+        let ptrs: Vec<*const u8> = outputs.iter().map(|tensor| tensor.get_read_ptr(arena)).collect();
+        assert!(ptrs[0] != ptrs[1] && ptrs[1] != ptrs[2] && ptrs[0] != ptrs[2]);
+        
+        let mut current = first;
+        for (&operation, output) in chain[1..].iter().zip(&outputs) {
+            executor.execute_unary(operation, &current, output).unwrap();
+            current = output.clone();
+        }
+        let squared: Vec<f32> = input.iter().map(|v| v * v).collect();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&outputs[0])), squared);
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&outputs[2])), squared);
+        executor.restore(checkpoint).unwrap();
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_replay_keeps_intermediates_readable() {
+        // Planning is opt-in: a default replay gives every output its own buffer
+        let mut executor = WasmExecutor::new();
+        
+        let input: Vec<f32> = (0..256).map(|i| i as f32).collect();
+        let x = executor.tensor_from_data(f32_bytes(&input), WasmDType::Float32, vec![256].into_boxed_slice()).unwrap();
+        let chain = [WasmOperation::Neg, WasmOperation::Square, WasmOperation::Neg, WasmOperation::Abs];
+        
+        for _ in 0..3 {
+            let checkpoint = executor.checkpoint();
+            let mut outputs = Vec::new();
+            let mut current = x.clone();
+            for &operation in &chain {
+                let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![256].into_boxed_slice()).unwrap();
+                executor.execute_unary(operation, &current, &output).unwrap();
+                current = output.clone();
+                outputs.push(output);
+            }
+            
+            // Intermediates read from JS after the whole sequence ran
+            let negated: Vec<f32> = input.iter().map(|v| -v).collect();
+            let squared: Vec<f32> = input.iter().map(|v| v * v).collect();
+            assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&outputs[0])), negated);
+            assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&outputs[1])), squared);
+            assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&outputs[3])), squared);
+            executor.restore(checkpoint).unwrap();
+        }
+        
+        assert_eq!(executor.pattern_cache_stats().hot_patterns(), 1);
+        assert_eq!(executor.memory_stats().planned_peak(), 0);
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_replay_releases_rollback_checkpoint() {
+        let mut executor = WasmExecutor::new();
+        let x = executor.tensor_from_data(f32_bytes(&[1.0; 64]), WasmDType::Float32, vec![64].into_boxed_slice()).unwrap();
+        
+        let mut growth = Vec::new();
+        for _ in 0..10 {
+            let checkpoint = executor.checkpoint();
+            let depth_before = executor.memory.checkpoint_depth();
+            let mut current = x.clone();
+            for operation in [WasmOperation::Neg, WasmOperation::Abs, WasmOperation::Sqrt] {
+                let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![64].into_boxed_slice()).unwrap();
+                executor.execute_unary(operation, &current, &output).unwrap();
+                current = output;
+            }
+            growth.push(executor.memory.checkpoint_depth() - depth_before);
+            executor.restore(checkpoint).unwrap();
+        }
+        
+        // Every run after the first replays the pattern with a bulk allocation
+        assert!(executor.pattern_cache_stats().hot_patterns() >= 1);
+        assert_eq!(growth, vec![0; 10]);
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_binary_mixed_dtypes() {
+        let mut executor = WasmExecutor::new();
+        
+        let ints: Vec<u8> = [1i32, -2, 3, 4, 5, 6].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let a = executor.tensor_from_data(ints, WasmDType::Int32, vec![2, 3].into_boxed_slice()).unwrap();
+        let b = executor.tensor_from_data(f32_bytes(&[0.5, 1.5, 2.5]), WasmDType::Float32, vec![3].into_boxed_slice()).unwrap();
+        let output = executor.alloc_temp_tensor(WasmDType::Float64, vec![2, 3].into_boxed_slice()).unwrap();
+        
+        // Int32 + Float32 -> Float64, with broadcasting
+        executor.execute_binary(WasmOperation::Add, &a, &b, &output).unwrap();
+        let result: Vec<f64> = executor.copy_tensor_data_to_js(&output)
+            .chunks_exact(8)
+            .map(|c| f64::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert_eq!(result, vec![1.5, -0.5, 5.5, 4.5, 6.5, 8.5]);
+        
+        // Output must have the promoted dtype
+        let wrong = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        assert!(executor.execute_binary(WasmOperation::Add, &a, &b, &wrong).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_cast() {
+        let mut executor = WasmExecutor::new();
+        
+        let input = executor.tensor_from_data(f32_bytes(&[1.5, -2.5, 3e9, f32::NAN, 7.0, 0.0]), WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        let output = executor.alloc_temp_tensor(WasmDType::Int32, vec![2, 3].into_boxed_slice()).unwrap();
+        let to_i32 = |bytes: Vec<u8>| -> Vec<i32> {
+            bytes.chunks_exact(4).map(|c| i32::from_le_bytes(c.try_into().unwrap())).collect()
+        };
+        
+        // SIMD saturating path
+        executor.execute_cast(&input, &output, WasmRoundingMode::Nearest, true).unwrap();
+        assert_eq!(to_i32(executor.copy_tensor_data_to_js(&output)), vec![2, -2, i32::MAX, 0, 7, 0]);
+        
+        // Scalar wrapping path (3e9 wraps modulo 2^32)
+        executor.execute_cast(&input, &output, WasmRoundingMode::Truncate, false).unwrap();
+        assert_eq!(to_i32(executor.copy_tensor_data_to_js(&output)), vec![1, -2, -1294967296, 0, 7, 0]);
+        
+        // Strided input: cast the [3, 2] transpose of the same storage
+        let transposed = executor.create_view_with_shape_and_strides(&input, &[3, 2], &[1, 3]);
+        let bools = executor.alloc_temp_tensor(WasmDType::Bool, vec![3, 2].into_boxed_slice()).unwrap();
+        executor.execute_cast(&transposed, &bools, WasmRoundingMode::Truncate, false).unwrap();
+        assert_eq!(executor.copy_tensor_data_to_js(&bools), vec![1, 1, 1, 1, 1, 0]);
+        
+        let wrong_size = executor.alloc_temp_tensor(WasmDType::Float64, vec![5].into_boxed_slice()).unwrap();
+        assert!(executor.execute_cast(&input, &wrong_size, WasmRoundingMode::Truncate, false).is_err());
+        
+        // Same size, different shape: a [2, 3] input does not cast into [3, 2]
+        let wrong_shape = executor.alloc_temp_tensor(WasmDType::Float64, vec![3, 2].into_boxed_slice()).unwrap();
+        assert!(executor.execute_cast(&input, &wrong_shape, WasmRoundingMode::Truncate, false).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_cast_simd_paths() {
+        let mut executor = WasmExecutor::new();
+        
+        // 37 elements exercise both the vector loops and the scalar tails
+        let bytes: Vec<u8> = (0..37).map(|i| (i * 7) as u8).collect();
+        let mut current = executor.tensor_from_data(bytes.clone(), WasmDType::Uint8, vec![37].into_boxed_slice()).unwrap();
+        for dtype in [WasmDType::Float32, WasmDType::Float64, WasmDType::Float32, WasmDType::Int32, WasmDType::Float32] {
+            let output = executor.alloc_temp_tensor(dtype, vec![37].into_boxed_slice()).unwrap();
+            executor.execute_cast(&current, &output, WasmRoundingMode::Truncate, true).unwrap();
+            current = output;
+        }
+        
+        let expected: Vec<f32> = bytes.iter().map(|&b| b as f32).collect();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&current)), expected);
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_half_precision() {
+        let mut executor = WasmExecutor::new();
+        let to_f32 = |executor: &mut WasmExecutor, tensor: &WasmTensor| -> Vec<f32> {
+            let output = executor.alloc_temp_tensor(WasmDType::Float32, tensor.meta().shape().into_boxed_slice()).unwrap();
+            executor.execute_cast(tensor, &output, WasmRoundingMode::Truncate, false).unwrap();
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&output))
+        };
+        
+        for dtype in [WasmDType::Float16, WasmDType::BFloat16] {
+            // [[1, 2], [3, 4]] narrowed from f32
+            let source = executor.tensor_from_data(f32_bytes(&[1.0, 2.0, 3.0, 4.0]), WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+            let a = executor.alloc_temp_tensor(dtype, vec![2, 2].into_boxed_slice()).unwrap();
+            executor.execute_cast(&source, &a, WasmRoundingMode::Truncate, false).unwrap();
+            assert_eq!(executor.copy_tensor_data_to_js(&a).len(), 8);
+            
+            let sum = executor.alloc_temp_tensor(dtype, vec![2, 2].into_boxed_slice()).unwrap();
+            executor.execute_binary(WasmOperation::Add, &a, &a, &sum).unwrap();
+            assert_eq!(to_f32(&mut executor, &sum), vec![2.0, 4.0, 6.0, 8.0]);
+            
+            let negated = executor.alloc_temp_tensor(dtype, vec![2, 2].into_boxed_slice()).unwrap();
+            executor.execute_unary(WasmOperation::Neg, &a, &negated).unwrap();
+            assert_eq!(to_f32(&mut executor, &negated), vec![-1.0, -2.0, -3.0, -4.0]);
+            
+            let product = executor.alloc_temp_tensor(dtype, vec![2, 2].into_boxed_slice()).unwrap();
+            executor.execute_matmul(&a, &a, &product).unwrap();
+            assert_eq!(to_f32(&mut executor, &product), vec![7.0, 10.0, 15.0, 22.0]);
+            
+            // Half + Float32 promotes to Float32
+            let promoted = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+            executor.execute_binary(WasmOperation::Mul, &a, &source, &promoted).unwrap();
+            assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&promoted)), vec![1.0, 4.0, 9.0, 16.0]);
+        }
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_quantized_matmul() {
+        let mut executor = WasmExecutor::new();
+        let i8_bytes = |values: &[i8]| -> Vec<u8> { values.iter().map(|&v| v as u8).collect() };
+        
+        // [2, 3] × [3, 2]
+        let a = executor.tensor_from_data(i8_bytes(&[1, 2, 3, -4, 5, -6]), WasmDType::Int8, vec![2, 3].into_boxed_slice()).unwrap();
+        let b = executor.tensor_from_data(i8_bytes(&[7, -8, 9, 10, -11, 12]), WasmDType::Int8, vec![3, 2].into_boxed_slice()).unwrap();
+        
+        let raw = executor.alloc_temp_tensor(WasmDType::Int32, vec![2, 2].into_boxed_slice()).unwrap();
+        executor.execute_matmul(&a, &b, &raw).unwrap();
+        let raw_values: Vec<i32> = executor.copy_tensor_data_to_js(&raw)
+            .chunks_exact(4)
+            .map(|c| i32::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert_eq!(raw_values, vec![-8, 48, 83, 10]);
+        
+        // Per-channel B: column 0 has zero point 1, column 1 has scale 0.25
+        let params = WasmQuantizationParams::new(0.5, -1, vec![1.0, 0.25], vec![1, 0]);
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        executor.execute_quantized_matmul(&a, &b, &output, &params).unwrap();
+        // Column 0: sum((a + 1) * (b - 1)) * 0.5, column 1: sum((a + 1) * b) * 0.125
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), vec![-6.0, 7.75, 45.0, 3.0]);
+        
+        let bad_params = WasmQuantizationParams::new(0.5, 0, vec![1.0, 2.0, 3.0], vec![0]);
+        assert!(executor.execute_quantized_matmul(&a, &b, &output, &bad_params).is_err());
+        
+        // k = 20 runs the SIMD dot product over full vectors plus a tail
+        let long_a: Vec<i8> = (0..20).map(|i| i - 10).collect();
+        let long_b: Vec<i8> = (0..20).map(|i| 3 - i).collect();
+        let expected: i32 = long_a.iter().zip(&long_b).map(|(&x, &y)| x as i32 * y as i32).sum();
+        let long_a = executor.tensor_from_data(i8_bytes(&long_a), WasmDType::Int8, vec![1, 20].into_boxed_slice()).unwrap();
+        let long_b = executor.tensor_from_data(i8_bytes(&long_b), WasmDType::Int8, vec![20].into_boxed_slice()).unwrap();
+        let dot = executor.alloc_temp_tensor(WasmDType::Int32, vec![1].into_boxed_slice()).unwrap();
+        executor.execute_matmul(&long_a, &long_b, &dot).unwrap();
+        assert_eq!(executor.copy_tensor_data_to_js(&dot), expected.to_le_bytes().to_vec());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_q4_matmul() {
+        let mut executor = WasmExecutor::new();
+        
+        // [3, 47] weights: one full block and a partial block of 15 values per row
+        let (n, k) = (3, 47);
+        let weights: Vec<f32> = (0..n * k).map(|i| ((i % 15) as f32 - 7.0) * 0.25).collect();
+        let weights_f32 = executor.tensor_from_data(f32_bytes(&weights), WasmDType::Float32, vec![n, k].into_boxed_slice()).unwrap();
+        let weights_q4 = executor.alloc_persistent_tensor(WasmDType::Uint8, vec![n, q4::q4_row_bytes(k)].into_boxed_slice()).unwrap();
+        executor.execute_quantize_q4(&weights_f32, &weights_q4).unwrap();
+        assert_eq!(executor.copy_tensor_data_to_js(&weights_q4).len(), n * 36);
+        
+        // Batch of 2 activation rows
+        let input: Vec<f32> = (0..2 * k).map(|i| (i % 7) as f32 - 3.0).collect();
+        let input_tensor = executor.tensor_from_data(f32_bytes(&input), WasmDType::Float32, vec![2, k].into_boxed_slice()).unwrap();
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, n].into_boxed_slice()).unwrap();
+        executor.execute_q4_matmul(&input_tensor, &weights_q4, &output).unwrap();
+        
+        // Weights are multiples of 0.25 within 7 steps of the block maximum, so they round-trip exactly
+        let mut expected = Vec::new();
+        for row in input.chunks(k) {
+            for weight_row in weights.chunks(k) {
+                expected.push(row.iter().zip(weight_row).map(|(a, w)| a * w).sum::<f32>());
+            }
+        }
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), expected);
+        
+        // Mismatched inner dimension
+        let wrong_input = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 32].into_boxed_slice()).unwrap();
+        assert!(executor.execute_q4_matmul(&wrong_input, &weights_q4, &output).is_err());
+        
+        // Quantizing into row 1 of a packed buffer leaves row 0 alone
+        let row_bytes = q4::q4_row_bytes(32);
+        let single = executor.tensor_from_data(f32_bytes(&weights[..32]), WasmDType::Float32, vec![1, 32].into_boxed_slice()).unwrap();
+        let reference = executor.alloc_temp_tensor(WasmDType::Uint8, vec![1, row_bytes].into_boxed_slice()).unwrap();
+        executor.execute_quantize_q4(&single, &reference).unwrap();
+        let packed = executor.tensor_from_data(vec![0xAA; 2 * row_bytes], WasmDType::Uint8, vec![2, row_bytes].into_boxed_slice()).unwrap();
+        let second_row = packed.create_view(WasmTensorMeta::new(WasmDType::Uint8, vec![1, row_bytes], vec![row_bytes, 1], row_bytes, row_bytes));
+        executor.execute_quantize_q4(&single, &second_row).unwrap();
+        let packed_bytes = executor.copy_tensor_data_to_js(&packed);
+        assert_eq!(packed_bytes[..row_bytes], vec![0xAA; row_bytes]);
+        assert_eq!(packed_bytes[row_bytes..], executor.copy_tensor_data_to_js(&reference));
+        
+        // A block scale that overflows f16 is rejected before anything is written
+        let mut huge = weights[..32].to_vec();
+        huge[5] = 1.0e6;
+        let huge = executor.tensor_from_data(f32_bytes(&huge), WasmDType::Float32, vec![1, 32].into_boxed_slice()).unwrap();
+        assert!(executor.execute_quantize_q4(&huge, &second_row).is_err());
+        assert_eq!(executor.copy_tensor_data_to_js(&packed), packed_bytes);
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_permute() {
+        let mut executor = WasmExecutor::new();
+        
+        // Attention heads: [B=1, H=2, S=3, D=2] -> [B, S, H, D]
+        let values: Vec<f32> = (0..12).map(|i| i as f32).collect();
+        let heads = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![1, 2, 3, 2].into_boxed_slice()).unwrap();
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![1, 3, 2, 2].into_boxed_slice()).unwrap();
+        executor.execute_permute(&heads, &output, &[0, 2, 1, 3]).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&output)),
+            vec![0.0, 1.0, 6.0, 7.0, 2.0, 3.0, 8.0, 9.0, 4.0, 5.0, 10.0, 11.0]
+        );
+        
+        // NCHW [1, 2, 2, 3] -> NHWC [1, 2, 3, 2] on 2-byte elements
+        let bytes: Vec<u8> = (0..12u16).flat_map(|v| v.to_le_bytes()).collect();
+        let nchw = executor.tensor_from_data(bytes, WasmDType::Int16, vec![1, 2, 2, 3].into_boxed_slice()).unwrap();
+        let nhwc = executor.alloc_temp_tensor(WasmDType::Int16, vec![1, 2, 3, 2].into_boxed_slice()).unwrap();
+        executor.execute_permute(&nchw, &nhwc, &[0, 2, 3, 1]).unwrap();
+        let permuted: Vec<u16> = executor.copy_tensor_data_to_js(&nhwc)
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        assert_eq!(permuted, vec![0, 6, 1, 7, 2, 8, 3, 9, 4, 10, 5, 11]);
+        
+        // 3D transpose through the view path swaps the last two axes
+        let batch = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![2, 2, 3].into_boxed_slice()).unwrap();
+        let transposed = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3, 2].into_boxed_slice()).unwrap();
+        executor.execute_unary(WasmOperation::Transpose, &batch, &transposed).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&transposed)),
+            vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0, 6.0, 9.0, 7.0, 10.0, 8.0, 11.0]
+        );
+        
+        assert!(executor.execute_permute(&heads, &output, &[0, 2, 2, 3]).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_slice_spec() {
+        let mut executor = WasmExecutor::new();
+        
+        // [2, 3, 4] -> x[:, 1:, ::-2]
+        let values: Vec<f32> = (0..24).map(|i| i as f32).collect();
+        let input = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![2, 3, 4].into_boxed_slice()).unwrap();
+        let mut spec = WasmSliceSpec::new();
+        spec.full();
+        spec.range(Some(1), None, 1);
+        spec.range(None, None, -2);
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 2, 2].into_boxed_slice()).unwrap();
+        executor.execute_slice(&input, &output, &spec).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&output)),
+            vec![7.0, 5.0, 11.0, 9.0, 19.0, 17.0, 23.0, 21.0]
+        );
+        
+        // Slicing a transposed view of bytes: x.T[-1]
+        let bytes: Vec<u8> = (0..6).collect();
+        let matrix = executor.tensor_from_data(bytes, WasmDType::Uint8, vec![2, 3].into_boxed_slice()).unwrap();
+        let transposed = executor.create_view_with_shape_and_strides(&matrix, &[3, 2], &[1, 3]);
+        let mut spec = WasmSliceSpec::new();
+        spec.index(-1);
+        let column = executor.alloc_temp_tensor(WasmDType::Uint8, vec![2].into_boxed_slice()).unwrap();
+        executor.execute_slice(&transposed, &column, &spec).unwrap();
+        assert_eq!(executor.copy_tensor_data_to_js(&column), vec![2, 5]);
+        
+        // Output shape must match the resolved spec
+        let wrong = executor.alloc_temp_tensor(WasmDType::Uint8, vec![3].into_boxed_slice()).unwrap();
+        assert!(executor.execute_slice(&transposed, &wrong, &spec).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_einops() {
+        let mut executor = WasmExecutor::new();
+        let values: Vec<f32> = (0..12).map(|i| i as f32).collect();
+        let input = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![2, 6].into_boxed_slice()).unwrap();
+        
+        // 'b (h w) -> b w h' with h = 2
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[2]);
+        plan.input_axis(&[2, 3]);
+        for axis in [0, 2, 1] {
+            plan.output_axis(&[axis]);
+        }
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3, 2].into_boxed_slice()).unwrap();
+        executor.execute_einops(&input, &output, &plan).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&output)),
+            vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0, 6.0, 9.0, 7.0, 10.0, 8.0, 11.0]
+        );
+        
+        // 'b (h w) -> h b' with max, recorded into a graph
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[2]);
+        plan.input_axis(&[2, 3]);
+        plan.output_axis(&[1]);
+        plan.output_axis(&[0]);
+        plan.reduce(WasmOperation::Max);
+        let pooled = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        executor.begin_graph().unwrap();
+        executor.execute_einops(&input, &pooled, &plan).unwrap();
+        executor.end_graph().unwrap();
+        executor.run_graph().unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&pooled)), vec![2.0, 8.0, 5.0, 11.0]);
+        
+        // 'n -> (r n)' repeats bytes; the output shape must match the plan
+        let bytes = executor.tensor_from_data(vec![1, 2], WasmDType::Uint8, vec![2].into_boxed_slice()).unwrap();
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[2]);
+        plan.new_axis(3);
+        plan.output_axis(&[1, 0]);
+        let repeated = executor.alloc_temp_tensor(WasmDType::Uint8, vec![6].into_boxed_slice()).unwrap();
+        executor.execute_einops(&bytes, &repeated, &plan).unwrap();
+        assert_eq!(executor.copy_tensor_data_to_js(&repeated), vec![1, 2, 1, 2, 1, 2]);
+        let wrong = executor.alloc_temp_tensor(WasmDType::Uint8, vec![2, 3].into_boxed_slice()).unwrap();
+        assert!(executor.execute_einops(&bytes, &wrong, &plan).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_arg_reduction() {
+        let mut executor = WasmExecutor::new();
+        let logits = executor.tensor_from_data(f32_bytes(&[0.5, 2.0, 2.0, 9.0, -1.0, 4.0]), WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        let to_i32 = |bytes: Vec<u8>| -> Vec<i32> {
+            bytes.chunks_exact(4).map(|c| i32::from_le_bytes(c.try_into().unwrap())).collect()
+        };
+        
+        // Greedy decoding over the last axis, keeping it as length 1
+        let tokens = executor.alloc_temp_tensor(WasmDType::Int32, vec![2, 1].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::ArgMax, &logits, &tokens, Some(vec![1]), true).unwrap();
+        assert_eq!(to_i32(executor.copy_tensor_data_to_js(&tokens)), vec![1, 0]);
+        
+        // Transposed view and flat index over all axes
+        let transposed = executor.create_view_with_shape_and_strides(&logits, &[3, 2], &[1, 3]);
+        let columns = executor.alloc_temp_tensor(WasmDType::Int32, vec![3].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::ArgMin, &transposed, &columns, Some(vec![1]), false).unwrap();
+        assert_eq!(to_i32(executor.copy_tensor_data_to_js(&columns)), vec![0, 1, 0]);
+        let flat = executor.alloc_temp_tensor(WasmDType::Int32, vec![].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::ArgMin, &transposed, &flat, None, false).unwrap();
+        assert_eq!(to_i32(executor.copy_tensor_data_to_js(&flat)), vec![3]);
+        
+        // Indices are always Int32
+        let wrong = executor.alloc_temp_tensor(WasmDType::Float32, vec![2].into_boxed_slice()).unwrap();
+        assert!(executor.execute_reduction(WasmOperation::ArgMax, &logits, &wrong, Some(vec![1]), false).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_statistics() {
+        let mut executor = WasmExecutor::new();
+        let ints: Vec<u8> = [2i32, 4, 4, 4, 5, 5, 7, 9].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let samples = executor.tensor_from_data(ints, WasmDType::Int32, vec![2, 4].into_boxed_slice()).unwrap();
+        
+        // Population std of the whole tensor is 2; per-row unbiased variance
+        let std = executor.alloc_temp_tensor(WasmDType::Float32, vec![].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::Std, &samples, &std, None, false).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&std)), vec![2.0]);
+        let var = executor.alloc_temp_tensor(WasmDType::Float64, vec![2, 1].into_boxed_slice()).unwrap();
+        executor.execute_statistic(WasmOperation::Var, &samples, &var, Some(vec![1]), 1.0).unwrap();
+        let var: Vec<f64> = executor.copy_tensor_data_to_js(&var)
+            .chunks_exact(8)
+            .map(|c| f64::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert_eq!(var[0], 1.0);
+        assert!((var[1] - 11.0 / 3.0).abs() < 1e-12);
+        
+        // Norms of a transposed float view, replayed from a graph
+        let matrix = executor.tensor_from_data(f32_bytes(&[3.0, 0.0, -4.0, 1.0]), WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        let transposed = executor.create_view_with_shape_and_strides(&matrix, &[2, 2], &[1, 2]);
+        let norms = executor.alloc_temp_tensor(WasmDType::Float32, vec![2].into_boxed_slice()).unwrap();
+        executor.begin_graph().unwrap();
+        executor.execute_statistic(WasmOperation::NormL2, &transposed, &norms, Some(vec![1]), 0.0).unwrap();
+        executor.end_graph().unwrap();
+        executor.run_graph().unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&norms)), vec![5.0, 1.0]);
+        
+        // LogSumExp stays finite where exp() overflows f32
+        let logits = executor.tensor_from_data(f32_bytes(&[100.0, 100.0]), WasmDType::Float32, vec![2].into_boxed_slice()).unwrap();
+        let lse = executor.alloc_temp_tensor(WasmDType::Float32, vec![].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::LogSumExp, &logits, &lse, None, false).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&lse)), vec![100.0 + 2f32.ln()]);
+        
+        // Integer outputs and non-statistic operations are rejected
+        let wrong = executor.alloc_temp_tensor(WasmDType::Int32, vec![2].into_boxed_slice()).unwrap();
+        assert!(executor.execute_statistic(WasmOperation::Var, &samples, &wrong, Some(vec![1]), 0.0).is_err());
+        assert!(executor.execute_statistic(WasmOperation::Sum, &samples, &norms, Some(vec![1]), 0.0).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_scan() {
+        let mut executor = WasmExecutor::new();
+        let probs = executor.tensor_from_data(f32_bytes(&[0.1, 0.5, 0.4, 0.25, 0.25, 0.5]), WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        
+        // Cumulative probabilities for top-p sampling
+        let cdf = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        executor.execute_scan(WasmOperation::CumSum, &probs, &cdf, -1, false, false).unwrap();
+        let cdf = bytes_to_f32(&executor.copy_tensor_data_to_js(&cdf));
+        for (actual, expected) in cdf.iter().zip([0.1, 0.6, 1.0, 0.25, 0.5, 1.0]) {
+            assert!((actual - expected).abs() < 1e-6);
+        }
+        
+        // Reverse running max down the columns of a transposed view, from a graph
+        let transposed = executor.create_view_with_shape_and_strides(&probs, &[3, 2], &[1, 3]);
+        let suffix_max = executor.alloc_temp_tensor(WasmDType::Float32, vec![3, 2].into_boxed_slice()).unwrap();
+        executor.begin_graph().unwrap();
+        executor.execute_scan(WasmOperation::CumMax, &transposed, &suffix_max, 0, false, true).unwrap();
+        executor.end_graph().unwrap();
+        executor.run_graph().unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&suffix_max)),
+            vec![0.5, 0.5, 0.5, 0.5, 0.4, 0.5]
+        );
+        
+        // Exclusive Int32 cumsum turns ragged lengths into offsets
+        let lengths: Vec<u8> = [3i32, 1, 4].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let lengths = executor.tensor_from_data(lengths, WasmDType::Int32, vec![3].into_boxed_slice()).unwrap();
+        let offsets = executor.alloc_temp_tensor(WasmDType::Int32, vec![3].into_boxed_slice()).unwrap();
+        executor.execute_scan(WasmOperation::CumSum, &lengths, &offsets, 0, true, false).unwrap();
+        let offsets: Vec<i32> = executor.copy_tensor_data_to_js(&offsets)
+            .chunks_exact(4)
+            .map(|c| i32::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert_eq!(offsets, vec![0, 3, 4]);
+        
+        // Axis out of range and mismatched dtypes are rejected
+        let floats = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        assert!(executor.execute_scan(WasmOperation::CumSum, &probs, &floats, 2, false, false).is_err());
+        let ints = executor.alloc_temp_tensor(WasmDType::Int32, vec![2, 3].into_boxed_slice()).unwrap();
+        assert!(executor.execute_scan(WasmOperation::CumProd, &probs, &ints, 1, false, false).is_err());
+
+        // Unsupported dtypes fail without overwriting the output
+        let bytes = executor.tensor_from_data(vec![1, 2, 3], WasmDType::Uint8, vec![3].into_boxed_slice()).unwrap();
+        let untouched = executor.tensor_from_data(vec![9, 9, 9], WasmDType::Uint8, vec![3].into_boxed_slice()).unwrap();
+        assert!(executor.execute_scan(WasmOperation::CumSum, &bytes, &untouched, 0, false, false).is_err());
+        assert_eq!(executor.copy_tensor_data_to_js(&untouched), vec![9, 9, 9]);
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_boolean_reductions() {
+        let mut executor = WasmExecutor::new();
+        let values = executor.tensor_from_data(f32_bytes(&[1.0, f32::NAN, 0.0, 2.0, 0.0, 3.0]), WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        
+        // Finite check without reading back: x == x is false only for NaN
+        let not_nan = executor.alloc_temp_tensor(WasmDType::Bool, vec![2, 3].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::Eq, &values, &values, &not_nan).unwrap();
+        let rows_ok = executor.alloc_temp_tensor(WasmDType::Bool, vec![2, 1].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::All, &not_nan, &rows_ok, Some(vec![1]), true).unwrap();
+        assert_eq!(executor.copy_tensor_data_to_js(&rows_ok), vec![0, 1]);
+        let any_ok = executor.alloc_temp_tensor(WasmDType::Bool, vec![].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::Any, &not_nan, &any_ok, None, false).unwrap();
+        assert_eq!(executor.copy_tensor_data_to_js(&any_ok), vec![1]);
+        
+        // Nonzero counts per column of the numeric input (NaN counts)
+        let counts = executor.alloc_temp_tensor(WasmDType::Int32, vec![3].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::CountNonzero, &values, &counts, Some(vec![0]), false).unwrap();
+        let counts: Vec<i32> = executor.copy_tensor_data_to_js(&counts)
+            .chunks_exact(4)
+            .map(|c| i32::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert_eq!(counts, vec![2, 1, 1]);
+        
+        // Output dtypes are fixed
+        let wrong = executor.alloc_temp_tensor(WasmDType::Int32, vec![2].into_boxed_slice()).unwrap();
+        assert!(executor.execute_reduction(WasmOperation::Any, &values, &wrong, Some(vec![1]), false).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_zero_copy_views() {
+        let mut executor = WasmExecutor::new();
+        
+        let values: Vec<f32> = (0..6).map(|i| i as f32).collect();
+        let matrix = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        
+        // Reshape and unsqueeze share storage with the source
+        let reshaped = executor.execute_view(WasmOperation::Reshape, &matrix, &[3, 2]).unwrap();
+        let unsqueezed = executor.execute_view(WasmOperation::Unsqueeze, &reshaped, &[1, 3, 2]).unwrap();
+        assert_eq!(unsqueezed.metadata().strides(), vec![6, 2, 1]);
+        executor.copy_js_data_to_tensor(&matrix, f32_bytes(&[9.0; 6])).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&unsqueezed)), vec![9.0; 6]);
+        executor.copy_js_data_to_tensor(&matrix, f32_bytes(&values)).unwrap();
+        
+        // Expanded row [3] -> [2, 3] has a zero stride and feeds a binary kernel directly
+        let row = executor.tensor_from_data(f32_bytes(&[10.0, 20.0, 30.0]), WasmDType::Float32, vec![3].into_boxed_slice()).unwrap();
+        let expanded = executor.execute_view(WasmOperation::Expand, &row, &[2, 3]).unwrap();
+        assert_eq!(expanded.metadata().strides(), vec![0, 1]);
+        let sum = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::Add, &matrix, &expanded, &sum).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&sum)),
+            vec![10.0, 21.0, 32.0, 13.0, 24.0, 35.0]
+        );
+        
+        // Flattening a transposed view cannot be a view: it is copied in logical order
+        let transposed = executor.create_view_with_shape_and_strides(&matrix, &[3, 2], &[1, 3]);
+        let flat = executor.execute_view(WasmOperation::Flatten, &transposed, &[6]).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&flat)),
+            vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0]
+        );
+        
+        // Unary kernels see the transposed view in logical order too
+        let negated = executor.alloc_temp_tensor(WasmDType::Float32, vec![3, 2].into_boxed_slice()).unwrap();
+        executor.execute_unary(WasmOperation::Neg, &transposed, &negated).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&negated)),
+            vec![-0.0, -3.0, -1.0, -4.0, -2.0, -5.0]
+        );
+        
+        assert!(executor.execute_view(WasmOperation::Reshape, &matrix, &[4]).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_strided_kernels() {
+        let mut executor = WasmExecutor::new();
+        
+        // [[0, 1, 2], [3, 4, 5]] and views of it: transpose, and row 1 via offset
+        let values: Vec<f32> = (0..6).map(|i| i as f32).collect();
+        let matrix = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        let transposed = executor.create_view_with_shape_and_strides(&matrix, &[3, 2], &[1, 3]);
+        let row = matrix.create_view(WasmTensorMeta::new(WasmDType::Float32, vec![1, 3], vec![3, 1], 3, 3));
+        
+        // Transposed + expanded row (stride 0) without materializing either
+        let column = executor.execute_view(WasmOperation::Reshape, &row, &[3, 1]).unwrap();
+        let expanded = executor.execute_view(WasmOperation::Expand, &column, &[3, 2]).unwrap();
+        let sum = executor.alloc_temp_tensor(WasmDType::Float32, vec![3, 2].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::Add, &transposed, &expanded, &sum).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&sum)),
+            vec![3.0, 6.0, 5.0, 8.0, 7.0, 10.0]
+        );
+        
+        // Full and axis reductions of the transposed view
+        let total = executor.alloc_temp_tensor(WasmDType::Float32, vec![].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::Max, &transposed, &total, None, false).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&total)), vec![5.0]);
+        let row_sums = executor.alloc_temp_tensor(WasmDType::Float32, vec![3].into_boxed_slice()).unwrap();
+        executor.execute_reduction(WasmOperation::Sum, &transposed, &row_sums, Some(vec![1]), false).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&row_sums)), vec![3.0, 5.0, 7.0]);
+        
+        // Softmax of the offset row matches softmax of [3, 4, 5]
+        let softmax = executor.alloc_temp_tensor(WasmDType::Float32, vec![1, 3].into_boxed_slice()).unwrap();
+        executor.execute_softmax(WasmOperation::Softmax, &row, &softmax, Some(-1)).unwrap();
+        let probabilities = bytes_to_f32(&executor.copy_tensor_data_to_js(&softmax));
+        let total_exp: f32 = [3.0f32, 4.0, 5.0].iter().map(|v| v.exp()).sum();
+        for (&p, v) in probabilities.iter().zip([3.0f32, 4.0, 5.0]) {
+            assert!((p - v.exp() / total_exp).abs() < 1e-6);
+        }
+
+        // Matmul reads the offset row and the transposed view in place
+        let product = executor.alloc_temp_tensor(WasmDType::Float32, vec![1, 2].into_boxed_slice()).unwrap();
+        executor.execute_matmul(&row, &transposed, &product).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&product)), vec![14.0, 50.0]);
+
+        // Batch dims broadcast from the right, including a stride-0 expanded batch
+        let rows = executor.execute_view(WasmOperation::Reshape, &matrix, &[2, 1, 3]).unwrap();
+        let batched = executor.execute_view(WasmOperation::Expand, &transposed, &[2, 3, 2]).unwrap();
+        let batch_product = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 1, 2].into_boxed_slice()).unwrap();
+        for b in [&batched, &transposed] {
+            executor.execute_matmul(&rows, b, &batch_product).unwrap();
+            assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&batch_product)), vec![5.0, 14.0, 14.0, 50.0]);
+        }
+
+        // Fused program over the transposed and expanded views, loading one of them twice
+        let mut program = WasmFusedProgram::new();
+        program.load(0);
+        program.load(1);
+        program.binary(WasmOperation::Add).unwrap();
+        program.load(0);
+        program.binary(WasmOperation::Mul).unwrap();
+        let fused = executor.alloc_temp_tensor(WasmDType::Float32, vec![3, 2].into_boxed_slice()).unwrap();
+        executor.execute_fused(&program, vec![transposed.clone(), expanded.clone()], &fused).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&fused)),
+            vec![0.0, 18.0, 5.0, 32.0, 14.0, 50.0]
+        );
+
+        // Integer unary on a transposed view goes through the strided gather
+        let ints: Vec<u8> = (0..6i32).flat_map(|v| v.to_le_bytes()).collect();
+        let int_matrix = executor.tensor_from_data(ints, WasmDType::Int32, vec![2, 3].into_boxed_slice()).unwrap();
+        let int_transposed = executor.create_view_with_shape_and_strides(&int_matrix, &[3, 2], &[1, 3]);
+        let negated = executor.alloc_temp_tensor(WasmDType::Int32, vec![3, 2].into_boxed_slice()).unwrap();
+        executor.execute_unary(WasmOperation::Neg, &int_transposed, &negated).unwrap();
+        let negated: Vec<i32> = executor.copy_tensor_data_to_js(&negated)
+            .chunks_exact(4)
+            .map(|c| i32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect();
+        assert_eq!(negated, vec![0, -3, -1, -4, -2, -5]);
+    }
+
+    #[wasm_bindgen_test]
+    fn wasm_test_offset_outputs() {
+        let mut executor = WasmExecutor::new();
+        
+        let a = executor.tensor_from_data(f32_bytes(&[1.0, 2.0, 3.0]), WasmDType::Float32, vec![3].into_boxed_slice()).unwrap();
+        let ints: Vec<u8> = [7i32, 8, 9].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let int_row = executor.tensor_from_data(ints, WasmDType::Int32, vec![3].into_boxed_slice()).unwrap();
+        let destination = executor.tensor_from_data(f32_bytes(&[-1.0; 12]), WasmDType::Float32, vec![4, 3].into_boxed_slice()).unwrap();
+        let row = |index: usize| destination.create_view(WasmTensorMeta::new(WasmDType::Float32, vec![3], vec![1], 3, index * 3));
+        
+        // Each kernel writes only the row its output view points at
+        executor.execute_binary(WasmOperation::Add, &a, &a, &row(1)).unwrap();
+        executor.execute_unary(WasmOperation::Neg, &a, &row(2)).unwrap();
+        executor.execute_cast(&int_row, &row(3), WasmRoundingMode::Nearest, false).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&destination)),
+            vec![-1.0, -1.0, -1.0, 2.0, 4.0, 6.0, -1.0, -2.0, -3.0, 7.0, 8.0, 9.0]
+        );
+        
+        let mut program = WasmFusedProgram::new();
+        program.load(0);
+        program.unary(WasmOperation::Abs).unwrap();
+        executor.execute_fused(&program, vec![row(2)], &row(0)).unwrap();
+        assert_eq!(
+            bytes_to_f32(&executor.copy_tensor_data_to_js(&destination))[..3],
+            [1.0, 2.0, 3.0]
+        );
+        
+        // A transposed output is rejected rather than written as if it were dense
+        let matrix = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 3].into_boxed_slice()).unwrap();
+        let transposed = executor.create_view_with_shape_and_strides(&matrix, &[3, 2], &[1, 3]);
+        let b = executor.tensor_from_data(f32_bytes(&[0.0; 6]), WasmDType::Float32, vec![3, 2].into_boxed_slice()).unwrap();
+        assert!(executor.execute_binary(WasmOperation::Add, &b, &b, &transposed).is_err());
+        assert!(executor.execute_unary(WasmOperation::Neg, &b, &transposed).is_err());
+        assert!(executor.execute_cast(&b, &transposed, WasmRoundingMode::Nearest, false).is_err());
+        assert!(executor.execute_fused(&program, vec![b], &transposed).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_comparison_mask() {
+        let mut executor = WasmExecutor::new();
+        
+        // Causal mask: key position [1, 4] <= query position [4, 1]
+        let keys = executor.tensor_from_data(f32_bytes(&[0.0, 1.0, 2.0, 3.0]), WasmDType::Float32, vec![1, 4].into_boxed_slice()).unwrap();
+        let queries = executor.tensor_from_data(f32_bytes(&[0.0, 1.0, 2.0, 3.0]), WasmDType::Float32, vec![4, 1].into_boxed_slice()).unwrap();
+        let mask = executor.alloc_temp_tensor(WasmDType::Bool, vec![4, 4].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::Le, &keys, &queries, &mask).unwrap();
+        assert_eq!(
+            executor.copy_tensor_data_to_js(&mask),
+            vec![1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1]
+        );
+        
+        // Logical not of the mask, then combined with its transpose
+        let inverted = executor.alloc_temp_tensor(WasmDType::Bool, vec![4, 4].into_boxed_slice()).unwrap();
+        executor.execute_unary(WasmOperation::LogicalNot, &mask, &inverted).unwrap();
+        let transposed = executor.create_view_with_shape_and_strides(&mask, &[4, 4], &[1, 4]);
+        let either = executor.alloc_temp_tensor(WasmDType::Bool, vec![4, 4].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::LogicalAnd, &inverted, &transposed, &either).unwrap();
+        assert_eq!(
+            executor.copy_tensor_data_to_js(&either),
+            vec![0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0]
+        );
+        
+        // Long rows take the 16-wide SIMD path plus a scalar tail; NaN is never greater
+        let values: Vec<f32> = (0..37).map(|i| if i == 20 { f32::NAN } else { (i % 7) as f32 }).collect();
+        let long = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![37].into_boxed_slice()).unwrap();
+        let threshold = executor.tensor_from_data(f32_bytes(&[3.0]), WasmDType::Float32, vec![].into_boxed_slice()).unwrap();
+        let greater = executor.alloc_temp_tensor(WasmDType::Bool, vec![37].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::Gt, &long, &threshold, &greater).unwrap();
+        let expected: Vec<u8> = values.iter().map(|&v| (v > 3.0) as u8).collect();
+        assert_eq!(executor.copy_tensor_data_to_js(&greater), expected);
+        
+        // Comparisons require a Bool output
+        let wrong = executor.alloc_temp_tensor(WasmDType::Float32, vec![4, 4].into_boxed_slice()).unwrap();
+        assert!(executor.execute_binary(WasmOperation::Eq, &keys, &queries, &wrong).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_where() {
+        let mut executor = WasmExecutor::new();
+        
+        // Replace NaN with zero: where(x == x, x, 0)
+        let values = executor.tensor_from_data(f32_bytes(&[1.0, f32::NAN, -2.0, f32::NAN]), WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        let zero = executor.tensor_from_data(f32_bytes(&[0.0]), WasmDType::Float32, vec![].into_boxed_slice()).unwrap();
+        let is_number = executor.alloc_temp_tensor(WasmDType::Bool, vec![2, 2].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::Eq, &values, &values, &is_number).unwrap();
+        let cleaned = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        executor.execute_where(&is_number, &values, &zero, &cleaned).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&cleaned)), vec![1.0, 0.0, -2.0, 0.0]);
+        
+        // Captured in a graph and replayed with a row-broadcast condition
+        let condition = executor.tensor_from_data(vec![1, 0], WasmDType::Bool, vec![2].into_boxed_slice()).unwrap();
+        let ten = executor.tensor_from_data(f32_bytes(&[10.0]), WasmDType::Float32, vec![1].into_boxed_slice()).unwrap();
+        let picked = executor.alloc_temp_tensor(WasmDType::Float32, vec![2, 2].into_boxed_slice()).unwrap();
+        executor.begin_graph().unwrap();
+        executor.execute_where(&condition, &cleaned, &ten, &picked).unwrap();
+        executor.end_graph().unwrap();
+        executor.run_graph().unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&picked)), vec![1.0, 10.0, -2.0, 10.0]);
+        
+        // The condition must be Bool
+        assert!(executor.execute_where(&values, &values, &zero, &picked).is_err());
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_activations() {
+        use crate::fast_math::{self, UnaryKernel};
+        let mut executor = WasmExecutor::new();
+        executor.set_precision(WasmPrecision::Fast);
+        
+        // 11 elements: two f32x4 bodies plus a scalar tail, which must agree exactly
+        let values: Vec<f32> = (0..11).map(|i| i as f32 * 0.75 - 4.0).collect();
+        let input = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![11].into_boxed_slice()).unwrap();
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![11].into_boxed_slice()).unwrap();
+        let kernels: [(WasmOperation, &dyn UnaryKernel); 5] = [
+            (WasmOperation::Gelu, &fast_math::Gelu),
+            (WasmOperation::GeluTanh, &fast_math::GeluTanh),
+            (WasmOperation::Sigmoid, &fast_math::Sigmoid),
+            (WasmOperation::Tanh, &fast_math::Tanh),
+            (WasmOperation::Sign, &fast_math::Sign),
+        ];
+        for (operation, kernel) in kernels {
+            executor.execute_unary(operation, &input, &output).unwrap();
+            let expected: Vec<f32> = values.iter().map(|&x| kernel.scalar(x)).collect();
+            assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), expected, "{:?}", operation);
+        }
+        
+        // Integer relu stays Int32; sigmoid of an integer promotes
+        let ints: Vec<u8> = [-3i32, 0, 5].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let ints = executor.tensor_from_data(ints, WasmDType::Int32, vec![3].into_boxed_slice()).unwrap();
+        let relu = executor.alloc_temp_tensor(WasmDType::Int32, vec![3].into_boxed_slice()).unwrap();
+        executor.execute_unary(WasmOperation::Relu, &ints, &relu).unwrap();
+        let expected: Vec<u8> = [0i32, 0, 5].iter().flat_map(|v| v.to_le_bytes()).collect();
+        assert_eq!(executor.copy_tensor_data_to_js(&relu), expected);
+        assert!(executor.execute_unary(WasmOperation::Sigmoid, &ints, &relu).is_err());
+        
+        // Promoting Int8 to Float32 quadruples the bytes; an output of the wrong shape is rejected
+        let bytes = executor.tensor_from_data(vec![1, 4, 9, 16], WasmDType::Int8, vec![4].into_boxed_slice()).unwrap();
+        let short = executor.alloc_temp_tensor(WasmDType::Float32, vec![1].into_boxed_slice()).unwrap();
+        assert!(executor.execute_unary(WasmOperation::Sqrt, &bytes, &short).is_err());
+        let roots = executor.alloc_temp_tensor(WasmDType::Float32, vec![4].into_boxed_slice()).unwrap();
+        executor.execute_unary(WasmOperation::Sqrt, &bytes, &roots).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&roots)), vec![1.0, 2.0, 3.0, 4.0]);
+        
+        // Maximum against a broadcast scalar propagates NaN
+        let with_nan = executor.tensor_from_data(f32_bytes(&[-1.0, f32::NAN, 2.0, -3.0, 4.0, 0.5]), WasmDType::Float32, vec![6].into_boxed_slice()).unwrap();
+        let zero = executor.tensor_from_data(f32_bytes(&[0.0]), WasmDType::Float32, vec![].into_boxed_slice()).unwrap();
+        let clamped = executor.alloc_temp_tensor(WasmDType::Float32, vec![6].into_boxed_slice()).unwrap();
+        executor.execute_binary(WasmOperation::Maximum, &with_nan, &zero, &clamped).unwrap();
+        let result = bytes_to_f32(&executor.copy_tensor_data_to_js(&clamped));
+        assert!(result[1].is_nan());
+        assert_eq!([result[0], result[2], result[3], result[4], result[5]], [0.0, 2.0, 0.0, 4.0, 0.5]);
+    }
+
+    #[wasm_bindgen_test]
+    fn wasm_test_leaky_relu_slope() {
+        let mut executor = WasmExecutor::new();
+        let input = executor.tensor_from_data(f32_bytes(&[-2.0, -0.5, 0.0, 3.0]), WasmDType::Float32, vec![4].into_boxed_slice()).unwrap();
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![4].into_boxed_slice()).unwrap();
+
+        // Explicit slope, and the 0.01 default through execute_unary
+        executor.execute_leaky_relu(&input, &output, 0.2).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), vec![-0.4, -0.1, 0.0, 3.0]);
+        executor.execute_unary(WasmOperation::LeakyRelu, &input, &output).unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), vec![-0.02, -0.005, 0.0, 3.0]);
+
+        // Integer inputs promote to float and keep the slope
+        let ints: Vec<u8> = [-10i32, 4].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let ints = executor.tensor_from_data(ints, WasmDType::Int32, vec![2].into_boxed_slice()).unwrap();
+        let promoted = executor.alloc_temp_tensor(WasmDType::Float64, vec![2].into_boxed_slice()).unwrap();
+        executor.execute_leaky_relu(&ints, &promoted, 0.25).unwrap();
+        let expected: Vec<u8> = [-2.5f64, 4.0].iter().flat_map(|v| v.to_le_bytes()).collect();
+        assert_eq!(executor.copy_tensor_data_to_js(&promoted), expected);
+
+        // The slope is kept when the op is captured and replayed in a graph
+        let graph_output = executor.alloc_temp_tensor(WasmDType::Float32, vec![4].into_boxed_slice()).unwrap();
+        executor.begin_graph().unwrap();
+        executor.execute_leaky_relu(&input, &graph_output, 0.5).unwrap();
+        assert_eq!(executor.end_graph().unwrap(), 1);
+        executor.run_graph().unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&graph_output)), vec![-1.0, -0.25, 0.0, 3.0]);
+    }
+    
+    #[wasm_bindgen_test]
+    fn wasm_test_precision_modes() {
+        let mut executor = WasmExecutor::new();
+        assert_eq!(executor.precision(), WasmPrecision::Accurate);
+        
+        let values: Vec<f32> = (0..9).map(|i| i as f32 * 1.3 - 5.0).collect();
+        let input = executor.tensor_from_data(f32_bytes(&values), WasmDType::Float32, vec![9].into_boxed_slice()).unwrap();
+        let output = executor.alloc_temp_tensor(WasmDType::Float32, vec![9].into_boxed_slice()).unwrap();
+        let libm_f64 = |f: fn(f64) -> f64| -> Vec<f32> { values.iter().map(|&x| f(x as f64) as f32).collect() };
+        
+        // The default keeps libm's expf / logf; other functions are libm in f64, rounded once
+        executor.execute_unary(WasmOperation::Exp, &input, &output).unwrap();
+        let expf: Vec<f32> = values.iter().map(|&x| libm::expf(x)).collect();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), expf);
+        let positive = executor.tensor_from_data(f32_bytes(&[0.0, 0.3, 2.0, 1e30]), WasmDType::Float32, vec![4].into_boxed_slice()).unwrap();
+        let logs = executor.alloc_temp_tensor(WasmDType::Float32, vec![4].into_boxed_slice()).unwrap();
+        executor.execute_unary(WasmOperation::Log, &positive, &logs).unwrap();
+        let logf: Vec<f32> = [0.0f32, 0.3, 2.0, 1e30].iter().map(|&x| libm::logf(x)).collect();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&logs)), logf);
+        
+        // A graph recorded in accurate mode keeps it after switching back
+        executor.begin_graph().unwrap();
+        executor.execute_unary(WasmOperation::Sin, &input, &output).unwrap();
+        executor.end_graph().unwrap();
+        executor.set_precision(WasmPrecision::Fast);
+        executor.run_graph().unwrap();
+        assert_eq!(bytes_to_f32(&executor.copy_tensor_data_to_js(&output)), libm_f64(libm::sin));
+        
+        executor.execute_unary(WasmOperation::Sin, &input, &output).unwrap();
+        let fast = bytes_to_f32(&executor.copy_tensor_data_to_js(&output));
+        assert!(fast.iter().zip(libm_f64(libm::sin)).all(|(&a, b)| (a - b).abs() < 1e-5));
+    }
+}
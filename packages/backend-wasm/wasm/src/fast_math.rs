@@ -1,126 +1,125 @@
 /*!
  * Fast mathematical operations optimized for WASM
  * 
- * Provides lookup tables, approximations, and SIMD-optimized
- * implementations of common mathematical functions.
+ * Provides polynomial approximations of common mathematical functions,
+ * each with a scalar form and an f32x4 SIMD form that agree bit for bit.
+ *
+ * These back the opt-in `WasmPrecision::Fast`; the default
+ * `WasmPrecision::Accurate` uses libm instead (within 1 ULP). Maximum errors
+ * against an f64 reference, as
+ * asserted by the unary precision tests:
+ * - exp (-87..88), log (all positive floats incl. subnormals): 2 ULP
+ * - sin, cos (|x| <= 12800, where the π/2 reduction is exact): 2 ULP
+ * - tanh: 3 ULP; erf: 5 ULP; sigmoid: 2 ULP; silu: 3 ULP
+ * - gelu, gelu_tanh (-5..10): absolute error 4 · f32::EPSILON
  */
 
-use core::f32::consts::PI;
-use once_cell::sync::Lazy;
+use core::f32::consts::{FRAC_1_SQRT_2, FRAC_2_PI};
+
+#[cfg(target_feature = "simd128")]
+use std::arch::wasm32::*;
 
 // Lookup table sizes - power of 2 for fast modulo
-const TRIG_TABLE_SIZE: usize = 4096;
 const EXP_TABLE_SIZE: usize = 2048;
 const LOG_TABLE_SIZE: usize = 2048;
 
-// Pre-computed lookup tables - initialized once at runtime
-static SIN_TABLE: Lazy<Vec<f32>> = Lazy::new(|| {
-    (0..TRIG_TABLE_SIZE)
-        .map(|i| {
-            let angle = (i as f32) * 2.0 * PI / (TRIG_TABLE_SIZE as f32);
-            libm::sinf(angle)
-        })
-        .collect()
-});
-
-static COS_TABLE: Lazy<Vec<f32>> = Lazy::new(|| {
-    (0..TRIG_TABLE_SIZE)
-        .map(|i| {
-            let angle = (i as f32) * 2.0 * PI / (TRIG_TABLE_SIZE as f32);
-            libm::cosf(angle)
-        })
-        .collect()
-});
-
-/// Fast sine approximation using lookup table with linear interpolation
+/// π/2 in three parts for Cody-Waite reduction; the first two have trailing
+/// zero bits, so `q · part` is exact for quadrants |q| < 2^13
+const FRAC_PI_2_HI: f32 = 1.5703125;
+const FRAC_PI_2_MID: f32 = 4.837513e-4;
+const FRAC_PI_2_LO: f32 = 7.54979e-8;
+
+/// Cephes minimax coefficients of (sin(r) - r) / r³ and (cos(r) - 1 + r²/2) / r⁴
+/// in r² on [-π/4, π/4]
+const SIN_POLY: [f32; 3] = [-0.16666655, 0.008332161, -0.00019515296];
+const COS_POLY: [f32; 3] = [0.041666646, -0.0013887316, 2.4433157e-5];
+
+/// Largest and smallest arguments with a finite, non-zero `fast_exp_f32`
+const EXP_MAX: f32 = 88.72283;
+const EXP_MIN: f32 = -87.33655;
+
+/// ln(2) in two parts; LN_2_HI has trailing zero bits so `n · LN_2_HI` is exact
+const LN_2_HI: f32 = f32::from_bits(0x3f31_7200); // 0.693145751953125
+const LN_2_LO: f32 = 1.428607e-6;
+
+/// Taylor coefficients of e^r, to r⁷
+const EXP_SERIES: [f32; 8] = [1.0, 1.0, 0.5, 0.1666667, 0.04166667, 0.008333333, 0.001388889, 0.0001984127];
+
+/// Coefficients of atanh(s) / s in s²: 1, 1/3, 1/5, 1/7, 1/9
+const LOG_SERIES: [f32; 5] = [1.0, 1.0 / 3.0, 0.2, 1.0 / 7.0, 1.0 / 9.0];
+
+/// Fast sine: reduction to `r` in [-π/4, π/4] and quadrant, then a polynomial
 #[inline]
 pub fn fast_sin_f32(x: f32) -> f32 {
-    // Normalize angle to [0, 2*PI)
-    let normalized = x * (1.0 / (2.0 * PI));
-    let normalized = normalized - normalized.floor();
-    
-    // Convert to table index
-    let index_f = normalized * (TRIG_TABLE_SIZE as f32);
-    let index = index_f as usize;
-    let fract = index_f - (index as f32);
-    
-    // Linear interpolation between table entries
-    let idx0 = index % TRIG_TABLE_SIZE;
-    let idx1 = (index + 1) % TRIG_TABLE_SIZE;
-    
-    SIN_TABLE[idx0] * (1.0 - fract) + SIN_TABLE[idx1] * fract
+    let (quadrant, r) = reduce_quadrant(x);
+    sin_from_quadrant(quadrant, r)
 }
 
-/// Fast cosine approximation using lookup table with linear interpolation
+/// Fast cosine as the sine one quadrant ahead
 #[inline]
 pub fn fast_cos_f32(x: f32) -> f32 {
-    // Normalize angle to [0, 2*PI)
-    let normalized = x * (1.0 / (2.0 * PI));
-    let normalized = normalized - normalized.floor();
-    
-    // Convert to table index
-    let index_f = normalized * (TRIG_TABLE_SIZE as f32);
-    let index = index_f as usize;
-    let fract = index_f - (index as f32);
-    
-    // Linear interpolation between table entries
-    let idx0 = index % TRIG_TABLE_SIZE;
-    let idx1 = (index + 1) % TRIG_TABLE_SIZE;
-    
-    COS_TABLE[idx0] * (1.0 - fract) + COS_TABLE[idx1] * fract
+    let (quadrant, r) = reduce_quadrant(x);
+    sin_from_quadrant(quadrant.wrapping_add(1), r)
+}
+
+/// Nearest multiple `q` of π/2 to `x` and `r = x - q·π/2`
+#[inline]
+fn reduce_quadrant(x: f32) -> (i32, f32) {
+    let q = (x * FRAC_2_PI).round_ties_even();
+    let r = ((x - q * FRAC_PI_2_HI) - q * FRAC_PI_2_MID) - q * FRAC_PI_2_LO;
+    (q as i32, r)
+}
+
+/// `sin(r + quadrant·π/2)` for reduced `r`
+#[inline]
+fn sin_from_quadrant(quadrant: i32, r: f32) -> f32 {
+    let z = r * r;
+    let value = if quadrant & 1 == 0 {
+        polynomial(&SIN_POLY, z) * z * r + r
+    } else {
+        polynomial(&COS_POLY, z) * z * z - 0.5 * z + 1.0
+    };
+    if quadrant & 2 == 0 { value } else { -value }
 }
 
 /// Fast exponential approximation using range reduction and polynomial
+///
+/// `e^x = 2^n · e^r` with `n = round(x·log2(e))` and `r = x - n·ln(2)`
+/// computed in two parts (Cody-Waite), so `|r| <= ln(2)/2` and a degree-7
+/// Taylor polynomial of `e^r` is accurate to a few ULP.
 #[inline]
 pub fn fast_exp_f32(x: f32) -> f32 {
     // Handle special cases
     if x.is_nan() {
         return x;
     }
-    if x > 88.72283 {
+    if x > EXP_MAX {
         return f32::INFINITY;
     }
-    if x < -87.33655 {
+    if x < EXP_MIN {
         return 0.0;
     }
     
-    // Range reduction: e^x = 2^(x/ln(2)) = 2^(n + f) where n is integer, 0 <= f < 1
-    const LOG2_E: f32 = 1.4426950408889634;
-    let x_scaled = x * LOG2_E;
-    let n = x_scaled.floor();
-    let f = x_scaled - n;
-    
-    // Higher precision polynomial approximation of 2^f for f in [0, 1]
-    // Using a 6th order polynomial for better accuracy
-    let f2 = f * f;
-    let f3 = f2 * f;
-    let f4 = f2 * f2;
-    let f5 = f4 * f;
-    let f6 = f3 * f3;
-    
-    let p = 1.0 
-        + f * 0.6931471805599453
-        + f2 * 0.2402265069591007
-        + f3 * 0.05550410866482158
-        + f4 * 0.009618129842128887
-        + f5 * 0.001333355814670577
-        + f6 * 0.0001540353039338161;
-    
-    // Reconstruct: 2^n * 2^f
-    if n >= -126.0 && n <= 127.0 {
-        // Fast path: use bit manipulation for 2^n
-        let n_int = n as i32;
-        let bits = ((n_int + 127) << 23) as u32;
-        let scale = f32::from_bits(bits);
-        p * scale
-    } else if n < -126.0 {
-        0.0 // Underflow
-    } else {
-        f32::INFINITY // Overflow
-    }
+    // Range reduction
+    let n = (x * core::f32::consts::LOG2_E).round_ties_even();
+    let r = (x - n * LN_2_HI) - n * LN_2_LO;
+    
+    // Taylor polynomial of e^r, Horner form
+    let p = polynomial(&EXP_SERIES, r);
+    
+    // Reconstruct: 2^n * 2^f, with 2^n split in two normal factors so n = 128
+    // (just below overflow) and n <= -126 (results near underflow) stay exact
+    let n_int = n as i32;
+    let n_half = n_int >> 1;
+    let scale_a = f32::from_bits(((n_half + 127) << 23) as u32);
+    let scale_b = f32::from_bits(((n_int - n_half + 127) << 23) as u32);
+    p * scale_a * scale_b
 }
 
 /// Fast natural logarithm approximation
+///
+/// `x = 2^e · m` with `m` in `[√½, √2)`, then `ln(m) = 2·atanh(s)` with
+/// `s = (m - 1) / (m + 1)`, `|s| <= 0.172`, from its odd series to `s⁹`.
 #[inline]
 pub fn fast_log_f32(x: f32) -> f32 {
     if x <= 0.0 {
@@ -136,26 +135,23 @@ pub fn fast_log_f32(x: f32) -> f32 {
         return x;
     }
     
-    // Extract exponent and mantissa
+    // Normalize subnormals so the exponent field is meaningful
+    let (x, bias) = if x < f32::MIN_POSITIVE { (x * 8388608.0, 23) } else { (x, 0) };
+    
+    // Extract exponent and mantissa in [1, 2), then move the mantissa to [√½, √2)
     let bits = x.to_bits();
-    let exponent = ((bits >> 23) & 0xFF) as i32 - 127;
-    let mantissa_bits = (bits & 0x007FFFFF) | 0x3F800000;
-    let mantissa = f32::from_bits(mantissa_bits);
-    
-    // Higher precision polynomial approximation of ln(m) for m in [1, 2)
-    // Using minimax polynomial coefficients
-    let m = mantissa;
-    let m_minus_1 = m - 1.0;
-    
-    let ln_m = m_minus_1 * (1.0
-        - m_minus_1 * (0.5
-        - m_minus_1 * (0.33333333333333
-        - m_minus_1 * (0.25
-        - m_minus_1 * (0.2
-        - m_minus_1 * 0.16666666666667)))));
+    let mut exponent = ((bits >> 23) & 0xFF) as i32 - 127 - bias;
+    let mut mantissa = f32::from_bits((bits & 0x007FFFFF) | 0x3F800000);
+    if mantissa > core::f32::consts::SQRT_2 {
+        mantissa *= 0.5;
+        exponent += 1;
+    }
+    
+    let s = (mantissa - 1.0) / (mantissa + 1.0);
+    let ln_m = 2.0 * s * polynomial(&LOG_SERIES, s * s);
     
     // Reconstruct: ln(x) = ln(2) * exponent + ln(mantissa)
-    const LN_2: f32 = 0.6931471805599453;
+    const LN_2: f32 = core::f32::consts::LN_2;
     (exponent as f32) * LN_2 + ln_m
 }
 
@@ -181,53 +177,641 @@ pub fn fast_sqrt_f32(x: f32) -> f32 {
     guess
 }
 
-/// SIMD-optimized trigonometric operations
+/// |x| below which tanh uses its Taylor series (avoids cancellation in `1 - 2/(e^2x + 1)`)
+const TANH_SMALL: f32 = 0.25;
+
+/// Taylor coefficients of tanh(x)/x in x²: 1, -1/3, 2/15, -17/315, 62/2835
+const TANH_SERIES: [f32; 5] = [1.0, -0.3333333, 0.1333333, -0.05396825, 0.02186949];
+
+/// |x| below which erf uses its Taylor series (A&S 7.1.26 is only accurate in absolute terms)
+const ERF_SMALL: f32 = 0.5;
+
+/// Taylor coefficients of erf(x)/x in x²: 2/√π · (1, -1/3, 1/10, -1/42, 1/216, -1/1320)
+const ERF_SERIES: [f32; 6] = [core::f32::consts::FRAC_2_SQRT_PI, -0.3761264, 0.1128379, -0.02686617, 0.005223977, -0.0008548327];
+
+/// sqrt(2 / pi), for the tanh approximation of GELU
+pub(crate) const SQRT_2_OVER_PI: f32 = 0.7978846;
+
+/// Abramowitz & Stegun 7.1.26 coefficients for erf (|error| <= 1.5e-7)
+const ERF_P: f32 = 0.3275911;
+const ERF_A: [f32; 5] = [0.2548296, -0.2844967, 1.421414, -1.453152, 1.061405];
+
+/// Fast logistic sigmoid `1 / (1 + e^-x)`
+#[inline]
+pub fn fast_sigmoid_f32(x: f32) -> f32 {
+    1.0 / (1.0 + fast_exp_f32(-x))
+}
+
+/// Fast hyperbolic tangent via `1 - 2 / (e^2|x| + 1)`, odd-extended
+#[inline]
+pub fn fast_tanh_f32(x: f32) -> f32 {
+    let ax = x.abs();
+    let result = if ax < TANH_SMALL {
+        ax * polynomial(&TANH_SERIES, ax * ax)
+    } else {
+        1.0 - 2.0 / (fast_exp_f32(ax + ax) + 1.0)
+    };
+    result.copysign(x)
+}
+
+/// Fast error function (Abramowitz & Stegun 7.1.26), odd-extended
+#[inline]
+pub fn fast_erf_f32(x: f32) -> f32 {
+    let ax = x.abs();
+    if ax < ERF_SMALL {
+        return x * polynomial(&ERF_SERIES, x * x);
+    }
+    let t = 1.0 / (1.0 + ERF_P * ax);
+    let poly = t * (ERF_A[0] + t * (ERF_A[1] + t * (ERF_A[2] + t * (ERF_A[3] + t * ERF_A[4]))));
+    (1.0 - poly * fast_exp_f32(-(ax * ax))).copysign(x)
+}
+
+/// Polynomial in `x` with `coefficients` in increasing order (Horner form)
+#[inline]
+fn polynomial(coefficients: &[f32], x: f32) -> f32 {
+    let (&last, rest) = coefficients.split_last().unwrap();
+    rest.iter().rev().fold(last, |acc, &c| c + x * acc)
+}
+
+/// Fast GELU `x/2 · (1 + erf(x/√2))`
+#[inline]
+pub fn fast_gelu_f32(x: f32) -> f32 {
+    0.5 * x * (1.0 + fast_erf_f32(x * FRAC_1_SQRT_2))
+}
+
+/// Fast tanh-approximated GELU `x/2 · (1 + tanh(√(2/π) · (x + 0.044715 x³)))`
+#[inline]
+pub fn fast_gelu_tanh_f32(x: f32) -> f32 {
+    let inner = SQRT_2_OVER_PI * (x + 0.044715 * (x * x * x));
+    0.5 * x * (1.0 + fast_tanh_f32(inner))
+}
+
+/// Fast SiLU (swish) `x / (1 + e^-x)`
+#[inline]
+pub fn fast_silu_f32(x: f32) -> f32 {
+    x / (1.0 + fast_exp_f32(-x))
+}
+
+/// Element-wise f32 function with a scalar form and an f32x4 form
+///
+/// Both forms perform the same f32 operations in the same order, so results
+/// do not depend on where the SIMD body ends and the scalar tail begins.
+pub trait UnaryKernel {
+    /// Value for a single element
+    fn scalar(&self, x: f32) -> f32;
+
+    /// Values for four packed elements
+    #[cfg(target_feature = "simd128")]
+    fn lanes(&self, x: v128) -> v128;
+
+    /// Apply to every element: f32x4 body (with SIMD enabled), scalar tail
+    #[inline]
+    fn apply(&self, input: &[f32], output: &mut [f32]) {
+        debug_assert_eq!(input.len(), output.len());
+
+        #[cfg(target_feature = "simd128")]
+        let done = {
+            let chunks = input.len() / 4;
+            for i in 0..chunks {
+                unsafe {
+                    let x = v128_load(input.as_ptr().add(i * 4) as *const v128);
+                    v128_store(output.as_mut_ptr().add(i * 4) as *mut v128, self.lanes(x));
+                }
+            }
+            chunks * 4
+        };
+        #[cfg(not(target_feature = "simd128"))]
+        let done = 0;
+
+        for (out, &x) in output[done..].iter_mut().zip(&input[done..]) {
+            *out = self.scalar(x);
+        }
+    }
+}
+
+/// Apply a scalar function to each lane (for functions without a vector form)
+#[cfg(target_feature = "simd128")]
+#[inline]
+fn lanewise(x: v128, f: impl Fn(f32) -> f32) -> v128 {
+    f32x4(
+        f(f32x4_extract_lane::<0>(x)),
+        f(f32x4_extract_lane::<1>(x)),
+        f(f32x4_extract_lane::<2>(x)),
+        f(f32x4_extract_lane::<3>(x)),
+    )
+}
+
+/// Magnitude of `magnitude` with the sign of `sign`, per lane
+#[cfg(target_feature = "simd128")]
+#[inline]
+fn copysign_lanes(magnitude: v128, sign: v128) -> v128 {
+    let sign_bit = f32x4_splat(-0.0);
+    v128_or(v128_andnot(magnitude, sign_bit), v128_and(sign, sign_bit))
+}
+
+/// e^x per lane
+#[cfg(target_feature = "simd128")]
+#[inline]
+fn exp_lanes(x: v128) -> v128 {
+    simd::simd_exp_f32x4(x)
+}
+
+/// See `fast_exp_f32`
+pub struct Exp;
+
+impl UnaryKernel for Exp {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_exp_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        simd::simd_exp_f32x4(x)
+    }
+}
+
+/// See `fast_log_f32`
+pub struct Log;
+
+impl UnaryKernel for Log {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_log_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        simd::simd_log_f32x4(x)
+    }
+}
+
+/// See `fast_sin_f32`
+pub struct Sin;
+
+impl UnaryKernel for Sin {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_sin_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        simd::simd_sin_f32x4(x)
+    }
+}
+
+/// See `fast_cos_f32`
+pub struct Cos;
+
+impl UnaryKernel for Cos {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_cos_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        simd::simd_cos_f32x4(x)
+    }
+}
+
+/// max(x, 0), keeping NaN
+pub struct Relu;
+
+impl UnaryKernel for Relu {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        if x < 0.0 { 0.0 } else { x }
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        let zero = f32x4_splat(0.0);
+        v128_bitselect(zero, x, f32x4_lt(x, zero))
+    }
+}
+
+/// `x` for x >= 0, `negative_slope · x` otherwise
+pub struct LeakyRelu {
+    pub negative_slope: f32,
+}
+
+impl UnaryKernel for LeakyRelu {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        if x < 0.0 { x * self.negative_slope } else { x }
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        let scaled = f32x4_mul(x, f32x4_splat(self.negative_slope));
+        v128_bitselect(scaled, x, f32x4_lt(x, f32x4_splat(0.0)))
+    }
+}
+
+/// See `fast_sigmoid_f32`
+pub struct Sigmoid;
+
+impl UnaryKernel for Sigmoid {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_sigmoid_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        let one = f32x4_splat(1.0);
+        f32x4_div(one, f32x4_add(one, exp_lanes(f32x4_neg(x))))
+    }
+}
+
+/// See `fast_tanh_f32`
+pub struct Tanh;
+
+impl UnaryKernel for Tanh {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_tanh_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        let one = f32x4_splat(1.0);
+        let ax = f32x4_abs(x);
+        let small = f32x4_mul(ax, polynomial_lanes(&TANH_SERIES, f32x4_mul(ax, ax)));
+        let large = f32x4_sub(one, f32x4_div(f32x4_splat(2.0), f32x4_add(exp_lanes(f32x4_add(ax, ax)), one)));
+        copysign_lanes(v128_bitselect(small, large, f32x4_lt(ax, f32x4_splat(TANH_SMALL))), x)
+    }
+}
+
+/// See `fast_erf_f32`
+pub struct Erf;
+
+impl UnaryKernel for Erf {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_erf_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        erf_lanes(x)
+    }
+}
+
+#[cfg(target_feature = "simd128")]
+#[inline]
+fn erf_lanes(x: v128) -> v128 {
+    let one = f32x4_splat(1.0);
+    let ax = f32x4_abs(x);
+    let t = f32x4_div(one, f32x4_add(one, f32x4_mul(f32x4_splat(ERF_P), ax)));
+    let mut poly = f32x4_splat(ERF_A[4]);
+    for &coefficient in ERF_A[..4].iter().rev() {
+        poly = f32x4_add(f32x4_splat(coefficient), f32x4_mul(t, poly));
+    }
+    poly = f32x4_mul(t, poly);
+    let decay = exp_lanes(f32x4_neg(f32x4_mul(ax, ax)));
+    let large = copysign_lanes(f32x4_sub(one, f32x4_mul(poly, decay)), x);
+    let small = f32x4_mul(x, polynomial_lanes(&ERF_SERIES, f32x4_mul(x, x)));
+    v128_bitselect(small, large, f32x4_lt(ax, f32x4_splat(ERF_SMALL)))
+}
+
+/// `polynomial` per lane
+#[cfg(target_feature = "simd128")]
+#[inline]
+fn polynomial_lanes(coefficients: &[f32], x: v128) -> v128 {
+    let (&last, rest) = coefficients.split_last().unwrap();
+    rest.iter().rev().fold(f32x4_splat(last), |acc, &c| f32x4_add(f32x4_splat(c), f32x4_mul(x, acc)))
+}
+
+#[cfg(target_feature = "simd128")]
+#[inline]
+fn tanh_lanes(x: v128) -> v128 {
+    Tanh.lanes(x)
+}
+
+/// See `fast_gelu_f32`
+pub struct Gelu;
+
+impl UnaryKernel for Gelu {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_gelu_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        let erf = erf_lanes(f32x4_mul(x, f32x4_splat(FRAC_1_SQRT_2)));
+        f32x4_mul(f32x4_mul(f32x4_splat(0.5), x), f32x4_add(f32x4_splat(1.0), erf))
+    }
+}
+
+/// See `fast_gelu_tanh_f32`
+pub struct GeluTanh;
+
+impl UnaryKernel for GeluTanh {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_gelu_tanh_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        let cube = f32x4_mul(f32x4_mul(x, x), x);
+        let inner = f32x4_mul(f32x4_splat(SQRT_2_OVER_PI), f32x4_add(x, f32x4_mul(f32x4_splat(0.044715), cube)));
+        f32x4_mul(f32x4_mul(f32x4_splat(0.5), x), f32x4_add(f32x4_splat(1.0), tanh_lanes(inner)))
+    }
+}
+
+/// See `fast_silu_f32`
+pub struct Silu;
+
+impl UnaryKernel for Silu {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        fast_silu_f32(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        f32x4_div(x, f32x4_add(f32x4_splat(1.0), exp_lanes(f32x4_neg(x))))
+    }
+}
+
+/// 1 / x
+pub struct Reciprocal;
+
+impl UnaryKernel for Reciprocal {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        1.0 / x
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        f32x4_div(f32x4_splat(1.0), x)
+    }
+}
+
+/// 1 / sqrt(x) (both steps correctly rounded)
+pub struct Rsqrt;
+
+impl UnaryKernel for Rsqrt {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        1.0 / x.sqrt()
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        f32x4_div(f32x4_splat(1.0), f32x4_sqrt(x))
+    }
+}
+
+/// Round toward negative infinity
+pub struct Floor;
+
+impl UnaryKernel for Floor {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        x.floor()
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        f32x4_floor(x)
+    }
+}
+
+/// Round toward positive infinity
+pub struct Ceil;
+
+impl UnaryKernel for Ceil {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        x.ceil()
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        f32x4_ceil(x)
+    }
+}
+
+/// Round to nearest, ties to even (NumPy/PyTorch `round`)
+pub struct Round;
+
+impl UnaryKernel for Round {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        x.round_ties_even()
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        f32x4_nearest(x)
+    }
+}
+
+/// -1, 0 or 1 by sign; zeros and NaN are returned unchanged
+pub struct Sign;
+
+impl UnaryKernel for Sign {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        if x > 0.0 {
+            1.0
+        } else if x < 0.0 {
+            -1.0
+        } else {
+            x
+        }
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        let zero = f32x4_splat(0.0);
+        let positive = f32x4_gt(x, zero);
+        let negative = f32x4_lt(x, zero);
+        let signs = v128_or(v128_and(positive, f32x4_splat(1.0)), v128_and(negative, f32x4_splat(-1.0)));
+        v128_bitselect(signs, x, v128_or(positive, negative))
+    }
+}
+
+/// Tangent (libm; a lookup-table ratio is too inaccurate near the poles)
+pub struct Tan;
+
+impl UnaryKernel for Tan {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        libm::tanf(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        lanewise(x, libm::tanf)
+    }
+}
+
+/// ln(1 + x) (libm: accurate for tiny x, which is the point of log1p)
+pub struct Log1p;
+
+impl UnaryKernel for Log1p {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        libm::log1pf(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        lanewise(x, libm::log1pf)
+    }
+}
+
+/// e^x - 1 (libm: accurate for tiny x, which is the point of expm1)
+pub struct Expm1;
+
+impl UnaryKernel for Expm1 {
+    #[inline]
+    fn scalar(&self, x: f32) -> f32 {
+        libm::expm1f(x)
+    }
+
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn lanes(&self, x: v128) -> v128 {
+        lanewise(x, libm::expm1f)
+    }
+}
+
+/// f32x4 forms of the transcendental functions
+///
+/// Each performs the same f32 operations as its scalar counterpart, with
+/// branches replaced by lane selects.
 pub mod simd {
     use super::*;
-    use std::arch::wasm32::*;
     
-    /// Process 4 sine values at once using SIMD
+    /// `fast_sin_f32` on four lanes
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    pub fn simd_sin_f32x4(input: v128) -> v128 {
+        let (quadrant, r) = reduce_quadrant_lanes(input);
+        sin_from_quadrant_lanes(quadrant, r)
+    }
+    
+    /// `fast_cos_f32` on four lanes
     #[cfg(target_feature = "simd128")]
-    pub unsafe fn simd_sin_f32x4(input: v128) -> v128 {
-        // Extract individual values
-        let a = f32x4_extract_lane::<0>(input);
-        let b = f32x4_extract_lane::<1>(input);
-        let c = f32x4_extract_lane::<2>(input);
-        let d = f32x4_extract_lane::<3>(input);
+    #[inline]
+    pub fn simd_cos_f32x4(input: v128) -> v128 {
+        let (quadrant, r) = reduce_quadrant_lanes(input);
+        sin_from_quadrant_lanes(i32x4_add(quadrant, i32x4_splat(1)), r)
+    }
+    
+    /// `reduce_quadrant` per lane, with quadrants as i32x4
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn reduce_quadrant_lanes(x: v128) -> (v128, v128) {
+        let q = f32x4_nearest(f32x4_mul(x, f32x4_splat(FRAC_2_PI)));
+        let r = f32x4_sub(x, f32x4_mul(q, f32x4_splat(FRAC_PI_2_HI)));
+        let r = f32x4_sub(r, f32x4_mul(q, f32x4_splat(FRAC_PI_2_MID)));
+        let r = f32x4_sub(r, f32x4_mul(q, f32x4_splat(FRAC_PI_2_LO)));
+        (i32x4_trunc_sat_f32x4(q), r)
+    }
+    
+    /// `sin_from_quadrant` per lane
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn sin_from_quadrant_lanes(quadrant: v128, r: v128) -> v128 {
+        let z = f32x4_mul(r, r);
+        let sin = f32x4_add(f32x4_mul(f32x4_mul(polynomial_lanes(&SIN_POLY, z), z), r), r);
+        let cos = f32x4_mul(f32x4_mul(polynomial_lanes(&COS_POLY, z), z), z);
+        let cos = f32x4_add(f32x4_sub(cos, f32x4_mul(f32x4_splat(0.5), z)), f32x4_splat(1.0));
+        
+        // Odd quadrants take the cosine, quadrants 2 and 3 flip the sign
+        let odd = i32x4_eq(v128_and(quadrant, i32x4_splat(1)), i32x4_splat(1));
+        let sign = i32x4_shl(v128_and(quadrant, i32x4_splat(2)), 30);
+        v128_xor(v128_bitselect(cos, sin, odd), sign)
+    }
+    
+    /// `fast_exp_f32` on four lanes
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    pub fn simd_exp_f32x4(input: v128) -> v128 {
+        // Evaluate on the clamped argument (NaN passes through), patch overflow after
+        let max = f32x4_splat(EXP_MAX);
+        let min = f32x4_splat(EXP_MIN);
+        let x = f32x4_pmax(f32x4_pmin(input, max), min);
+        
+        let n = f32x4_nearest(f32x4_mul(x, f32x4_splat(core::f32::consts::LOG2_E)));
+        let r = f32x4_sub(x, f32x4_mul(n, f32x4_splat(LN_2_HI)));
+        let r = f32x4_sub(r, f32x4_mul(n, f32x4_splat(LN_2_LO)));
         
-        // Apply fast sine
-        let result_a = fast_sin_f32(a);
-        let result_b = fast_sin_f32(b);
-        let result_c = fast_sin_f32(c);
-        let result_d = fast_sin_f32(d);
+        let p = polynomial_lanes(&EXP_SERIES, r);
         
-        // Pack results
-        f32x4(result_a, result_b, result_c, result_d)
+        let n_int = i32x4_trunc_sat_f32x4(n);
+        let n_half = i32x4_shr(n_int, 1);
+        let bias = i32x4_splat(127);
+        let scale_a = i32x4_shl(i32x4_add(n_half, bias), 23);
+        let scale_b = i32x4_shl(i32x4_add(i32x4_sub(n_int, n_half), bias), 23);
+        let result = f32x4_mul(f32x4_mul(p, scale_a), scale_b);
+        
+        let result = v128_bitselect(f32x4_splat(f32::INFINITY), result, f32x4_gt(input, max));
+        v128_andnot(result, f32x4_lt(input, min))
     }
     
-    /// Process 4 exponential values at once using SIMD
+    /// `fast_log_f32` on four lanes
     #[cfg(target_feature = "simd128")]
-    pub unsafe fn simd_exp_f32x4(input: v128) -> v128 {
-        // Extract individual values
-        let a = f32x4_extract_lane::<0>(input);
-        let b = f32x4_extract_lane::<1>(input);
-        let c = f32x4_extract_lane::<2>(input);
-        let d = f32x4_extract_lane::<3>(input);
+    #[inline]
+    pub fn simd_log_f32x4(input: v128) -> v128 {
+        let one = f32x4_splat(1.0);
+        
+        // Normalize subnormals, then split into exponent and mantissa in [√½, √2)
+        let subnormal = f32x4_lt(input, f32x4_splat(f32::MIN_POSITIVE));
+        let x = v128_bitselect(f32x4_mul(input, f32x4_splat(8388608.0)), input, subnormal);
+        let exponent = v128_and(u32x4_shr(x, 23), i32x4_splat(0xFF));
+        let exponent = i32x4_sub(i32x4_sub(exponent, i32x4_splat(127)), v128_and(subnormal, i32x4_splat(23)));
+        let mantissa = v128_or(v128_and(x, i32x4_splat(0x007FFFFF)), i32x4_splat(0x3F800000));
+        let upper = f32x4_gt(mantissa, f32x4_splat(core::f32::consts::SQRT_2));
+        let mantissa = v128_bitselect(f32x4_mul(mantissa, f32x4_splat(0.5)), mantissa, upper);
+        let exponent = i32x4_sub(exponent, upper);
         
-        // Apply fast exponential
-        let result_a = fast_exp_f32(a);
-        let result_b = fast_exp_f32(b);
-        let result_c = fast_exp_f32(c);
-        let result_d = fast_exp_f32(d);
+        let s = f32x4_div(f32x4_sub(mantissa, one), f32x4_add(mantissa, one));
+        let ln_m = f32x4_mul(f32x4_mul(f32x4_splat(2.0), s), polynomial_lanes(&LOG_SERIES, f32x4_mul(s, s)));
+        let result = f32x4_add(f32x4_mul(f32x4_convert_i32x4(exponent), f32x4_splat(core::f32::consts::LN_2)), ln_m);
         
-        // Pack results
-        f32x4(result_a, result_b, result_c, result_d)
+        // ln(0) = -inf, ln(inf) = inf, negative and NaN inputs give NaN
+        let zero = f32x4_splat(0.0);
+        let result = v128_bitselect(f32x4_splat(f32::NEG_INFINITY), result, f32x4_eq(input, zero));
+        let result = v128_bitselect(input, result, f32x4_eq(input, f32x4_splat(f32::INFINITY)));
+        v128_bitselect(f32x4_splat(f32::NAN), result, v128_not(f32x4_ge(input, zero)))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::f32::consts::PI;
     
     #[test]
     fn test_fast_sin() {
@@ -236,6 +820,13 @@ mod tests {
         assert!((fast_sin_f32(PI / 2.0) - 1.0).abs() < 0.001);
         assert!((fast_sin_f32(PI) - 0.0).abs() < 0.001);
         assert!((fast_sin_f32(3.0 * PI / 2.0) - (-1.0)).abs() < 0.001);
+
+        // Every quadrant, including negative ones, for both functions
+        for i in -12..=12 {
+            let x = i as f32 * 0.7;
+            assert!((fast_sin_f32(x) - libm::sinf(x)).abs() < 1e-6, "sin({})", x);
+            assert!((fast_cos_f32(x) - libm::cosf(x)).abs() < 1e-6, "cos({})", x);
+        }
     }
     
     #[test]
@@ -243,12 +834,94 @@ mod tests {
         assert!((fast_exp_f32(0.0) - 1.0).abs() < 0.001);
         assert!((fast_exp_f32(1.0) - std::f32::consts::E).abs() < 0.01);
         assert!((fast_exp_f32(-1.0) - (1.0 / std::f32::consts::E)).abs() < 0.01);
+
+        // Both ends of the finite range
+        assert!(fast_exp_f32(88.7).is_finite());
+        assert!(fast_exp_f32(-87.3) > 0.0);
     }
     
+    #[test]
+    fn test_activations_against_libm() {
+        // ULP bounds against f64 are asserted by the unary precision tests
+        let xs: Vec<f32> = (-80..=80).map(|i| i as f32 * 0.1).collect();
+        for &x in &xs {
+            let sigmoid = 1.0 / (1.0 + libm::expf(-x));
+            assert!((fast_sigmoid_f32(x) - sigmoid).abs() < 1e-6, "sigmoid({})", x);
+            assert!((fast_tanh_f32(x) - libm::tanhf(x)).abs() < 1e-6, "tanh({})", x);
+            assert!((fast_erf_f32(x) - libm::erff(x)).abs() < 1e-6, "erf({})", x);
+            assert!((fast_silu_f32(x) - x * sigmoid).abs() < 1e-5, "silu({})", x);
+
+            let gelu = 0.5 * x * (1.0 + libm::erff(x * FRAC_1_SQRT_2));
+            assert!((fast_gelu_f32(x) - gelu).abs() < 1e-6, "gelu({})", x);
+            assert!((fast_gelu_tanh_f32(x) - gelu).abs() < 1e-3, "gelu_tanh({})", x);
+        }
+
+        // Odd functions keep tiny arguments accurate and saturate cleanly
+        assert_eq!(fast_tanh_f32(1e-6), 1e-6);
+        assert_eq!(fast_tanh_f32(-100.0), -1.0);
+        assert_eq!(fast_sigmoid_f32(-200.0), 0.0);
+        assert!(fast_erf_f32(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_unary_kernels() {
+        let input = [-2.5f32, -0.5, -0.0, 0.0, 0.5, 1.5, 2.5, f32::NAN, 4.0];
+        let run = |kernel: &dyn Fn(&[f32], &mut [f32])| {
+            let mut output = [0.0f32; 9];
+            kernel(&input, &mut output);
+            output
+        };
+
+        let relu = run(&|i, o| Relu.apply(i, o));
+        assert_eq!(&relu[..7], &[0.0, 0.0, -0.0, 0.0, 0.5, 1.5, 2.5]);
+        assert!(relu[7].is_nan());
+
+        let leaky = run(&|i, o| LeakyRelu { negative_slope: 0.1 }.apply(i, o));
+        assert_eq!(&leaky[..2], &[-0.25, -0.05]);
+
+        assert_eq!(&run(&|i, o| Round.apply(i, o))[..7], &[-2.0, -0.0, -0.0, 0.0, 0.0, 2.0, 2.0]);
+        assert_eq!(&run(&|i, o| Floor.apply(i, o))[..7], &[-3.0, -1.0, -0.0, 0.0, 0.0, 1.0, 2.0]);
+        assert_eq!(&run(&|i, o| Ceil.apply(i, o))[..7], &[-2.0, -0.0, -0.0, 0.0, 1.0, 2.0, 3.0]);
+
+        let sign = run(&|i, o| Sign.apply(i, o));
+        assert_eq!(&sign[..7], &[-1.0, -1.0, -0.0, 0.0, 1.0, 1.0, 1.0]);
+        assert!(sign[2].is_sign_negative() && sign[7].is_nan());
+
+        assert_eq!(run(&|i, o| Rsqrt.apply(i, o))[8], 0.5);
+        assert_eq!(run(&|i, o| Reciprocal.apply(i, o))[8], 0.25);
+        assert!((run(&|i, o| Expm1.apply(i, o))[4] - libm::expm1f(0.5)).abs() == 0.0);
+    }
+
+    #[test]
+    fn test_transcendental_kernels_match_scalar() {
+        // 4k + 3 elements so both the f32x4 body and the scalar tail run
+        let mut input: Vec<f32> = (0..399).map(|i| (i as f32 - 199.0) * 0.37).collect();
+        input.extend([0.0, -0.0, 1e-30, f32::from_bits(1), 88.8, -87.5, f32::INFINITY, f32::NEG_INFINITY, f32::NAN]);
+        input.truncate(input.len() / 4 * 4 + 3);
+
+        let check = |kernel: &dyn UnaryKernel, scalar: fn(f32) -> f32| {
+            let mut output = vec![0.0f32; input.len()];
+            kernel.apply(&input, &mut output);
+            for (&x, &y) in input.iter().zip(&output) {
+                let expected = scalar(x);
+                assert!(y == expected || (y.is_nan() && expected.is_nan()), "f({}) = {}, scalar {}", x, y, expected);
+            }
+        };
+        check(&Exp, fast_exp_f32);
+        check(&Log, fast_log_f32);
+        check(&Sin, fast_sin_f32);
+        check(&Cos, fast_cos_f32);
+        check(&Tanh, fast_tanh_f32);
+        check(&Sigmoid, fast_sigmoid_f32);
+    }
+
     #[test]
     fn test_fast_log() {
         assert!((fast_log_f32(1.0) - 0.0).abs() < 0.001);
         assert!((fast_log_f32(std::f32::consts::E) - 1.0).abs() < 0.01);
         assert!((fast_log_f32(10.0) - 2.302585).abs() < 0.01);
+
+        // Subnormal inputs are normalized before the exponent is read
+        assert!((fast_log_f32(f32::from_bits(1)) - libm::logf(f32::from_bits(1))).abs() < 1e-5);
     }
 }
\ No newline at end of file
@@ -0,0 +1,384 @@
+/*!
+ * Lazy operation graph capture for WASM tensor operations
+ *
+ * Records a sequence of executor calls into an in-Rust graph so that:
+ * - The whole sequence runs in a single JS → WASM call (`run_graph`)
+ * - The pattern cache sees a real multi-op sequence instead of single ops
+ * - Later passes (fusion, memory planning) have the full sequence to work with
+ */
+
+use crate::types::{WasmOperation, WasmPrecision, WasmResult};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::operations::{unary, binary, matmul, reduction, softmax, fused, cast, quantized, q4, permute, slice, select, einops, scan};
+use crate::operations::fused::WasmFusedProgram;
+use crate::operations::cast::CastOptions;
+use crate::operations::quantized::WasmQuantizationParams;
+use crate::operations::slice::WasmSliceSpec;
+use crate::operations::einops::WasmEinopsPlan;
+use crate::operations::scan::ScanOptions;
+use crate::pattern::{OperationDesc, ProducerTracker};
+
+/// Operation recorded in a graph node, together with its inputs and parameters
+#[derive(Debug, Clone)]
+pub enum GraphOp {
+    /// Element-wise unary, view, reduction (all axes) or softmax (last axis) operation
+    Unary {
+        operation: WasmOperation,
+        input: WasmTensor,
+        precision: WasmPrecision,
+    },
+    /// LeakyRelu with an explicit negative-side slope
+    LeakyRelu {
+        input: WasmTensor,
+        negative_slope: f64,
+        precision: WasmPrecision,
+    },
+    /// Element-wise binary operation with broadcasting
+    Binary {
+        operation: WasmOperation,
+        input_a: WasmTensor,
+        input_b: WasmTensor,
+    },
+    /// Element-wise select between two tensors by a Bool condition, with broadcasting
+    Where {
+        condition: WasmTensor,
+        input_a: WasmTensor,
+        input_b: WasmTensor,
+    },
+    /// Matrix multiplication
+    Matmul {
+        input_a: WasmTensor,
+        input_b: WasmTensor,
+    },
+    /// Affine-quantized int8 matrix multiplication with f32 output
+    QuantizedMatmul {
+        input_a: WasmTensor,
+        input_b: WasmTensor,
+        params: WasmQuantizationParams,
+    },
+    /// Matrix multiplication against Q4 block-quantized weights
+    Q4Matmul {
+        input: WasmTensor,
+        weights: WasmTensor,
+    },
+    /// Quantization of f32 weights into the Q4 format
+    QuantizeQ4 {
+        input: WasmTensor,
+    },
+    /// Permute with an explicit axis order
+    Permute {
+        input: WasmTensor,
+        axes: Vec<usize>,
+    },
+    /// Slice with an explicit per-axis spec
+    Slice {
+        input: WasmTensor,
+        spec: WasmSliceSpec,
+    },
+    /// Einops rearrange, repeat or reduce plan
+    Einops {
+        input: WasmTensor,
+        plan: WasmEinopsPlan,
+    },
+    /// Reduction with explicit axes
+    Reduction {
+        operation: WasmOperation,
+        input: WasmTensor,
+        axes: Option<Vec<usize>>,
+        keep_dims: bool,
+    },
+    /// Var, Std, norm or LogSumExp with explicit axes and Var/Std correction
+    Statistic {
+        operation: WasmOperation,
+        input: WasmTensor,
+        axes: Option<Vec<usize>>,
+        correction: f64,
+    },
+    /// Cumulative scan along an axis
+    Scan {
+        operation: WasmOperation,
+        input: WasmTensor,
+        axis: i32,
+        options: ScanOptions,
+    },
+    /// Softmax / LogSoftmax with explicit axis
+    Softmax {
+        operation: WasmOperation,
+        input: WasmTensor,
+        axis: Option<i32>,
+        precision: WasmPrecision,
+    },
+    /// Fused element-wise program
+    Fused {
+        program: WasmFusedProgram,
+        inputs: Vec<WasmTensor>,
+        precision: WasmPrecision,
+    },
+    /// Dtype cast to the output's dtype
+    Cast {
+        input: WasmTensor,
+        options: CastOptions,
+    },
+}
+
+/// Single node in a captured graph
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    pub op: GraphOp,
+    pub output: WasmTensor,
+}
+
+impl GraphNode {
+    /// Operation type of this node
+    pub fn operation(&self) -> WasmOperation {
+        match &self.op {
+            GraphOp::Unary { operation, .. }
+            | GraphOp::Binary { operation, .. }
+            | GraphOp::Reduction { operation, .. }
+            | GraphOp::Statistic { operation, .. }
+            | GraphOp::Scan { operation, .. }
+            | GraphOp::Softmax { operation, .. } => *operation,
+            GraphOp::LeakyRelu { .. } => WasmOperation::LeakyRelu,
+            GraphOp::Where { .. } => WasmOperation::Where,
+            GraphOp::Matmul { .. } => WasmOperation::Matmul,
+            GraphOp::QuantizedMatmul { .. } => WasmOperation::QuantizedMatmul,
+            GraphOp::Q4Matmul { .. } => WasmOperation::Q4Matmul,
+            GraphOp::QuantizeQ4 { .. } => WasmOperation::QuantizeQ4,
+            GraphOp::Permute { .. } => WasmOperation::Permute,
+            GraphOp::Slice { .. } => WasmOperation::Slice,
+            GraphOp::Einops { plan, .. } => plan.operation(),
+            GraphOp::Fused { .. } => WasmOperation::Fused,
+            GraphOp::Cast { .. } => WasmOperation::Cast,
+        }
+    }
+
+    /// Input tensors of this node, in argument order
+    pub fn inputs(&self) -> Vec<&WasmTensor> {
+        match &self.op {
+            GraphOp::Unary { input, .. }
+            | GraphOp::LeakyRelu { input, .. }
+            | GraphOp::Reduction { input, .. }
+            | GraphOp::Statistic { input, .. }
+            | GraphOp::Scan { input, .. }
+            | GraphOp::Softmax { input, .. }
+            | GraphOp::Cast { input, .. }
+            | GraphOp::QuantizeQ4 { input }
+            | GraphOp::Permute { input, .. }
+            | GraphOp::Slice { input, .. }
+            | GraphOp::Einops { input, .. } => vec![input],
+            GraphOp::Binary { input_a, input_b, .. }
+            | GraphOp::Matmul { input_a, input_b }
+            | GraphOp::QuantizedMatmul { input_a, input_b, .. } => vec![input_a, input_b],
+            GraphOp::Where { condition, input_a, input_b } => vec![condition, input_a, input_b],
+            GraphOp::Q4Matmul { input, weights } => vec![input, weights],
+            GraphOp::Fused { inputs, .. } => inputs.iter().collect(),
+        }
+    }
+
+    /// Execute this node against the arena
+    pub fn execute(&self, arena: &TempArena) -> WasmResult<()> {
+        match &self.op {
+            GraphOp::Unary { operation, input, precision } => {
+                unary::execute_unary_op_with_precision(*operation, input, &self.output, arena, *precision)
+            }
+            GraphOp::LeakyRelu { input, negative_slope, precision } => {
+                unary::execute_leaky_relu_op(input, &self.output, arena, *negative_slope, *precision)
+            }
+            GraphOp::Binary { operation, input_a, input_b } => {
+                binary::execute_binary_op(*operation, input_a, input_b, &self.output, arena)
+            }
+            GraphOp::Where { condition, input_a, input_b } => {
+                select::execute_where_op(condition, input_a, input_b, &self.output, arena)
+            }
+            GraphOp::Matmul { input_a, input_b } => {
+                matmul::execute_matmul_op(WasmOperation::Matmul, input_a, input_b, &self.output, arena)
+            }
+            GraphOp::QuantizedMatmul { input_a, input_b, params } => {
+                quantized::execute_quantized_matmul_op(input_a, input_b, &self.output, arena, params)
+            }
+            GraphOp::Q4Matmul { input, weights } => {
+                q4::execute_q4_matmul_op(input, weights, &self.output, arena)
+            }
+            GraphOp::QuantizeQ4 { input } => {
+                q4::execute_quantize_q4_op(input, &self.output, arena)
+            }
+            GraphOp::Permute { input, axes } => {
+                permute::execute_permute_op(input, &self.output, arena, axes)
+            }
+            GraphOp::Slice { input, spec } => {
+                slice::execute_slice_op(input, &self.output, arena, spec)
+            }
+            GraphOp::Einops { input, plan } => {
+                einops::execute_einops_op(input, &self.output, arena, plan)
+            }
+            GraphOp::Reduction { operation, input, axes, keep_dims } => {
+                reduction::execute_reduction_op_with_axes(
+                    *operation,
+                    input,
+                    &self.output,
+                    arena,
+                    axes.as_deref(),
+                    *keep_dims,
+                )
+            }
+            GraphOp::Statistic { operation, input, axes, correction } => {
+                reduction::execute_statistic_op(*operation, input, &self.output, arena, axes.as_deref(), *correction)
+            }
+            GraphOp::Scan { operation, input, axis, options } => {
+                scan::execute_scan_op(*operation, input, &self.output, arena, *axis, *options)
+            }
+            GraphOp::Softmax { operation, input, axis, precision } => {
+                softmax::execute_softmax_op(*operation, input, &self.output, arena, *axis, *precision)
+            }
+            GraphOp::Fused { program, inputs, precision } => {
+                fused::execute_fused_op(program, inputs, &self.output, arena, *precision)
+            }
+            GraphOp::Cast { input, options } => {
+                cast::execute_cast_op(input, &self.output, arena, *options)
+            }
+        }
+    }
+
+    /// Create operation description for pattern recognition
+    ///
+    /// `producers` maps tensors written by earlier nodes to their node index.
+    pub fn operation_desc(&self, producers: &ProducerTracker) -> OperationDesc {
+        let inputs = self.inputs();
+
+        OperationDesc {
+            operation: self.operation(),
+            input_shapes: inputs.iter().map(|t| t.metadata().shape()).collect(),
+            input_dtypes: inputs.iter().map(|t| t.metadata().dtype()).collect(),
+            input_producers: producers.input_producers(&inputs),
+            output_shape: self.output.metadata().shape(),
+            output_dtype: self.output.metadata().dtype(),
+        }
+    }
+}
+
+/// Captured sequence of operations, executed in recording order
+///
+/// Nodes hold clones of the tensor handles passed to the executor, so
+/// temporary tensors must stay valid (no `restore` past their checkpoint)
+/// for as long as the graph is run.
+#[derive(Debug, Clone, Default)]
+pub struct OperationGraph {
+    nodes: Vec<GraphNode>,
+}
+
+impl OperationGraph {
+    /// Create empty graph
+    pub fn new() -> Self {
+        OperationGraph { nodes: Vec::new() }
+    }
+
+    /// Append node to graph
+    pub fn push(&mut self, op: GraphOp, output: &WasmTensor) {
+        self.nodes.push(GraphNode {
+            op,
+            output: output.clone(),
+        });
+    }
+
+    /// Number of recorded nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check if graph has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Recorded nodes in execution order
+    pub fn nodes(&self) -> &[GraphNode] {
+        &self.nodes
+    }
+
+    /// Operation descriptions for the whole sequence, with dataflow between nodes
+    pub fn operation_descs(&self) -> Vec<OperationDesc> {
+        let mut producers = ProducerTracker::new();
+        self.nodes.iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let desc = node.operation_desc(&producers);
+                producers.record_output(&node.output, index);
+                desc
+            })
+            .collect()
+    }
+
+    /// Execute every node in order, stopping at the first failure
+    pub fn execute(&self, arena: &TempArena) -> WasmResult<()> {
+        for node in &self.nodes {
+            node.execute(arena)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::WasmMemorySystem;
+    use crate::types::WasmDType;
+
+    #[test]
+    fn test_graph_operation_descs() {
+        let mut memory = WasmMemorySystem::new();
+        let a = memory.alloc_temp_tensor(WasmDType::Float32, &[2, 3]).unwrap();
+        let b = memory.alloc_temp_tensor(WasmDType::Float32, &[3, 4]).unwrap();
+        let c = memory.alloc_temp_tensor(WasmDType::Float32, &[2, 4]).unwrap();
+        let d = memory.alloc_temp_tensor(WasmDType::Float32, &[2, 4]).unwrap();
+
+        let mut graph = OperationGraph::new();
+        graph.push(GraphOp::Matmul { input_a: a, input_b: b }, &c);
+        graph.push(GraphOp::Unary { operation: WasmOperation::Exp, input: c.clone(), precision: WasmPrecision::Fast }, &d);
+
+        assert_eq!(graph.len(), 2);
+
+        let descs = graph.operation_descs();
+        assert_eq!(descs[0].operation, WasmOperation::Matmul);
+        assert_eq!(descs[0].input_shapes, vec![vec![2, 3], vec![3, 4]]);
+        assert_eq!(descs[1].operation, WasmOperation::Exp);
+        assert_eq!(descs[1].output_shape, vec![2, 4]);
+        assert_eq!(descs[0].input_producers, vec![None, None]);
+        assert_eq!(descs[1].input_producers, vec![Some(0)]);
+    }
+
+    // WASM-specific integration tests
+    #[cfg(test)]
+    mod wasm_tests {
+        use super::*;
+        use wasm_bindgen_test::*;
+
+        fn f32_tensor(memory: &mut WasmMemorySystem, data: &[f32], shape: &[usize]) -> WasmTensor {
+            let bytes: Vec<u8> = data.iter().flat_map(|&f| f.to_le_bytes()).collect();
+            memory.tensor_from_data(bytes, WasmDType::Float32, shape).unwrap()
+        }
+
+        fn read_f32(memory: &WasmMemorySystem, tensor: &WasmTensor) -> Vec<f32> {
+            let ptr = tensor.get_read_ptr(memory.arena()) as *const f32;
+            let len = tensor.metadata().size();
+            unsafe { std::slice::from_raw_parts(ptr, len).to_vec() }
+        }
+
+        #[wasm_bindgen_test]
+        fn wasm_test_graph_execution_order() {
+            let mut memory = WasmMemorySystem::new();
+            let a = f32_tensor(&mut memory, &[1.0, 2.0, 3.0, 4.0], &[4]);
+            let b = f32_tensor(&mut memory, &[1.0, 1.0, 1.0, 1.0], &[4]);
+            let sum = memory.alloc_temp_tensor(WasmDType::Float32, &[4]).unwrap();
+            let neg = memory.alloc_temp_tensor(WasmDType::Float32, &[4]).unwrap();
+
+            let mut graph = OperationGraph::new();
+            graph.push(GraphOp::Binary { operation: WasmOperation::Add, input_a: a, input_b: b }, &sum);
+            graph.push(GraphOp::Unary { operation: WasmOperation::Neg, input: sum.clone(), precision: WasmPrecision::Fast }, &neg);
+
+            graph.execute(memory.arena()).unwrap();
+
+            assert_eq!(read_f32(&memory, &neg), vec![-2.0, -3.0, -4.0, -5.0]);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+/*!
+ * Half-precision float storage for WebAssembly backend
+ *
+ * `Float16` (IEEE 754 binary16) and `BFloat16` (truncated binary32) tensors
+ * are stored natively at 2 bytes per element. WebAssembly has no half-precision
+ * arithmetic, so kernels widen blocks to f32, compute, and narrow the results:
+ * - Widening is exact
+ * - Narrowing rounds to nearest, ties to even; overflow becomes infinity and
+ *   NaN stays NaN (quiet)
+ */
+
+use crate::simd::convert;
+
+/// IEEE 754 binary16 value (1 sign, 5 exponent, 10 mantissa bits)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct F16(pub u16);
+
+/// bfloat16 value (1 sign, 8 exponent, 7 mantissa bits) - the upper half of an f32
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct BF16(pub u16);
+
+/// Half-precision storage format that kernels upcast to f32
+pub trait HalfFloat: Copy + Default {
+    fn to_f32(self) -> f32;
+    fn from_f32(value: f32) -> Self;
+    fn from_f64(value: f64) -> Self;
+
+    /// Widen a block of values to f32
+    #[inline]
+    fn widen_slice(input: &[Self], output: &mut [f32]) {
+        for (out, &val) in output.iter_mut().zip(input) {
+            *out = val.to_f32();
+        }
+    }
+}
+
+impl HalfFloat for F16 {
+    #[inline]
+    fn to_f32(self) -> f32 {
+        let sign = ((self.0 as u32) & 0x8000) << 16;
+        let exponent = ((self.0 >> 10) & 0x1F) as u32;
+        let mantissa = (self.0 & 0x3FF) as u32;
+
+        match exponent {
+            0 => {
+                // Zero or subnormal: mantissa * 2^-24, exact in f32
+                let magnitude = mantissa as f32 * (1.0 / 16_777_216.0);
+                if sign != 0 { -magnitude } else { magnitude }
+            }
+            0x1F => f32::from_bits(sign | 0x7F80_0000 | (mantissa << 13)),
+            _ => f32::from_bits(sign | ((exponent + 112) << 23) | (mantissa << 13)),
+        }
+    }
+
+    #[inline]
+    fn from_f32(value: f32) -> Self {
+        // f32 -> f64 is exact, so this rounds only once
+        F16::from_f64(value as f64)
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        F16(narrow_f64::<5, 10>(value))
+    }
+}
+
+impl HalfFloat for BF16 {
+    #[inline]
+    fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+
+    #[inline]
+    fn from_f32(value: f32) -> Self {
+        let bits = value.to_bits();
+        if value.is_nan() {
+            // Keep NaN quiet; rounding could carry it into infinity
+            return BF16(((bits >> 16) as u16) | 0x0040);
+        }
+        // Round to nearest even on the 16 dropped bits
+        let rounding_bias = 0x7FFF + ((bits >> 16) & 1);
+        BF16((bits.wrapping_add(rounding_bias) >> 16) as u16)
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        BF16(narrow_f64::<8, 7>(value))
+    }
+
+    /// SIMD widening: a 16-bit shift per lane
+    #[inline]
+    fn widen_slice(input: &[Self], output: &mut [f32]) {
+        // SAFETY: BF16 is a transparent wrapper around its u16 bits
+        let bits = unsafe { std::slice::from_raw_parts(input.as_ptr() as *const u16, input.len()) };
+        convert::bf16_to_f32(bits, output);
+    }
+}
+
+/// Round an f64 to a small binary float format with `E` exponent and `M` mantissa bits
+fn narrow_f64<const E: u32, const M: u32>(value: f64) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 63) as u16) << (E + M);
+    let exponent = ((bits >> 52) & 0x7FF) as i32;
+    let mantissa = bits & ((1u64 << 52) - 1);
+    let max_exponent = (1u16 << E) - 1;
+    let mantissa_mask = (1u16 << M) - 1;
+
+    if exponent == 0x7FF {
+        // Infinity, or NaN with the quiet bit set
+        let nan_bits = if mantissa != 0 {
+            (1u16 << (M - 1)) | ((mantissa >> (52 - M)) as u16 & mantissa_mask)
+        } else {
+            0
+        };
+        return sign | (max_exponent << M) | nan_bits;
+    }
+    if exponent == 0 {
+        // f64 subnormals are far below the smallest half-precision subnormal
+        return sign;
+    }
+
+    let bias = (1i32 << (E - 1)) - 1;
+    let target_exponent = exponent - 1023 + bias;
+    let significand = mantissa | (1u64 << 52);
+
+    // Bits to drop: down to M mantissa bits, more for subnormal results
+    let shift = if target_exponent >= 1 {
+        52 - M
+    } else {
+        52 - M + (1 - target_exponent) as u32
+    };
+    if shift > 53 {
+        // Below half the smallest subnormal: rounds to zero
+        return sign;
+    }
+
+    let mut rounded = significand >> shift;
+    let remainder = significand & ((1u64 << shift) - 1);
+    let half = 1u64 << (shift - 1);
+    if remainder > half || (remainder == half && rounded & 1 == 1) {
+        rounded += 1;
+    }
+
+    if target_exponent < 1 {
+        // Subnormal; rounding up into 1 << M yields the smallest normal encoding
+        return sign | rounded as u16;
+    }
+
+    let mut biased_exponent = target_exponent as u64;
+    if rounded >> (M + 1) != 0 {
+        // Rounding carried into a new leading bit
+        rounded >>= 1;
+        biased_exponent += 1;
+    }
+    if biased_exponent >= max_exponent as u64 {
+        return sign | (max_exponent << M);
+    }
+    sign | ((biased_exponent as u16) << M) | (rounded as u16 & mantissa_mask)
+}
+
+/// Widen half-precision values to f32
+#[inline]
+pub fn widen<H: HalfFloat>(input: &[H], output: &mut [f32]) {
+    H::widen_slice(input, output);
+}
+
+/// Narrow f32 values to half precision
+#[inline]
+pub fn narrow<H: HalfFloat>(input: &[f32], output: &mut [H]) {
+    for (out, &val) in output.iter_mut().zip(input) {
+        *out = H::from_f32(val);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f16_round_trip() {
+        let values = [0.0f32, -0.0, 1.0, -2.5, 65504.0, 6.103_515_6e-5, 5.960_464_5e-8, f32::INFINITY];
+        for &val in &values {
+            let half = F16::from_f32(val);
+            assert_eq!(half.to_f32().to_bits(), val.to_bits(), "{}", val);
+        }
+        assert_eq!(F16::from_f32(1.0).0, 0x3C00);
+        assert_eq!(F16::from_f32(-2.0).0, 0xC000);
+        assert!(F16::from_f32(f32::NAN).to_f32().is_nan());
+    }
+
+    #[test]
+    fn test_f16_rounding() {
+        // 1 + 2^-11 is halfway between 1 and 1 + 2^-10: ties to even
+        assert_eq!(F16::from_f32(1.0 + 2f32.powi(-11)).0, 0x3C00);
+        assert_eq!(F16::from_f32(1.0 + 3.0 * 2f32.powi(-11)).0, 0x3C02);
+        // Overflow and underflow
+        assert_eq!(F16::from_f32(65520.0).0, 0x7C00);
+        assert_eq!(F16::from_f32(65519.0).0, 0x7BFF);
+        assert_eq!(F16::from_f32(2f32.powi(-26)).0, 0x0000);
+        assert_eq!(F16::from_f32(3.0 * 2f32.powi(-26)).0, 0x0001);
+        // Largest subnormal rounds up into the smallest normal
+        assert_eq!(F16::from_f64(2f64.powi(-14) - 2f64.powi(-26)).0, 0x0400);
+    }
+
+    #[test]
+    fn test_bf16_conversions() {
+        assert_eq!(BF16::from_f32(1.0).0, 0x3F80);
+        assert_eq!(BF16::from_f32(-3.0).to_f32(), -3.0);
+        // 1 + 2^-8 ties to even (down), 1 + 3 * 2^-8 ties up
+        assert_eq!(BF16::from_f32(1.0 + 2f32.powi(-8)).0, 0x3F80);
+        assert_eq!(BF16::from_f32(1.0 + 3.0 * 2f32.powi(-8)).0, 0x3F82);
+        assert!(BF16::from_f32(f32::NAN).to_f32().is_nan());
+        assert_eq!(BF16::from_f32(f32::MAX).0, 0x7F80);
+
+        // The f64 path agrees with the f32 bit trick
+        for val in [1.0f32 + 2f32.powi(-8), 3.140625, -1e30, 1e-40] {
+            assert_eq!(BF16::from_f64(val as f64), BF16::from_f32(val), "{}", val);
+        }
+    }
+
+    #[test]
+    fn test_block_conversions() {
+        let input: Vec<f32> = (0..37).map(|i| i as f32 * 0.5 - 9.0).collect();
+        let mut halves = vec![BF16::default(); 37];
+        narrow(&input, &mut halves);
+
+        let mut output = vec![0.0f32; 37];
+        widen(&halves, &mut output);
+        assert_eq!(output, input);
+
+        let mut halves = vec![F16::default(); 37];
+        narrow(&input, &mut halves);
+        widen(&halves, &mut output);
+        assert_eq!(output, input);
+    }
+}
@@ -8,6 +8,10 @@ mod arena;        // New arena-based memory system
 mod memory;       // Main memory management system
 mod executor;     // Main operation executor (replaces WasmOperationDispatcher)
 mod pattern;      // Operation pattern recognition and caching
+mod graph;        // Lazy operation graph capture
+mod planner;      // Static memory planning with buffer reuse
+mod promotion;    // DType promotion rules (mirrors core/src/dtype/promotion.ts)
+mod half;         // Float16 / BFloat16 storage and f32 conversions
 // Note: Assessment modules removed during cleanup
 // mod performance_benchmarks;  // Performance validation and benchmarks - removed for now
 
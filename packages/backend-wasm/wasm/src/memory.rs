@@ -13,6 +13,7 @@ use wasm_bindgen::prelude::*;
 use crate::arena::{TempArena, PersistentStorage, PersistentTensor, ArenaOffset, CheckpointId};
 use crate::types::{WasmDType, WasmTensorMeta};
 use crate::pattern::{OperationPattern, AllocationRequirement};
+use crate::planner::MemoryPlan;
 
 /// Tensor data storage - either temporary (arena) or persistent (reference-counted)
 #[derive(Debug, Clone)]
@@ -177,6 +178,8 @@ pub struct WasmMemoryStats {
     persistent_count: usize,
     persistent_bytes: usize,
     total_allocated: usize,
+    planned_peak: usize,
+    actual_peak: usize,
 }
 
 #[wasm_bindgen]
@@ -198,12 +201,21 @@ impl WasmMemoryStats {
     
     #[wasm_bindgen(getter)]
     pub fn total_allocated(&self) -> usize { self.total_allocated }
+    
+    /// Peak bytes required by the most recent memory plan
+    #[wasm_bindgen(getter)]
+    pub fn planned_peak(&self) -> usize { self.planned_peak }
+    
+    /// Highest arena usage observed (bytes)
+    #[wasm_bindgen(getter)]
+    pub fn actual_peak(&self) -> usize { self.actual_peak }
 }
 
 /// Main memory management system
 pub struct WasmMemorySystem {
     arena: TempArena,
     persistent_storage: PersistentStorage,
+    planned_peak: usize,
 }
 
 impl WasmMemorySystem {
@@ -212,6 +224,7 @@ impl WasmMemorySystem {
         WasmMemorySystem {
             arena: TempArena::new(),
             persistent_storage: PersistentStorage::new(),
+            planned_peak: 0,
         }
     }
     
@@ -288,6 +301,11 @@ impl WasmMemorySystem {
         self.arena.restore(checkpoint)
     }
     
+    /// Number of live arena checkpoints
+    pub fn checkpoint_depth(&self) -> usize {
+        self.arena.checkpoint_depth()
+    }
+    
     /// Reset entire arena (deallocate all temporaries)
     pub fn reset_arena(&mut self) {
         self.arena.reset();
@@ -305,6 +323,8 @@ impl WasmMemorySystem {
             persistent_count,
             persistent_bytes,
             total_allocated: arena_used + persistent_bytes,
+            planned_peak: self.planned_peak,
+            actual_peak: self.arena.peak_usage(),
         }
     }
     
@@ -338,24 +358,73 @@ impl WasmMemorySystem {
             return Err("Insufficient memory for bulk allocation".to_string());
         }
         
-        let mut allocated_tensors = Vec::with_capacity(pattern.allocations.len());
+        self.bulk_allocate_requirements(&pattern.allocations)
+    }
+    
+    /// Bulk allocate tensors for a list of requirements (all or nothing)
+    /// 
+    /// Tensors are allocated back to back in the arena with the dtype and
+    /// shape recorded in each requirement.
+    pub fn bulk_allocate_requirements(
+        &mut self,
+        requirements: &[AllocationRequirement]
+    ) -> Result<Vec<WasmTensor>, String> {
+        let mut allocated_tensors = Vec::with_capacity(requirements.len());
         let checkpoint = self.checkpoint(); // Create checkpoint for rollback
         
         // Attempt to allocate all tensors in sequence
-        for (i, allocation) in pattern.allocations.iter().enumerate() {
-            match self.allocate_tensor_for_requirement(allocation, i) {
+        for (i, allocation) in requirements.iter().enumerate() {
+            match self.allocate_tensor_for_requirement(allocation) {
                 Ok(tensor) => allocated_tensors.push(tensor),
                 Err(e) => {
                     // Rollback all allocations on failure
                     let _ = self.restore(checkpoint);
+                    self.arena.release(checkpoint);
                     return Err(format!("Bulk allocation failed at tensor {}: {}", i, e));
                 }
             }
         }
         
+        // Keep the allocations but drop the rollback checkpoint, so replays don't grow the stack
+        self.arena.release(checkpoint);
         Ok(allocated_tensors)
     }
     
+    /// Allocate tensors at planned offsets inside a single arena block
+    /// 
+    /// Tensors whose lifetimes don't overlap share memory, so the block is
+    /// only `plan.peak_bytes` large instead of the sum of all tensor sizes.
+    pub fn bulk_allocate_planned(
+        &mut self,
+        requirements: &[AllocationRequirement],
+        plan: &MemoryPlan,
+    ) -> Result<Vec<WasmTensor>, String> {
+        if plan.offsets.len() != requirements.len() {
+            return Err("Memory plan does not match allocation requirements".to_string());
+        }
+        
+        let block = self.arena.alloc_aligned(plan.peak_bytes, 16)?;
+        self.planned_peak = plan.peak_bytes;
+        
+        let tensors = requirements.iter()
+            .zip(&plan.offsets)
+            .map(|(requirement, &offset)| {
+                let shape = &requirement.shape;
+                let byte_size = calculate_tensor_bytes(requirement.dtype, shape);
+                let meta = WasmTensorMeta::new(
+                    requirement.dtype,
+                    shape.clone(),
+                    calculate_row_major_strides(shape),
+                    shape.iter().product(),
+                    0, // offset - always 0 for new tensors
+                );
+                WasmTensor::new_temporary(ArenaOffset::new(block.offset() + offset, byte_size), meta)
+            })
+            .collect();
+        
+        Ok(tensors)
+    }
+    
     /// Check if pattern can be bulk allocated (memory availability check)
     pub fn can_bulk_allocate(&self, pattern: &OperationPattern) -> bool {
         // Check if arena has enough space for all allocations
@@ -367,27 +436,19 @@ impl WasmMemorySystem {
     fn allocate_tensor_for_requirement(
         &mut self,
         requirement: &AllocationRequirement,
-        index: usize,
     ) -> Result<WasmTensor, String> {
-        // For bulk allocation, we assume Float32 tensors with specific sizes
-        // In a full implementation, this would use pattern metadata
-        let dtype = WasmDType::Float32; // Default for now
-        let element_size = dtype.byte_size();
-        let element_count = requirement.size_bytes / element_size;
-        
-        // Create a simple 1D shape for the required size
-        // TODO: Extract actual shapes from pattern operations
-        let shape = vec![element_count];
+        let shape = &requirement.shape;
+        let byte_size = calculate_tensor_bytes(requirement.dtype, shape);
         
         // Use temporary allocation for bulk allocations (arena-based)
-        let offset = self.arena.alloc_aligned(requirement.size_bytes, requirement.alignment)?;
+        let offset = self.arena.alloc_aligned(byte_size, requirement.alignment)?;
         
         let meta = WasmTensorMeta::new(
-            dtype,
+            requirement.dtype,
             shape.clone(),
-            calculate_row_major_strides(&shape),
-            element_count,
-            0,
+            calculate_row_major_strides(shape),
+            shape.iter().product(),
+            0, // offset - always 0 for new tensors
         );
         
         Ok(WasmTensor::new_temporary(offset, meta))
@@ -423,6 +484,8 @@ fn calculate_tensor_bytes(dtype: WasmDType, shape: &[usize]) -> usize {
         WasmDType::Uint8 => 1,
         WasmDType::Int16 => 2,
         WasmDType::Uint16 => 2,
+        WasmDType::Float16 => 2,
+        WasmDType::BFloat16 => 2,
         WasmDType::Int32 => 4,
         WasmDType::Uint32 => 4,
         WasmDType::Float32 => 4,
@@ -518,11 +581,15 @@ mod tests {
             allocations: vec![
                 AllocationRequirement {
                     size_bytes: 1024,
+                    dtype: WasmDType::Float32,
+                    shape: vec![256],
                     alignment: 16,
                     is_output: false,
                 },
                 AllocationRequirement {
                     size_bytes: 2048,
+                    dtype: WasmDType::Float32,
+                    shape: vec![512],
                     alignment: 16,
                     is_output: true,
                 },
@@ -545,6 +612,30 @@ mod tests {
         assert!(tensors[1].is_temporary());
     }
     
+    #[test]
+    fn test_bulk_allocation_uses_requirement_dtype_and_shape() {
+        use crate::pattern::AllocationRequirement;
+        
+        let mut memory = WasmMemorySystem::new();
+        
+        let requirements = vec![
+            AllocationRequirement::for_tensor(WasmDType::Int32, &[2, 3], true),
+            AllocationRequirement::for_tensor(WasmDType::Float64, &[4], true),
+        ];
+        
+        let tensors = memory.bulk_allocate_requirements(&requirements).unwrap();
+        assert_eq!(tensors.len(), 2);
+        
+        assert_eq!(tensors[0].metadata().dtype(), WasmDType::Int32);
+        assert_eq!(tensors[0].metadata().shape(), vec![2, 3]);
+        assert_eq!(tensors[0].metadata().strides(), vec![3, 1]);
+        assert_eq!(tensors[0].byte_size(), 24);
+        
+        assert_eq!(tensors[1].metadata().dtype(), WasmDType::Float64);
+        assert_eq!(tensors[1].metadata().shape(), vec![4]);
+        assert_eq!(tensors[1].byte_size(), 32);
+    }
+    
     #[test]
     fn test_bulk_allocation_memory_limit() {
         use crate::pattern::{OperationPattern, AllocationRequirement, PatternId};
@@ -558,6 +649,8 @@ mod tests {
             allocations: vec![
                 AllocationRequirement {
                     size_bytes: 1024 * 1024 * 1024, // 1GB - likely exceeds available arena space
+                    dtype: WasmDType::Float32,
+                    shape: vec![256 * 1024 * 1024],
                     alignment: 16,
                     is_output: true,
                 },
@@ -587,11 +680,15 @@ mod tests {
             allocations: vec![
                 AllocationRequirement {
                     size_bytes: 1024,
+                    dtype: WasmDType::Float32,
+                    shape: vec![256],
                     alignment: 16,
                     is_output: false,
                 },
                 AllocationRequirement {
                     size_bytes: 1024,
+                    dtype: WasmDType::Float32,
+                    shape: vec![256],
                     alignment: 16,
                     is_output: true,
                 },
@@ -639,16 +736,22 @@ mod tests {
                 allocations: vec![
                     AllocationRequirement {
                         size_bytes: 1024,
+                        dtype: WasmDType::Float32,
+                        shape: vec![256],
                         alignment: 16,
                         is_output: false,
                     },
                     AllocationRequirement {
                         size_bytes: 2048,
+                        dtype: WasmDType::Float32,
+                        shape: vec![512],
                         alignment: 16,
                         is_output: false,
                     },
                     AllocationRequirement {
                         size_bytes: 4096,
+                        dtype: WasmDType::Float32,
+                        shape: vec![1024],
                         alignment: 16,
                         is_output: true,
                     },
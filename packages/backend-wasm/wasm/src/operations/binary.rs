@@ -8,9 +8,24 @@
 use crate::types::{WasmOperation, WasmTensorMeta, WasmDType, WasmResult, WasmError};
 use crate::memory::WasmTensor;
 use crate::arena::TempArena;
-use crate::simd::float32;
+use crate::simd::{float32, float64};
+use crate::promotion::{binary_result_dtype, is_bool_producing};
+use crate::operations::comparison;
+use crate::operations::element::{IntElement, PromotedElement};
+use crate::operations::strided::{StridedIndexer, broadcast_strides, merge_broadcast_dims, is_contiguous, output_byte_offset};
+use crate::half::{self, HalfFloat, F16, BF16};
+
+/// Number of elements converted per block on the promoted path
+pub(crate) const PROMOTION_BLOCK_SIZE: usize = 256;
+
+/// Element-wise kernel run on broadcast blocks of `T` inputs, writing `O`
+pub(crate) type BinaryKernel<T, O = T> = fn(WasmOperation, &[T], &[T], &mut [O]) -> WasmResult<()>;
 
 /// Execute a binary operation
+///
+/// Inputs may have different dtypes; they are computed in (and the output
+/// must have) the dtype given by `binary_result_dtype`. Comparison and
+/// logical operations compute in that dtype but write a Bool output.
 pub fn execute_binary_op(
     operation: WasmOperation,
     input_a: &WasmTensor,
@@ -23,28 +38,46 @@ pub fn execute_binary_op(
     
     // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
     // Each tensor has unique memory from bump allocator, no aliasing occurs
-    let output_ptr = output.get_read_ptr(arena) as *mut u8;
+    let output_meta = output.metadata();
+    let output_ptr = unsafe { output.get_read_ptr(arena).add(output_byte_offset(output_meta)?) } as *mut u8;
     
     let input_meta_a = input_a.metadata();
     let input_meta_b = input_b.metadata();
-    let output_meta = output.metadata();
 
-    // Check if we can use fast path (same size, no broadcasting)
-    let same_size = input_meta_a.size() == input_meta_b.size() && 
-                   input_meta_a.size() == output_meta.size();
+    if is_bool_producing(operation) {
+        return comparison::execute_compare_op(
+            operation,
+            (input_meta_a, input_a_ptr),
+            (input_meta_b, input_b_ptr),
+            output_meta,
+            output_ptr,
+        );
+    }
+
+    if output_meta.dtype() != binary_result_dtype(input_meta_a.dtype(), input_meta_b.dtype()) {
+        return Err(WasmError::InvalidDType);
+    }
+
+    // Check if we can use fast path (same shape, no broadcasting). Comparing
+    // shapes rather than sizes keeps [2, 3] + [3, 2] on the validating path
+    let shape_a = input_meta_a.shape();
+    let same_shape = shape_a == input_meta_b.shape() && shape_a == output_meta.shape();
+    let same_dtype = input_meta_a.dtype() == input_meta_b.dtype();
+    let contiguous = is_contiguous(input_meta_a) && is_contiguous(input_meta_b);
     
-    if same_size && input_meta_a.dtype() == input_meta_b.dtype() {
-        // Fast path: same shapes and types
+    if contiguous && same_shape && same_dtype {
+        // Fast path: same shapes and types, read from the first element of each view
+        let byte_size = input_meta_a.dtype().byte_size();
         execute_binary_fast(
             operation,
             input_meta_a.dtype(),
-            input_a_ptr,
-            input_b_ptr,
+            unsafe { input_a_ptr.add(input_meta_a.offset() * byte_size) },
+            unsafe { input_b_ptr.add(input_meta_b.offset() * byte_size) },
             output_ptr,
             input_meta_a.size(),
         )?;
     } else {
-        // Slow path: handle broadcasting and type conversion
+        // Broadcasting, mixed dtypes and strided views
         execute_binary_broadcast(
             operation,
             input_meta_a,
@@ -88,13 +121,72 @@ fn execute_binary_fast(
             let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i32, size) };
             execute_binary_i32_fast(operation, a_slice, b_slice, out_slice)?;
         }
-        _ => return Err(WasmError::NotImplemented),
+        WasmDType::Int8 => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const i8, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const i8, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i8, size) };
+            execute_binary_int(operation, a_slice, b_slice, out_slice)?;
+        }
+        WasmDType::Uint8 => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr, size) };
+            execute_binary_int(operation, a_slice, b_slice, out_slice)?;
+        }
+        WasmDType::Int16 => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const i16, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const i16, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i16, size) };
+            execute_binary_int(operation, a_slice, b_slice, out_slice)?;
+        }
+        WasmDType::Uint16 => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const u16, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const u16, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u16, size) };
+            execute_binary_int(operation, a_slice, b_slice, out_slice)?;
+        }
+        WasmDType::Uint32 => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const u32, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const u32, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u32, size) };
+            execute_binary_int(operation, a_slice, b_slice, out_slice)?;
+        }
+        WasmDType::BigInt64 => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const i64, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const i64, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i64, size) };
+            execute_binary_int(operation, a_slice, b_slice, out_slice)?;
+        }
+        WasmDType::BigUint64 => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const u64, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const u64, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u64, size) };
+            execute_binary_int(operation, a_slice, b_slice, out_slice)?;
+        }
+        WasmDType::Bool => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr, size) };
+            execute_binary_bool(operation, a_slice, b_slice, out_slice)?;
+        }
+        WasmDType::Float16 => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const F16, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const F16, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut F16, size) };
+            execute_binary_half(operation, a_slice, b_slice, out_slice)?;
+        }
+        WasmDType::BFloat16 => {
+            let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const BF16, size) };
+            let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const BF16, size) };
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut BF16, size) };
+            execute_binary_half(operation, a_slice, b_slice, out_slice)?;
+        }
     }
     Ok(())
 }
 
 /// Fast binary operations for f32 arrays
-fn execute_binary_f32_fast(
+pub(crate) fn execute_binary_f32_fast(
     operation: WasmOperation,
     a: &[f32],
     b: &[f32],
@@ -117,13 +209,25 @@ fn execute_binary_f32_fast(
             // Use SIMD-optimized division
             float32::simd_div(a, b, output);
         }
+        WasmOperation::Maximum => float32::simd_maximum(a, b, output),
+        WasmOperation::Minimum => float32::simd_minimum(a, b, output),
+        WasmOperation::Pow => {
+            for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *out = libm::powf(a_val, b_val);
+            }
+        }
+        WasmOperation::Mod => {
+            for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *out = float_floor_mod(a_val, b_val);
+            }
+        }
         _ => return Err(WasmError::InvalidOperation),
     }
     Ok(())
 }
 
 /// Fast binary operations for f64 arrays
-fn execute_binary_f64_fast(
+pub(crate) fn execute_binary_f64_fast(
     operation: WasmOperation,
     a: &[f64],
     b: &[f64],
@@ -150,17 +254,58 @@ fn execute_binary_f64_fast(
                 *out = crate::utils::safe_div_f64(a_val, b_val);
             }
         }
+        WasmOperation::Maximum => float64::simd_maximum(a, b, output),
+        WasmOperation::Minimum => float64::simd_minimum(a, b, output),
+        WasmOperation::Pow => {
+            for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *out = a_val.powf(b_val);
+            }
+        }
+        WasmOperation::Mod => {
+            for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *out = float_floor_mod(a_val, b_val);
+            }
+        }
         _ => return Err(WasmError::InvalidOperation),
     }
     Ok(())
 }
 
+/// Remainder with the sign of the divisor (Python/PyTorch `%`); NaN for a zero divisor
+#[inline]
+fn float_floor_mod<T>(a: T, b: T) -> T
+where
+    T: Copy + Default + PartialOrd + std::ops::Rem<Output = T> + std::ops::Add<Output = T>,
+{
+    let zero = T::default();
+    let remainder = a % b;
+    if remainder != zero && (remainder < zero) != (b < zero) {
+        remainder + b
+    } else {
+        remainder
+    }
+}
+
 /// Fast binary operations for i32 arrays
-fn execute_binary_i32_fast(
+pub(crate) fn execute_binary_i32_fast(
     operation: WasmOperation,
     a: &[i32],
     b: &[i32],
     output: &mut [i32],
+) -> WasmResult<()> {
+    execute_binary_int(operation, a, b, output)
+}
+
+/// Binary operations on integer arrays
+/// 
+/// Add, Sub, Mul and Pow wrap modulo 2^bits (JS typed-array semantics);
+/// division truncates and saturates on division by zero, while Mod takes
+/// the divisor's sign and gives 0 for a zero divisor.
+fn execute_binary_int<T: IntElement>(
+    operation: WasmOperation,
+    a: &[T],
+    b: &[T],
+    output: &mut [T],
 ) -> WasmResult<()> {
     match operation {
         WasmOperation::Add => {
@@ -180,202 +325,99 @@ fn execute_binary_i32_fast(
         }
         WasmOperation::Div => {
             for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
-                *out = if b_val == 0 {
-                    if a_val > 0 { i32::MAX } else { i32::MIN }
-                } else {
-                    a_val / b_val
-                };
+                *out = a_val.div_or_saturate(b_val);
             }
         }
-        _ => return Err(WasmError::InvalidOperation),
-    }
-    Ok(())
-}
-
-/// Slow path for binary operations with broadcasting
-fn execute_binary_broadcast(
-    operation: WasmOperation,
-    input_meta_a: &WasmTensorMeta,
-    input_meta_b: &WasmTensorMeta,
-    output_meta: &WasmTensorMeta,
-    input_a_ptr: *const u8,
-    input_b_ptr: *const u8,
-    output_ptr: *mut u8,
-) -> WasmResult<()> {
-    let shape_a = input_meta_a.shape();
-    let shape_b = input_meta_b.shape();
-    let output_shape = output_meta.shape();
-
-    // Check if input_b is a scalar (single element)
-    if input_meta_b.size() == 1 {
-        execute_binary_scalar_broadcast(
-            operation,
-            input_meta_a.dtype(),
-            input_a_ptr,
-            input_b_ptr,
-            output_ptr,
-            input_meta_a.size(),
-        )?;
-        return Ok(());
-    } else if input_meta_a.size() == 1 {
-        // input_a is scalar
-        execute_binary_scalar_broadcast_reverse(
-            operation,
-            input_meta_b.dtype(),
-            input_a_ptr,
-            input_b_ptr,
-            output_ptr,
-            input_meta_b.size(),
-        )?;
-        return Ok(());
-    }
-    
-    // Try basic broadcasting patterns first
-    if try_basic_broadcasting(
-        operation,
-        input_meta_a,
-        input_meta_b,
-        output_meta,
-        input_a_ptr,
-        input_b_ptr,
-        output_ptr,
-    ).is_ok() {
-        return Ok(());
-    }
-    
-    // Fall back to general broadcasting
-    execute_general_broadcast(
-        operation,
-        input_meta_a,
-        input_meta_b,
-        output_meta,
-        input_a_ptr,
-        input_b_ptr,
-        output_ptr,
-    )
-}
-
-/// Element-wise binary operation for tensors of the same size
-fn execute_binary_elementwise(
-    operation: WasmOperation,
-    dtype: WasmDType,
-    input_a_ptr: *const u8,
-    input_b_ptr: *const u8,
-    output_ptr: *mut u8,
-    size: usize,
-) -> WasmResult<()> {
-    match dtype {
-        WasmDType::Float32 => {
-            let input_a = unsafe { std::slice::from_raw_parts(input_a_ptr as *const f32, size) };
-            let input_b = unsafe { std::slice::from_raw_parts(input_b_ptr as *const f32, size) };
-            let output = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
-            
-            execute_binary_f32_fast(operation, input_a, input_b, output)?;
+        WasmOperation::Maximum => {
+            for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *out = a_val.max(b_val);
+            }
         }
-        WasmDType::Float64 => {
-            let input_a = unsafe { std::slice::from_raw_parts(input_a_ptr as *const f64, size) };
-            let input_b = unsafe { std::slice::from_raw_parts(input_b_ptr as *const f64, size) };
-            let output = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
-            
-            execute_binary_f64_fast(operation, input_a, input_b, output)?;
+        WasmOperation::Minimum => {
+            for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *out = a_val.min(b_val);
+            }
+        }
+        WasmOperation::Pow => {
+            for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *out = a_val.wrapping_pow(b_val);
+            }
+        }
+        WasmOperation::Mod => {
+            for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *out = a_val.floor_mod(b_val);
+            }
         }
         _ => return Err(WasmError::InvalidOperation),
     }
-    
     Ok(())
 }
 
-/// Binary operation with scalar broadcasting (tensor op scalar)
-fn execute_binary_scalar_broadcast(
+/// Binary operations on bool arrays
+/// 
+/// Computed on the stored bytes, then normalized to 0/1: Add acts as OR,
+/// Mul as AND and Sub as XOR; Maximum is OR and Minimum is AND. Pow and
+/// Mod are defined on truth values (`a || !b` and always false).
+fn execute_binary_bool(
     operation: WasmOperation,
-    dtype: WasmDType,
-    tensor_ptr: *const u8,
-    scalar_ptr: *const u8,
-    output_ptr: *mut u8,
-    size: usize,
+    a: &[u8],
+    b: &[u8],
+    output: &mut [u8],
 ) -> WasmResult<()> {
-    match dtype {
-        WasmDType::Float32 => {
-            let tensor_slice = unsafe { std::slice::from_raw_parts(tensor_ptr as *const f32, size) };
-            let scalar_val = unsafe { *(scalar_ptr as *const f32) };
-            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
-            
-            match operation {
-                WasmOperation::Add => {
-                    for (out, &tensor_val) in output_slice.iter_mut().zip(tensor_slice.iter()) {
-                        *out = tensor_val + scalar_val;
-                    }
-                }
-                WasmOperation::Sub => {
-                    for (out, &tensor_val) in output_slice.iter_mut().zip(tensor_slice.iter()) {
-                        *out = tensor_val - scalar_val;
-                    }
-                }
-                WasmOperation::Mul => {
-                    for (out, &tensor_val) in output_slice.iter_mut().zip(tensor_slice.iter()) {
-                        *out = tensor_val * scalar_val;
-                    }
-                }
-                WasmOperation::Div => {
-                    for (out, &tensor_val) in output_slice.iter_mut().zip(tensor_slice.iter()) {
-                        *out = crate::utils::safe_div_f32(tensor_val, scalar_val);
-                    }
-                }
-                _ => return Err(WasmError::InvalidOperation),
+    match operation {
+        WasmOperation::Pow => {
+            for ((out, &a_val), &b_val) in output.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *out = (a_val != 0 || b_val == 0) as u8;
             }
+            return Ok(());
         }
-        _ => return Err(WasmError::NotImplemented),
+        WasmOperation::Mod => {
+            output.fill(0);
+            return Ok(());
+        }
+        _ => execute_binary_int(operation, a, b, output)?,
+    }
+    for out in output.iter_mut() {
+        *out = (*out != 0) as u8;
     }
     Ok(())
 }
 
-/// Binary operation with scalar broadcasting (scalar op tensor)
-fn execute_binary_scalar_broadcast_reverse(
+/// Binary operations on half-precision arrays
+/// 
+/// Each block is widened to f32, computed with the f32 kernel and rounded
+/// back; f32 is wide enough that this matches correctly rounded half math.
+fn execute_binary_half<H: HalfFloat>(
     operation: WasmOperation,
-    dtype: WasmDType,
-    scalar_ptr: *const u8,
-    tensor_ptr: *const u8,
-    output_ptr: *mut u8,
-    size: usize,
+    a: &[H],
+    b: &[H],
+    output: &mut [H],
 ) -> WasmResult<()> {
-    match dtype {
-        WasmDType::Float32 => {
-            let scalar_val = unsafe { *(scalar_ptr as *const f32) };
-            let tensor_slice = unsafe { std::slice::from_raw_parts(tensor_ptr as *const f32, size) };
-            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
-            
-            match operation {
-                WasmOperation::Add => {
-                    for (out, &tensor_val) in output_slice.iter_mut().zip(tensor_slice.iter()) {
-                        *out = scalar_val + tensor_val;
-                    }
-                }
-                WasmOperation::Sub => {
-                    for (out, &tensor_val) in output_slice.iter_mut().zip(tensor_slice.iter()) {
-                        *out = scalar_val - tensor_val;
-                    }
-                }
-                WasmOperation::Mul => {
-                    for (out, &tensor_val) in output_slice.iter_mut().zip(tensor_slice.iter()) {
-                        *out = scalar_val * tensor_val;
-                    }
-                }
-                WasmOperation::Div => {
-                    for (out, &tensor_val) in output_slice.iter_mut().zip(tensor_slice.iter()) {
-                        *out = crate::utils::safe_div_f32(scalar_val, tensor_val);
-                    }
-                }
-                _ => return Err(WasmError::InvalidOperation),
-            }
-        }
-        _ => return Err(WasmError::NotImplemented),
+    let mut block_a = [0.0f32; PROMOTION_BLOCK_SIZE];
+    let mut block_b = [0.0f32; PROMOTION_BLOCK_SIZE];
+    let mut result = [0.0f32; PROMOTION_BLOCK_SIZE];
+    for ((a_chunk, b_chunk), out_chunk) in a.chunks(PROMOTION_BLOCK_SIZE)
+        .zip(b.chunks(PROMOTION_BLOCK_SIZE))
+        .zip(output.chunks_mut(PROMOTION_BLOCK_SIZE))
+    {
+        let len = out_chunk.len();
+        half::widen(a_chunk, &mut block_a[..len]);
+        half::widen(b_chunk, &mut block_b[..len]);
+        execute_binary_f32_fast(operation, &block_a[..len], &block_b[..len], &mut result[..len])?;
+        half::narrow(&result[..len], out_chunk);
     }
     Ok(())
 }
 
-
-/// Try basic broadcasting patterns
-fn try_basic_broadcasting(
+/// Execute binary operation with NumPy broadcasting, in the result dtype
+/// 
+/// Handles every rank and dtype, mixed input dtypes and strided views.
+/// Output rows along the innermost (merged) axis are computed in blocks by
+/// the same-dtype kernel: an input whose row is contiguous and already in
+/// the result dtype is passed in place (so SIMD kernels run on it directly),
+/// a broadcast row is splatted from its single element, and anything else is
+/// gathered and converted.
+fn execute_binary_broadcast(
     operation: WasmOperation,
     input_meta_a: &WasmTensorMeta,
     input_meta_b: &WasmTensorMeta,
@@ -384,285 +426,197 @@ fn try_basic_broadcasting(
     input_b_ptr: *const u8,
     output_ptr: *mut u8,
 ) -> WasmResult<()> {
-    // Check if we can use one of the optimized patterns
-    execute_binary_broadcast_basic(
-        operation,
-        input_meta_a,
-        input_meta_b,
-        output_meta,
-        input_a_ptr,
-        input_b_ptr,
-        output_ptr,
-    )
-}
+    let layout = BroadcastLayout::new(input_meta_a, input_meta_b, output_meta)?;
+    let output_dtype = output_meta.dtype();
+    let input_a = BroadcastInput::new(input_meta_a, input_a_ptr, output_dtype);
+    let input_b = BroadcastInput::new(input_meta_b, input_b_ptr, output_dtype);
+    let inputs = (&input_a, &input_b);
+    let size = output_meta.size();
 
-/// General broadcasting following NumPy rules
-fn execute_general_broadcast(
-    operation: WasmOperation,
-    input_meta_a: &WasmTensorMeta,
-    input_meta_b: &WasmTensorMeta,
-    output_meta: &WasmTensorMeta,
-    input_a_ptr: *const u8,
-    input_b_ptr: *const u8,
-    output_ptr: *mut u8,
-) -> WasmResult<()> {
-    // Only handle float32 for now
-    if input_meta_a.dtype() != WasmDType::Float32 || input_meta_b.dtype() != WasmDType::Float32 {
-        return Err(WasmError::NotImplemented);
-    }
-    
-    let shape_a = input_meta_a.shape();
-    let shape_b = input_meta_b.shape();
-    let strides_a = input_meta_a.strides();
-    let strides_b = input_meta_b.strides();
-    let output_shape = output_meta.shape();
-    let output_size = output_meta.size();
-    
-    // Get typed slices
-    let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const f32, input_meta_a.size()) };
-    let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const f32, input_meta_b.size()) };
-    let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_size) };
-    
-    // Iterate through all output positions
-    for out_idx in 0..output_size {
-        // Convert flat index to multi-dimensional indices
-        let mut out_indices = vec![0; output_shape.len()];
-        let mut remaining = out_idx;
-        for i in (0..output_shape.len()).rev() {
-            out_indices[i] = remaining % output_shape[i];
-            remaining /= output_shape[i];
+    match output_dtype {
+        WasmDType::Float32 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_f32_fast)
+        }
+        WasmDType::Float64 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_f64_fast)
+        }
+        WasmDType::Int8 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i8, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_int)
+        }
+        WasmDType::Uint8 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_int)
+        }
+        WasmDType::Int16 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i16, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_int)
+        }
+        WasmDType::Uint16 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u16, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_int)
+        }
+        WasmDType::Int32 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i32, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_int)
+        }
+        WasmDType::Uint32 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u32, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_int)
+        }
+        WasmDType::BigInt64 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i64, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_int)
+        }
+        WasmDType::BigUint64 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u64, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_int)
+        }
+        WasmDType::Bool => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_bool)
+        }
+        WasmDType::Float16 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut F16, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_half)
+        }
+        WasmDType::BFloat16 => {
+            let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut BF16, size) };
+            execute_binary_broadcast_typed(operation, inputs, &layout, out_slice, execute_binary_half)
         }
-        
-        // Map output indices to input indices for both tensors
-        let a_idx = compute_broadcast_index(&out_indices, &shape_a, &strides_a, &output_shape);
-        let b_idx = compute_broadcast_index(&out_indices, &shape_b, &strides_b, &output_shape);
-        
-        // Get values and perform operation
-        let a_val = a_slice[a_idx];
-        let b_val = b_slice[b_idx];
-        
-        out_slice[out_idx] = match operation {
-            WasmOperation::Add => a_val + b_val,
-            WasmOperation::Sub => a_val - b_val,
-            WasmOperation::Mul => a_val * b_val,
-            WasmOperation::Div => crate::utils::safe_div_f32(a_val, b_val),
-            _ => return Err(WasmError::InvalidOperation),
-        };
     }
-    
-    Ok(())
 }
 
-/// Compute the index in a tensor given output indices and broadcasting rules
-fn compute_broadcast_index(
-    out_indices: &[usize],
-    tensor_shape: &[usize],
-    tensor_strides: &[usize],
-    output_shape: &[usize],
-) -> usize {
-    let mut index = 0;
-    let ndim_out = output_shape.len();
-    let ndim_tensor = tensor_shape.len();
-    
-    // Broadcasting aligns dimensions from the right
-    let offset = ndim_out.saturating_sub(ndim_tensor);
-    
-    for i in 0..ndim_tensor {
-        let out_dim = i + offset;
-        let out_idx = out_indices[out_dim];
-        
-        // Apply broadcasting rule: if dimension is 1, use index 0
-        let tensor_idx = if tensor_shape[i] == 1 {
-            0
-        } else {
-            out_idx
-        };
-        
-        index += tensor_idx * tensor_strides[i];
-    }
-    
-    index
+/// Broadcast iteration space: output shape with the element strides of both
+/// inputs (0 along broadcast dims)
+/// 
+/// Size-1 dims are dropped and neighbours that both inputs step through
+/// uniformly are merged, so e.g. `[B, M, N] + [N]` becomes `[B * M, N]` and
+/// same-shape contiguous inputs become a single row.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BroadcastLayout {
+    shape: Vec<usize>,
+    strides_a: Vec<usize>,
+    strides_b: Vec<usize>,
+    offset_a: usize,
+    offset_b: usize,
 }
 
-/// Basic broadcasting for common cases (vector-matrix)
-fn execute_binary_broadcast_basic(
-    operation: WasmOperation,
-    input_meta_a: &WasmTensorMeta,
-    input_meta_b: &WasmTensorMeta,
-    output_meta: &WasmTensorMeta,
-    input_a_ptr: *const u8,
-    input_b_ptr: *const u8,
-    output_ptr: *mut u8,
-) -> WasmResult<()> {
-    let shape_a = input_meta_a.shape();
-    let shape_b = input_meta_b.shape();
-    let _output_shape = output_meta.shape();
-    
-    // Only handle float32 for now
-    if input_meta_a.dtype() != WasmDType::Float32 || input_meta_b.dtype() != WasmDType::Float32 {
-        return Err(WasmError::NotImplemented);
-    }
-    
-    // Case 1: Vector [n] + Matrix [m, n] -> broadcast vector across rows
-    if shape_a.len() == 1 && shape_b.len() == 2 && shape_a[0] == shape_b[1] {
-        let rows = shape_b[0];
-        let cols = shape_b[1];
-        
-        let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const f32, cols) };
-        let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const f32, rows * cols) };
-        let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, rows * cols) };
-        
-        for row in 0..rows {
-            for col in 0..cols {
-                let idx = row * cols + col;
-                let a_val = a_slice[col];
-                let b_val = b_slice[idx];
-                
-                out_slice[idx] = match operation {
-                    WasmOperation::Add => a_val + b_val,
-                    WasmOperation::Sub => a_val - b_val,
-                    WasmOperation::Mul => a_val * b_val,
-                    WasmOperation::Div => crate::utils::safe_div_f32(a_val, b_val),
-                    _ => return Err(WasmError::InvalidOperation),
-                };
-            }
+impl BroadcastLayout {
+    /// Validate broadcasting and build the merged layout
+    pub(crate) fn new(
+        input_meta_a: &WasmTensorMeta,
+        input_meta_b: &WasmTensorMeta,
+        output_meta: &WasmTensorMeta,
+    ) -> WasmResult<Self> {
+        let shape = output_meta.shape();
+        if shape.iter().product::<usize>() != output_meta.size() {
+            return Err(WasmError::InvalidShape);
         }
-        Ok(())
+
+        let strides_a = broadcast_strides(&input_meta_a.shape(), &input_meta_a.strides(), &shape)?;
+        let strides_b = broadcast_strides(&input_meta_b.shape(), &input_meta_b.strides(), &shape)?;
+        let (shape, [strides_a, strides_b]) = merge_broadcast_dims(&shape, [&strides_a, &strides_b]);
+
+        Ok(BroadcastLayout {
+            shape,
+            strides_a,
+            strides_b,
+            offset_a: input_meta_a.offset(),
+            offset_b: input_meta_b.offset(),
+        })
     }
-    // Case 2: Matrix [m, n] + Vector [n] -> broadcast vector across rows
-    else if shape_a.len() == 2 && shape_b.len() == 1 && shape_a[1] == shape_b[0] {
-        let rows = shape_a[0];
-        let cols = shape_a[1];
-        
-        let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const f32, rows * cols) };
-        let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const f32, cols) };
-        let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, rows * cols) };
-        
-        for row in 0..rows {
-            for col in 0..cols {
-                let idx = row * cols + col;
-                let a_val = a_slice[idx];
-                let b_val = b_slice[col];
-                
-                out_slice[idx] = match operation {
-                    WasmOperation::Add => a_val + b_val,
-                    WasmOperation::Sub => a_val - b_val,
-                    WasmOperation::Mul => a_val * b_val,
-                    WasmOperation::Div => crate::utils::safe_div_f32(a_val, b_val),
-                    _ => return Err(WasmError::InvalidOperation),
-                };
-            }
-        }
-        Ok(())
+}
+
+/// Raw input of a broadcast binary operation
+pub(crate) struct BroadcastInput {
+    dtype: WasmDType,
+    ptr: *const u8,
+    /// Stored in the compute dtype, so contiguous rows can be used in place
+    in_place: bool,
+}
+
+impl BroadcastInput {
+    /// Input read from `ptr` and computed in `compute_dtype`
+    pub(crate) fn new(meta: &WasmTensorMeta, ptr: *const u8, compute_dtype: WasmDType) -> Self {
+        BroadcastInput { dtype: meta.dtype(), ptr, in_place: meta.dtype() == compute_dtype }
     }
-    // Case 3: Vector [m] (as [m, 1]) + Matrix [m, n] -> broadcast vector across columns
-    else if shape_a.len() == 1 && shape_b.len() == 2 && shape_a[0] == shape_b[0] {
-        let rows = shape_b[0];
-        let cols = shape_b[1];
-        
-        let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const f32, rows) };
-        let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const f32, rows * cols) };
-        let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, rows * cols) };
-        
-        for row in 0..rows {
-            for col in 0..cols {
-                let idx = row * cols + col;
-                let a_val = a_slice[row];
-                let b_val = b_slice[idx];
-                
-                out_slice[idx] = match operation {
-                    WasmOperation::Add => a_val + b_val,
-                    WasmOperation::Sub => a_val - b_val,
-                    WasmOperation::Mul => a_val * b_val,
-                    WasmOperation::Div => crate::utils::safe_div_f32(a_val, b_val),
-                    _ => return Err(WasmError::InvalidOperation),
-                };
-            }
+
+    /// `block.len()` elements starting at element `base` with step `stride`, as `T`
+    /// 
+    /// # Safety
+    /// The input must contain every element read, and `T` must be the
+    /// compute dtype (matching `self.dtype` when `in_place` is set).
+    pub(crate) unsafe fn load_row<'a, T: PromotedElement>(
+        &self,
+        base: usize,
+        stride: usize,
+        block: &'a mut [T],
+        offsets: &mut [usize],
+    ) -> &'a [T] {
+        let len = block.len();
+        if self.in_place && stride == 1 {
+            return std::slice::from_raw_parts((self.ptr as *const T).add(base), len);
         }
-        Ok(())
-    }
-    // Case 4: Matrix [m, n] + Vector [m] (as [m, 1]) -> broadcast vector across columns
-    else if shape_a.len() == 2 && shape_b.len() == 1 && shape_a[0] == shape_b[0] {
-        let rows = shape_a[0];
-        let cols = shape_a[1];
-        
-        let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const f32, rows * cols) };
-        let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const f32, rows) };
-        let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, rows * cols) };
-        
-        for row in 0..rows {
-            for col in 0..cols {
-                let idx = row * cols + col;
-                let a_val = a_slice[idx];
-                let b_val = b_slice[row];
-                
-                out_slice[idx] = match operation {
-                    WasmOperation::Add => a_val + b_val,
-                    WasmOperation::Sub => a_val - b_val,
-                    WasmOperation::Mul => a_val * b_val,
-                    WasmOperation::Div => crate::utils::safe_div_f32(a_val, b_val),
-                    _ => return Err(WasmError::InvalidOperation),
-                };
-            }
+        if stride == 0 {
+            // Broadcast along the row: convert once, then splat
+            T::load_block(self.dtype, self.ptr, &[base], &mut block[..1]);
+            let value = block[0];
+            block.fill(value);
+            return block;
         }
-        Ok(())
-    }
-    // Case 5: Column vector [m, 1] + Matrix [m, n] -> broadcast column vector across columns
-    else if shape_a.len() == 2 && shape_b.len() == 2 && shape_a[0] == shape_b[0] && shape_a[1] == 1 {
-        let rows = shape_b[0];
-        let cols = shape_b[1];
-        
-        let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const f32, rows) };
-        let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const f32, rows * cols) };
-        let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, rows * cols) };
-        
-        for row in 0..rows {
-            for col in 0..cols {
-                let idx = row * cols + col;
-                let a_val = a_slice[row];  // Column vector has one value per row
-                let b_val = b_slice[idx];
-                
-                out_slice[idx] = match operation {
-                    WasmOperation::Add => a_val + b_val,
-                    WasmOperation::Sub => a_val - b_val,
-                    WasmOperation::Mul => a_val * b_val,
-                    WasmOperation::Div => crate::utils::safe_div_f32(a_val, b_val),
-                    _ => return Err(WasmError::InvalidOperation),
-                };
-            }
+        let offsets = &mut offsets[..len];
+        for (i, offset) in offsets.iter_mut().enumerate() {
+            *offset = base + i * stride;
         }
-        Ok(())
+        T::load_block(self.dtype, self.ptr, offsets, block);
+        block
     }
-    // Case 6: Matrix [m, n] + Column vector [m, 1] -> broadcast column vector across columns
-    else if shape_a.len() == 2 && shape_b.len() == 2 && shape_a[0] == shape_b[0] && shape_b[1] == 1 {
-        let rows = shape_a[0];
-        let cols = shape_a[1];
-        
-        let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const f32, rows * cols) };
-        let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const f32, rows) };
-        let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, rows * cols) };
-        
-        for row in 0..rows {
-            for col in 0..cols {
-                let idx = row * cols + col;
-                let a_val = a_slice[idx];
-                let b_val = b_slice[row];  // Column vector has one value per row
-                
-                out_slice[idx] = match operation {
-                    WasmOperation::Add => a_val + b_val,
-                    WasmOperation::Sub => a_val - b_val,
-                    WasmOperation::Mul => a_val * b_val,
-                    WasmOperation::Div => crate::utils::safe_div_f32(a_val, b_val),
-                    _ => return Err(WasmError::InvalidOperation),
-                };
-            }
-        }
-        Ok(())
+}
+
+/// Compute output rows along the innermost layout axis, one block at a time
+pub(crate) fn execute_binary_broadcast_typed<T: PromotedElement, O>(
+    operation: WasmOperation,
+    (input_a, input_b): (&BroadcastInput, &BroadcastInput),
+    layout: &BroadcastLayout,
+    output: &mut [O],
+    kernel: BinaryKernel<T, O>,
+) -> WasmResult<()> {
+    if output.is_empty() {
+        return Ok(());
     }
-    else {
-        // Full broadcasting not implemented yet
-        return Err(WasmError::NotImplemented);
+
+    let inner = layout.shape.len() - 1;
+    let row_len = layout.shape[inner];
+    let stride_a = layout.strides_a[inner];
+    let stride_b = layout.strides_b[inner];
+    let mut rows_a = StridedIndexer::new(&layout.shape[..inner], &layout.strides_a[..inner], layout.offset_a);
+    let mut rows_b = StridedIndexer::new(&layout.shape[..inner], &layout.strides_b[..inner], layout.offset_b);
+
+    let mut offsets = [0usize; PROMOTION_BLOCK_SIZE];
+    let mut block_a = [T::default(); PROMOTION_BLOCK_SIZE];
+    let mut block_b = [T::default(); PROMOTION_BLOCK_SIZE];
+
+    for out_row in output.chunks_exact_mut(row_len) {
+        for (chunk, out_chunk) in out_row.chunks_mut(PROMOTION_BLOCK_SIZE).enumerate() {
+            let start = chunk * PROMOTION_BLOCK_SIZE;
+            let len = out_chunk.len();
+
+            // SAFETY: layout offsets stay inside each input's (broadcast) shape
+            let (a, b) = unsafe {
+                (
+                    input_a.load_row(rows_a.offset() + start * stride_a, stride_a, &mut block_a[..len], &mut offsets),
+                    input_b.load_row(rows_b.offset() + start * stride_b, stride_b, &mut block_b[..len], &mut offsets),
+                )
+            };
+            kernel(operation, a, b, out_chunk)?;
+        }
+        rows_a.advance();
+        rows_b.advance();
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -703,13 +657,13 @@ mod tests {
     }
 
     #[test]
-    fn test_execute_binary_elementwise() {
+    fn test_execute_binary_fast() {
         let a = vec![1.0f32, 2.0, 3.0, 4.0];
         let b = vec![5.0f32, 6.0, 7.0, 8.0];
         let mut output = vec![0.0f32; 4];
         
         // Test element-wise addition
-        execute_binary_elementwise(
+        execute_binary_fast(
             WasmOperation::Add,
             WasmDType::Float32,
             a.as_ptr() as *const u8,
@@ -720,4 +674,248 @@ mod tests {
         
         assert_eq!(output, vec![6.0, 8.0, 10.0, 12.0]);
     }
-}
\ No newline at end of file
+    
+    fn contiguous_meta(dtype: WasmDType, shape: &[usize]) -> WasmTensorMeta {
+        let strides = crate::operations::strided::contiguous_strides(shape);
+        WasmTensorMeta::new(dtype, shape.to_vec(), strides, shape.iter().product(), 0)
+    }
+    
+    #[test]
+    fn test_binary_int_wrapping() {
+        let a = [100i8, -128, 7, -7];
+        let b = [100i8, 1, 2, 0];
+        let mut output = vec![0i8; 4];
+        
+        execute_binary_int(WasmOperation::Add, &a, &b, &mut output).unwrap();
+        assert_eq!(output, vec![-56, -127, 9, -7]);
+        
+        execute_binary_int(WasmOperation::Div, &a, &b, &mut output).unwrap();
+        assert_eq!(output, vec![1, -128, 3, i8::MIN]);
+    }
+
+    #[test]
+    fn test_binary_extended_ops() {
+        let a = [2i32, -7, 7, -3];
+        let b = [10i32, 3, -3, 0];
+        let mut output = vec![0i32; 4];
+        execute_binary_int(WasmOperation::Pow, &a, &b, &mut output).unwrap();
+        assert_eq!(output, vec![1024, -343, 0, 1]);
+        execute_binary_int(WasmOperation::Mod, &a, &b, &mut output).unwrap();
+        assert_eq!(output, vec![2, 2, -2, 0]);
+        execute_binary_int(WasmOperation::Maximum, &a, &b, &mut output).unwrap();
+        assert_eq!(output, vec![10, 3, 7, 0]);
+
+        // Float Mod follows the divisor's sign; Maximum/Minimum propagate NaN
+        let a = [-7.5f32, 7.5, 1.0, f32::NAN, -0.0, 3.0];
+        let b = [2.0f32, -2.0, f32::NAN, 1.0, 0.0, 2.0];
+        let mut output = vec![0.0f32; 6];
+        execute_binary_f32_fast(WasmOperation::Mod, &a, &b, &mut output).unwrap();
+        assert_eq!(output[..2], [0.5, -0.5]);
+        execute_binary_f32_fast(WasmOperation::Maximum, &a, &b, &mut output).unwrap();
+        assert_eq!(output[..2], [2.0, 7.5]);
+        assert!(output[2].is_nan() && output[3].is_nan());
+        assert!(output[4] == 0.0 && output[4].is_sign_positive());
+        execute_binary_f32_fast(WasmOperation::Pow, &a, &b, &mut output).unwrap();
+        assert_eq!(output[5], 9.0);
+
+        let mut output = vec![0.0f64; 2];
+        execute_binary_f64_fast(WasmOperation::Minimum, &[-0.0, f64::NAN], &[0.0, 1.0], &mut output).unwrap();
+        assert!(output[0].is_sign_negative() && output[1].is_nan());
+
+        let mut output = vec![9u8; 4];
+        execute_binary_bool(WasmOperation::Pow, &[0, 0, 2, 2], &[0, 3, 0, 3], &mut output).unwrap();
+        assert_eq!(output, vec![1, 0, 1, 1]);
+    }
+    
+    #[test]
+    fn test_binary_op_shape_mismatch() {
+        use crate::memory::WasmMemorySystem;
+
+        // Same element count, incompatible shapes
+        let mut memory = WasmMemorySystem::new();
+        let a = memory.tensor_from_data(vec![0; 24], WasmDType::Float32, &[2, 3]).unwrap();
+        let b = memory.tensor_from_data(vec![0; 24], WasmDType::Float32, &[3, 2]).unwrap();
+        let output = memory.alloc_temp_tensor(WasmDType::Float32, &[2, 3]).unwrap();
+        let result = execute_binary_op(WasmOperation::Add, &a, &b, &output, memory.arena());
+        assert!(matches!(result, Err(WasmError::InvalidShape)));
+
+        let same = memory.alloc_temp_tensor(WasmDType::Float32, &[2, 3]).unwrap();
+        assert!(execute_binary_op(WasmOperation::Add, &a, &a, &same, memory.arena()).is_ok());
+        let reshaped = memory.alloc_temp_tensor(WasmDType::Float32, &[6]).unwrap();
+        assert!(execute_binary_op(WasmOperation::Add, &a, &a, &reshaped, memory.arena()).is_err());
+    }
+
+    #[test]
+    fn test_binary_promoted_mixed_dtypes() {
+        // int8 + uint8 -> int16
+        let a = [-100i8, 127, -1];
+        let b = [200u8, 255, 0];
+        let mut output = vec![0i16; 3];
+        
+        execute_binary_broadcast(
+            WasmOperation::Add,
+            &contiguous_meta(WasmDType::Int8, &[3]),
+            &contiguous_meta(WasmDType::Uint8, &[3]),
+            &contiguous_meta(WasmDType::Int16, &[3]),
+            a.as_ptr() as *const u8,
+            b.as_ptr(),
+            output.as_mut_ptr() as *mut u8,
+        ).unwrap();
+        
+        assert_eq!(output, vec![100, 382, -1]);
+    }
+    
+    #[test]
+    fn test_binary_promoted_broadcast() {
+        // uint8 [2, 1] * float64 [3] -> float64 [2, 3]
+        let a = [2u8, 3];
+        let b = [0.5f64, 1.0, 1.5];
+        let mut output = vec![0.0f64; 6];
+        
+        execute_binary_broadcast(
+            WasmOperation::Mul,
+            &contiguous_meta(WasmDType::Uint8, &[2, 1]),
+            &contiguous_meta(WasmDType::Float64, &[3]),
+            &contiguous_meta(WasmDType::Float64, &[2, 3]),
+            a.as_ptr(),
+            b.as_ptr() as *const u8,
+            output.as_mut_ptr() as *mut u8,
+        ).unwrap();
+        
+        assert_eq!(output, vec![1.0, 2.0, 3.0, 1.5, 3.0, 4.5]);
+    }
+    
+    #[test]
+    fn test_binary_promoted_many_blocks() {
+        let a: Vec<i16> = (0..600).map(|i| i as i16).collect();
+        let b = [0.5f32];
+        let mut output = vec![0.0f32; 600];
+        
+        execute_binary_broadcast(
+            WasmOperation::Sub,
+            &contiguous_meta(WasmDType::Int16, &[600]),
+            &contiguous_meta(WasmDType::Float32, &[]),
+            &contiguous_meta(WasmDType::Float32, &[600]),
+            a.as_ptr() as *const u8,
+            b.as_ptr() as *const u8,
+            output.as_mut_ptr() as *mut u8,
+        ).unwrap();
+        
+        for (i, &val) in output.iter().enumerate() {
+            assert_eq!(val, i as f32 - 0.5);
+        }
+    }
+    
+    #[test]
+    fn test_binary_promoted_incompatible_shapes() {
+        let a = [0i32; 3];
+        let b = [0.0f32; 2];
+        let mut output = vec![0.0f64; 3];
+        
+        let result = execute_binary_broadcast(
+            WasmOperation::Add,
+            &contiguous_meta(WasmDType::Int32, &[3]),
+            &contiguous_meta(WasmDType::Float32, &[2]),
+            &contiguous_meta(WasmDType::Float64, &[3]),
+            a.as_ptr() as *const u8,
+            b.as_ptr() as *const u8,
+            output.as_mut_ptr() as *mut u8,
+        );
+        assert!(matches!(result, Err(WasmError::InvalidShape)));
+    }
+    
+    #[test]
+    fn test_broadcast_layout_merging() {
+        // [B, M, N] + [N]: batch and rows merge, the vector repeats per row
+        let layout = BroadcastLayout::new(
+            &contiguous_meta(WasmDType::Float32, &[2, 3, 4]),
+            &contiguous_meta(WasmDType::Float32, &[4]),
+            &contiguous_meta(WasmDType::Float32, &[2, 3, 4]),
+        ).unwrap();
+        assert_eq!(layout.shape, vec![6, 4]);
+        assert_eq!(layout.strides_a, vec![4, 1]);
+        assert_eq!(layout.strides_b, vec![0, 1]);
+        
+        // [M, 1] + [1, N] stays 2D, scalar output collapses to one element
+        let layout = BroadcastLayout::new(
+            &contiguous_meta(WasmDType::Int8, &[3, 1]),
+            &contiguous_meta(WasmDType::Int8, &[1, 5]),
+            &contiguous_meta(WasmDType::Int8, &[3, 5]),
+        ).unwrap();
+        assert_eq!((layout.strides_a, layout.strides_b), (vec![1, 0], vec![0, 1]));
+        let layout = BroadcastLayout::new(
+            &contiguous_meta(WasmDType::Int8, &[1, 1]),
+            &contiguous_meta(WasmDType::Int8, &[]),
+            &contiguous_meta(WasmDType::Int8, &[1, 1]),
+        ).unwrap();
+        assert_eq!(layout.shape, vec![1]);
+    }
+    
+    #[test]
+    fn test_broadcast_nd_every_dtype() {
+        // [2, 1, 3] - [4, 1] -> [2, 4, 3] on int32, checked against the definition
+        let a: Vec<i32> = (0..6).collect();
+        let b = [100i32, 200, 300, 400];
+        let mut output = vec![0i32; 24];
+        execute_binary_broadcast(
+            WasmOperation::Sub,
+            &contiguous_meta(WasmDType::Int32, &[2, 1, 3]),
+            &contiguous_meta(WasmDType::Int32, &[4, 1]),
+            &contiguous_meta(WasmDType::Int32, &[2, 4, 3]),
+            a.as_ptr() as *const u8,
+            b.as_ptr() as *const u8,
+            output.as_mut_ptr() as *mut u8,
+        ).unwrap();
+        for ((i, j, k), &val) in (0..2).flat_map(|i| (0..4).flat_map(move |j| (0..3).map(move |k| (i, j, k)))).zip(&output) {
+            assert_eq!(val, a[i * 3 + k] - b[j]);
+        }
+        
+        // Half precision column vector times row vector
+        let a = [F16::from_f32(1.0), F16::from_f32(2.0)];
+        let b = [F16::from_f32(0.5), F16::from_f32(4.0), F16::from_f32(-1.0)];
+        let mut output = [F16::from_f32(0.0); 6];
+        execute_binary_broadcast(
+            WasmOperation::Mul,
+            &contiguous_meta(WasmDType::Float16, &[2, 1]),
+            &contiguous_meta(WasmDType::Float16, &[3]),
+            &contiguous_meta(WasmDType::Float16, &[2, 3]),
+            a.as_ptr() as *const u8,
+            b.as_ptr() as *const u8,
+            output.as_mut_ptr() as *mut u8,
+        ).unwrap();
+        let output: Vec<f32> = output.iter().map(|v| v.to_f32()).collect();
+        assert_eq!(output, vec![0.5, 4.0, -1.0, 1.0, 8.0, -2.0]);
+        
+        // Bool rows longer than one block: OR with a broadcast scalar
+        let a: Vec<u8> = (0..700).map(|i| (i % 3 == 0) as u8).collect();
+        let mut output = vec![0u8; 700];
+        execute_binary_broadcast(
+            WasmOperation::Add,
+            &contiguous_meta(WasmDType::Bool, &[700]),
+            &contiguous_meta(WasmDType::Bool, &[1]),
+            &contiguous_meta(WasmDType::Bool, &[700]),
+            a.as_ptr(),
+            [1u8].as_ptr(),
+            output.as_mut_ptr(),
+        ).unwrap();
+        assert!(output.iter().all(|&v| v == 1));
+    }
+    
+    #[test]
+    fn test_broadcast_strided_view() {
+        // Transposed [3, 2] view of a [2, 3] buffer plus a [2] vector
+        let a = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let b = [10.0f32, 20.0];
+        let mut output = vec![0.0f32; 6];
+        execute_binary_broadcast(
+            WasmOperation::Add,
+            &WasmTensorMeta::new(WasmDType::Float32, vec![3, 2], vec![1, 3], 6, 0),
+            &contiguous_meta(WasmDType::Float32, &[2]),
+            &contiguous_meta(WasmDType::Float32, &[3, 2]),
+            a.as_ptr() as *const u8,
+            b.as_ptr() as *const u8,
+            output.as_mut_ptr() as *mut u8,
+        ).unwrap();
+        assert_eq!(output, vec![11.0, 24.0, 12.0, 25.0, 13.0, 26.0]);
+    }
+}
@@ -0,0 +1,472 @@
+/*!
+ * Dtype cast operation for WebAssembly backend
+ *
+ * Converts tensors between any pair of `WasmDType`s:
+ * - Float -> integer rounds with the selected `WasmRoundingMode`, then either
+ *   wraps modulo 2^bits (JS typed-array semantics, NaN/Inf become 0) or
+ *   saturates to the target range (NaN becomes 0)
+ * - Integer -> narrower integer wraps or saturates the same way
+ * - Casts to Bool test for non-zero (NaN is true); Bool reads as 0/1
+ * - Casts to floats (including Float16/BFloat16) round to nearest
+ *
+ * Contiguous f32 <-> i32, f32 <-> f64, u8 -> f32 and bf16 -> f32 casts use SIMD kernels
+ * (f32 -> i32 only when saturating, which is what the hardware does).
+ * Strided inputs (views) are read in logical row-major order.
+ */
+
+use crate::types::{WasmDType, WasmResult, WasmError, WasmRoundingMode};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::simd::convert;
+use crate::operations::strided::{StridedIndexer, is_contiguous, output_byte_offset};
+use crate::half::{self, HalfFloat, F16, BF16};
+
+/// 2^64, the modulus for wrapping float -> integer casts
+const TWO_POW_64: f64 = 18446744073709551616.0;
+
+/// Conversion behavior for casts that can lose information
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CastOptions {
+    /// Rounding applied to floats cast to integer dtypes
+    pub rounding: WasmRoundingMode,
+    /// Clamp out-of-range values to the target range instead of wrapping
+    pub saturate: bool,
+}
+
+/// Element that can be read as a cast source
+trait CastSource: Copy {
+    fn cast_to<T: CastTarget>(self, options: CastOptions) -> T;
+}
+
+/// Element that can be written as a cast result
+trait CastTarget: Copy {
+    fn from_int(value: i128, saturate: bool) -> Self;
+    fn from_float(value: f64, options: CastOptions) -> Self;
+}
+
+/// Bool storage byte: any non-zero source value is true
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+struct BoolByte(u8);
+
+macro_rules! impl_cast_source {
+    (int: $($ty:ty),*) => {$(
+        impl CastSource for $ty {
+            #[inline]
+            fn cast_to<T: CastTarget>(self, options: CastOptions) -> T {
+                T::from_int(self as i128, options.saturate)
+            }
+        }
+    )*};
+    (float: $($ty:ty),*) => {$(
+        impl CastSource for $ty {
+            #[inline]
+            fn cast_to<T: CastTarget>(self, options: CastOptions) -> T {
+                T::from_float(self as f64, options)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_cast_target {
+    (int: $($ty:ty),*) => {$(
+        impl CastTarget for $ty {
+            #[inline]
+            fn from_int(value: i128, saturate: bool) -> Self {
+                if saturate {
+                    value.clamp(<$ty>::MIN as i128, <$ty>::MAX as i128) as $ty
+                } else {
+                    value as $ty
+                }
+            }
+
+            #[inline]
+            fn from_float(value: f64, options: CastOptions) -> Self {
+                let rounded = options.rounding.apply(value);
+                if options.saturate {
+                    // Float -> int `as` saturates and maps NaN to 0
+                    rounded as $ty
+                } else if rounded.is_finite() {
+                    // Reduce modulo 2^64 exactly, then wrap to the target width
+                    (rounded % TWO_POW_64) as i128 as $ty
+                } else {
+                    0
+                }
+            }
+        }
+    )*};
+    (float: $($ty:ty),*) => {$(
+        impl CastTarget for $ty {
+            #[inline]
+            fn from_int(value: i128, _saturate: bool) -> Self {
+                value as $ty
+            }
+
+            #[inline]
+            fn from_float(value: f64, _options: CastOptions) -> Self {
+                value as $ty
+            }
+        }
+    )*};
+}
+
+impl_cast_source!(int: i8, u8, i16, u16, i32, u32, i64, u64);
+impl_cast_source!(float: f32, f64);
+impl_cast_target!(int: i8, u8, i16, u16, i32, u32, i64, u64);
+impl_cast_target!(float: f32, f64);
+
+macro_rules! impl_cast_half {
+    ($($ty:ty),*) => {$(
+        impl CastSource for $ty {
+            #[inline]
+            fn cast_to<T: CastTarget>(self, options: CastOptions) -> T {
+                T::from_float(self.to_f32() as f64, options)
+            }
+        }
+
+        impl CastTarget for $ty {
+            #[inline]
+            fn from_int(value: i128, _saturate: bool) -> Self {
+                <$ty>::from_f64(value as f64)
+            }
+
+            #[inline]
+            fn from_float(value: f64, _options: CastOptions) -> Self {
+                <$ty>::from_f64(value)
+            }
+        }
+    )*};
+}
+
+impl_cast_half!(F16, BF16);
+
+impl CastTarget for BoolByte {
+    #[inline]
+    fn from_int(value: i128, _saturate: bool) -> Self {
+        BoolByte((value != 0) as u8)
+    }
+
+    #[inline]
+    fn from_float(value: f64, _options: CastOptions) -> Self {
+        BoolByte((value != 0.0) as u8)
+    }
+}
+
+/// Input of a cast: contiguous data, or a strided walk over a view
+struct CastInput {
+    dtype: WasmDType,
+    ptr: *const u8,
+    indexer: Option<StridedIndexer>,
+}
+
+/// Execute cast of `input` into the dtype of `output`
+pub fn execute_cast_op(
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    options: CastOptions,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    let size = output_meta.size();
+
+    if input_meta.shape() != output_meta.shape() {
+        return Err(WasmError::InvalidShape);
+    }
+
+    let input_dtype = input_meta.dtype();
+    let output_dtype = output_meta.dtype();
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = unsafe { output.get_read_ptr(arena).add(output_byte_offset(output_meta)?) } as *mut u8;
+
+    let input = if is_contiguous(input_meta) {
+        let start = input_meta.offset() * input_dtype.byte_size();
+        let input_ptr = unsafe { input.get_read_ptr(arena).add(start) };
+
+        if input_dtype == output_dtype {
+            unsafe { std::ptr::copy_nonoverlapping(input_ptr, output_ptr, size * input_dtype.byte_size()) };
+            return Ok(());
+        }
+        if execute_cast_simd(input_dtype, output_dtype, input_ptr, output_ptr, size, options) {
+            return Ok(());
+        }
+
+        CastInput { dtype: input_dtype, ptr: input_ptr, indexer: None }
+    } else {
+        CastInput {
+            dtype: input_dtype,
+            ptr: input.get_read_ptr(arena),
+            indexer: Some(StridedIndexer::for_meta(input_meta)?),
+        }
+    };
+
+    match output_dtype {
+        WasmDType::Bool => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut BoolByte, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::Int8 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i8, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::Uint8 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::Int16 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i16, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::Uint16 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u16, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::Int32 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i32, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::Uint32 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u32, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::Float32 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::Float64 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::BigInt64 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i64, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::BigUint64 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u64, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::Float16 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut F16, size) };
+            cast_into(&input, output_slice, options);
+        }
+        WasmDType::BFloat16 => {
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut BF16, size) };
+            cast_into(&input, output_slice, options);
+        }
+    }
+
+    Ok(())
+}
+
+/// SIMD kernels for the common contiguous cast paths (returns false if none applies)
+fn execute_cast_simd(
+    input_dtype: WasmDType,
+    output_dtype: WasmDType,
+    input_ptr: *const u8,
+    output_ptr: *mut u8,
+    size: usize,
+    options: CastOptions,
+) -> bool {
+    match (input_dtype, output_dtype) {
+        (WasmDType::Float32, WasmDType::Int32) if options.saturate => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const f32, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i32, size) };
+            convert::f32_to_i32(input_slice, output_slice, options.rounding);
+        }
+        (WasmDType::Int32, WasmDType::Float32) => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const i32, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            convert::i32_to_f32(input_slice, output_slice);
+        }
+        (WasmDType::Float32, WasmDType::Float64) => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const f32, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
+            convert::f32_to_f64(input_slice, output_slice);
+        }
+        (WasmDType::Float64, WasmDType::Float32) => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const f64, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            convert::f64_to_f32(input_slice, output_slice);
+        }
+        (WasmDType::BFloat16, WasmDType::Float32) => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const BF16, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            half::widen(input_slice, output_slice);
+        }
+        (WasmDType::Uint8, WasmDType::Float32) | (WasmDType::Bool, WasmDType::Float32) => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            convert::u8_to_f32(input_slice, output_slice);
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Cast input of any dtype into a typed output
+fn cast_into<T: CastTarget>(input: &CastInput, output: &mut [T], options: CastOptions) {
+    match input.dtype {
+        WasmDType::Bool | WasmDType::Uint8 => cast_from::<u8, T>(input, output, options),
+        WasmDType::Int8 => cast_from::<i8, T>(input, output, options),
+        WasmDType::Int16 => cast_from::<i16, T>(input, output, options),
+        WasmDType::Uint16 => cast_from::<u16, T>(input, output, options),
+        WasmDType::Int32 => cast_from::<i32, T>(input, output, options),
+        WasmDType::Uint32 => cast_from::<u32, T>(input, output, options),
+        WasmDType::Float32 => cast_from::<f32, T>(input, output, options),
+        WasmDType::Float64 => cast_from::<f64, T>(input, output, options),
+        WasmDType::BigInt64 => cast_from::<i64, T>(input, output, options),
+        WasmDType::BigUint64 => cast_from::<u64, T>(input, output, options),
+        WasmDType::Float16 => cast_from::<F16, T>(input, output, options),
+        WasmDType::BFloat16 => cast_from::<BF16, T>(input, output, options),
+    }
+}
+
+/// Cast typed input elements, walking strided inputs in logical order
+fn cast_from<S: CastSource, T: CastTarget>(input: &CastInput, output: &mut [T], options: CastOptions) {
+    let ptr = input.ptr as *const S;
+
+    match &input.indexer {
+        None => {
+            let input_slice = unsafe { std::slice::from_raw_parts(ptr, output.len()) };
+            for (out, &val) in output.iter_mut().zip(input_slice) {
+                *out = val.cast_to(options);
+            }
+        }
+        Some(indexer) => {
+            let mut indexer = indexer.clone();
+            for out in output.iter_mut() {
+                *out = unsafe { *ptr.add(indexer.offset()) }.cast_to(options);
+                indexer.advance();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cast_slice<S: CastSource, T: CastTarget>(input: &[S], output: &mut [T], options: CastOptions) {
+        for (out, &val) in output.iter_mut().zip(input) {
+            *out = val.cast_to(options);
+        }
+    }
+
+    #[test]
+    fn test_float_to_int_rounding() {
+        let input = [2.5f32, -2.5, 1.7, -1.7];
+        let mut output = [0i32; 4];
+
+        let modes = [
+            (WasmRoundingMode::Truncate, [2, -2, 1, -1]),
+            (WasmRoundingMode::Nearest, [2, -2, 2, -2]),
+            (WasmRoundingMode::Floor, [2, -3, 1, -2]),
+            (WasmRoundingMode::Ceil, [3, -2, 2, -1]),
+        ];
+        for (rounding, expected) in modes {
+            cast_slice(&input, &mut output, CastOptions { rounding, saturate: false });
+            assert_eq!(output, expected, "{:?}", rounding);
+
+            // SIMD kernel agrees with the scalar path for in-range values
+            convert::f32_to_i32(&input, &mut output, rounding);
+            assert_eq!(output, expected, "{:?} (simd)", rounding);
+        }
+    }
+
+    #[test]
+    fn test_float_to_int_wrap_and_saturate() {
+        let input = [300.0f64, -129.0, f64::NAN, f64::INFINITY];
+        let mut output = [0i8; 4];
+
+        cast_slice(&input, &mut output, CastOptions::default());
+        assert_eq!(output, [44, 127, 0, 0]);
+
+        cast_slice(&input, &mut output, CastOptions { saturate: true, ..CastOptions::default() });
+        assert_eq!(output, [127, -128, 0, 127]);
+
+        // Wrapping is exact beyond the i64 range (JS ToUint32 semantics)
+        let mut output = [0u32; 2];
+        cast_slice(&[-1.0f64, 2f64.powi(70) + 2f64.powi(40)], &mut output, CastOptions::default());
+        assert_eq!(output, [u32::MAX, 0]);
+    }
+
+    #[test]
+    fn test_int_to_int() {
+        let input = [-1i32, 70000, 255];
+        let mut output = [0u16; 3];
+
+        cast_slice(&input, &mut output, CastOptions::default());
+        assert_eq!(output, [u16::MAX, 4464, 255]);
+
+        cast_slice(&input, &mut output, CastOptions { saturate: true, ..CastOptions::default() });
+        assert_eq!(output, [0, u16::MAX, 255]);
+
+        let mut output = [0i64; 1];
+        cast_slice(&[u64::MAX], &mut output, CastOptions { saturate: true, ..CastOptions::default() });
+        assert_eq!(output, [i64::MAX]);
+    }
+
+    #[test]
+    fn test_bool_casts() {
+        let mut output = [BoolByte(9); 4];
+        cast_slice(&[0.0f32, -0.0, 0.1, f32::NAN], &mut output, CastOptions::default());
+        assert_eq!(output.map(|b| b.0), [0, 0, 1, 1]);
+
+        let mut output = [BoolByte(9); 2];
+        cast_slice(&[0i64, -5], &mut output, CastOptions::default());
+        assert_eq!(output.map(|b| b.0), [0, 1]);
+    }
+
+    #[test]
+    fn test_simd_conversions() {
+        let bytes: Vec<u8> = (0..37).map(|i| (i * 7) as u8).collect();
+        let mut floats = vec![0.0f32; 37];
+        convert::u8_to_f32(&bytes, &mut floats);
+        assert!(floats.iter().zip(&bytes).all(|(&f, &b)| f == b as f32));
+
+        let mut doubles = vec![0.0f64; 37];
+        convert::f32_to_f64(&floats, &mut doubles);
+        assert!(doubles.iter().zip(&floats).all(|(&d, &f)| d == f as f64));
+
+        let mut narrowed = vec![0.0f32; 37];
+        convert::f64_to_f32(&doubles, &mut narrowed);
+        assert_eq!(narrowed, floats);
+
+        let mut ints = vec![0i32; 37];
+        convert::f32_to_i32(&floats, &mut ints, WasmRoundingMode::Truncate);
+        let mut back = vec![0.0f32; 37];
+        convert::i32_to_f32(&ints, &mut back);
+        assert_eq!(back, floats);
+    }
+
+    #[test]
+    fn test_half_casts() {
+        let mut halves = [F16::default(); 3];
+        cast_slice(&[1.0f64, 70000.0, -0.5], &mut halves, CastOptions::default());
+        assert_eq!(halves.map(|h| h.to_f32()), [1.0, f32::INFINITY, -0.5]);
+
+        let mut output = [0i16; 3];
+        cast_slice(&halves, &mut output, CastOptions { saturate: true, ..CastOptions::default() });
+        assert_eq!(output, [1, i16::MAX, 0]);
+
+        let mut output = [BF16::default(); 2];
+        cast_slice(&[F16::from_f32(3.0), F16::from_f32(-0.125)], &mut output, CastOptions::default());
+        assert_eq!(output.map(|h| h.to_f32()), [3.0, -0.125]);
+    }
+
+    #[test]
+    fn test_strided_input() {
+        // [2, 3] i16 storage read through its [3, 2] transpose
+        let storage = [1i16, 2, 3, 4, 5, 6];
+        let input = CastInput {
+            dtype: WasmDType::Int16,
+            ptr: storage.as_ptr() as *const u8,
+            indexer: Some(StridedIndexer::new(&[3, 2], &[1, 3], 0)),
+        };
+        let mut output = [0.0f64; 6];
+        cast_into(&input, &mut output, CastOptions::default());
+        assert_eq!(output, [1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    }
+}
@@ -0,0 +1,317 @@
+/*!
+ * Comparison and logical operations for WebAssembly backend
+ *
+ * Every operation writes a Bool tensor (one 0/1 byte per element):
+ * - `Eq`, `Ne`, `Lt`, `Le`, `Gt`, `Ge` compare in the promoted dtype of
+ *   their inputs (`binary_result_dtype`), so e.g. `int8 < uint8` compares
+ *   as int16 and never wraps. NaN compares false except under `Ne`.
+ * - `LogicalAnd`, `LogicalOr`, `LogicalXor` and `LogicalNot` treat any
+ *   nonzero value (including NaN) as true.
+ *
+ * Binary operations broadcast like arithmetic ops and read strided views
+ * directly (see `binary::execute_binary_broadcast_typed`). Float32 and Int32
+ * comparisons use SIMD; half-precision inputs are compared as f32, which
+ * represents them exactly.
+ */
+
+use crate::types::{WasmOperation, WasmTensorMeta, WasmDType, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::promotion::binary_result_dtype;
+use crate::simd::compare::{self as simd_compare, Comparison};
+use crate::operations::binary::{
+    BroadcastInput, BroadcastLayout, execute_binary_broadcast_typed, PROMOTION_BLOCK_SIZE,
+};
+use crate::operations::element::PromotedElement;
+use crate::operations::strided::{StridedIndexer, is_contiguous};
+
+/// Comparison predicate of an operation, if it is one
+fn comparison(operation: WasmOperation) -> Option<Comparison> {
+    match operation {
+        WasmOperation::Eq => Some(Comparison::Eq),
+        WasmOperation::Ne => Some(Comparison::Ne),
+        WasmOperation::Lt => Some(Comparison::Lt),
+        WasmOperation::Le => Some(Comparison::Le),
+        WasmOperation::Gt => Some(Comparison::Gt),
+        WasmOperation::Ge => Some(Comparison::Ge),
+        _ => None,
+    }
+}
+
+/// Storage dtype inputs are loaded in: half precision compares as f32
+fn compute_dtype(dtype: WasmDType) -> WasmDType {
+    match dtype {
+        WasmDType::Float16 | WasmDType::BFloat16 => WasmDType::Float32,
+        _ => dtype,
+    }
+}
+
+/// Execute a binary comparison or logical operation into a Bool `output`
+pub(crate) fn execute_compare_op(
+    operation: WasmOperation,
+    (input_meta_a, input_a_ptr): (&WasmTensorMeta, *const u8),
+    (input_meta_b, input_b_ptr): (&WasmTensorMeta, *const u8),
+    output_meta: &WasmTensorMeta,
+    output_ptr: *mut u8,
+) -> WasmResult<()> {
+    if output_meta.dtype() != WasmDType::Bool {
+        return Err(WasmError::InvalidDType);
+    }
+
+    let layout = BroadcastLayout::new(input_meta_a, input_meta_b, output_meta)?;
+    let dtype = compute_dtype(binary_result_dtype(input_meta_a.dtype(), input_meta_b.dtype()));
+    let input_a = BroadcastInput::new(input_meta_a, input_a_ptr, dtype);
+    let input_b = BroadcastInput::new(input_meta_b, input_b_ptr, dtype);
+    let inputs = (&input_a, &input_b);
+    let output = unsafe { std::slice::from_raw_parts_mut(output_ptr, output_meta.size()) };
+
+    match dtype {
+        WasmDType::Float32 | WasmDType::Float16 | WasmDType::BFloat16 => {
+            execute_binary_broadcast_typed::<f32, u8>(operation, inputs, &layout, output, compare_f32)
+        }
+        WasmDType::Int32 => {
+            execute_binary_broadcast_typed::<i32, u8>(operation, inputs, &layout, output, compare_i32)
+        }
+        WasmDType::Float64 => execute_binary_broadcast_typed::<f64, u8>(operation, inputs, &layout, output, compare),
+        WasmDType::Int8 => execute_binary_broadcast_typed::<i8, u8>(operation, inputs, &layout, output, compare),
+        WasmDType::Uint8 | WasmDType::Bool => {
+            execute_binary_broadcast_typed::<u8, u8>(operation, inputs, &layout, output, compare)
+        }
+        WasmDType::Int16 => execute_binary_broadcast_typed::<i16, u8>(operation, inputs, &layout, output, compare),
+        WasmDType::Uint16 => execute_binary_broadcast_typed::<u16, u8>(operation, inputs, &layout, output, compare),
+        WasmDType::Uint32 => execute_binary_broadcast_typed::<u32, u8>(operation, inputs, &layout, output, compare),
+        WasmDType::BigInt64 => execute_binary_broadcast_typed::<i64, u8>(operation, inputs, &layout, output, compare),
+        WasmDType::BigUint64 => execute_binary_broadcast_typed::<u64, u8>(operation, inputs, &layout, output, compare),
+    }
+}
+
+/// Comparison and logical kernel for any element type
+fn compare<T: PromotedElement + PartialOrd>(
+    operation: WasmOperation,
+    a: &[T],
+    b: &[T],
+    output: &mut [u8],
+) -> WasmResult<()> {
+    if let Some(comparison) = comparison(operation) {
+        simd_compare::scalar_compare(comparison, a, b, output);
+        return Ok(());
+    }
+
+    let zero = T::default();
+    let pairs = output.iter_mut().zip(a.iter().zip(b));
+    match operation {
+        WasmOperation::LogicalAnd => pairs.for_each(|(out, (&x, &y))| *out = (x != zero && y != zero) as u8),
+        WasmOperation::LogicalOr => pairs.for_each(|(out, (&x, &y))| *out = (x != zero || y != zero) as u8),
+        WasmOperation::LogicalXor => pairs.for_each(|(out, (&x, &y))| *out = ((x != zero) != (y != zero)) as u8),
+        _ => return Err(WasmError::InvalidOperation),
+    }
+    Ok(())
+}
+
+/// f32 kernel: SIMD comparisons, generic logical ops
+fn compare_f32(operation: WasmOperation, a: &[f32], b: &[f32], output: &mut [u8]) -> WasmResult<()> {
+    match comparison(operation) {
+        Some(comparison) => {
+            simd_compare::f32_compare(comparison, a, b, output);
+            Ok(())
+        }
+        None => compare(operation, a, b, output),
+    }
+}
+
+/// i32 kernel: SIMD comparisons, generic logical ops
+fn compare_i32(operation: WasmOperation, a: &[i32], b: &[i32], output: &mut [u8]) -> WasmResult<()> {
+    match comparison(operation) {
+        Some(comparison) => {
+            simd_compare::i32_compare(comparison, a, b, output);
+            Ok(())
+        }
+        None => compare(operation, a, b, output),
+    }
+}
+
+/// Execute logical not: Bool `output` is 1 where `input` is zero
+pub fn execute_logical_not_op(
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    if output_meta.dtype() != WasmDType::Bool {
+        return Err(WasmError::InvalidDType);
+    }
+    if input_meta.size() != output_meta.size() {
+        return Err(WasmError::InvalidShape);
+    }
+
+    let input_ptr = input.get_read_ptr(arena);
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = output.get_read_ptr(arena) as *mut u8;
+    let out = unsafe { std::slice::from_raw_parts_mut(output_ptr, output_meta.size()) };
+
+    match compute_dtype(input_meta.dtype()) {
+        WasmDType::Float32 | WasmDType::Float16 | WasmDType::BFloat16 => logical_not_typed::<f32>(input_meta, input_ptr, out),
+        WasmDType::Float64 => logical_not_typed::<f64>(input_meta, input_ptr, out),
+        WasmDType::Int8 => logical_not_typed::<i8>(input_meta, input_ptr, out),
+        WasmDType::Uint8 | WasmDType::Bool => logical_not_typed::<u8>(input_meta, input_ptr, out),
+        WasmDType::Int16 => logical_not_typed::<i16>(input_meta, input_ptr, out),
+        WasmDType::Uint16 => logical_not_typed::<u16>(input_meta, input_ptr, out),
+        WasmDType::Int32 => logical_not_typed::<i32>(input_meta, input_ptr, out),
+        WasmDType::Uint32 => logical_not_typed::<u32>(input_meta, input_ptr, out),
+        WasmDType::BigInt64 => logical_not_typed::<i64>(input_meta, input_ptr, out),
+        WasmDType::BigUint64 => logical_not_typed::<u64>(input_meta, input_ptr, out),
+    }
+    Ok(())
+}
+
+/// Logical not of a tensor loaded as `T`, gathering strided views in blocks
+fn logical_not_typed<T: PromotedElement + PartialEq>(meta: &WasmTensorMeta, ptr: *const u8, output: &mut [u8]) {
+    let zero = T::default();
+
+    if is_contiguous(meta) && compute_dtype(meta.dtype()) == meta.dtype() {
+        let input = unsafe { std::slice::from_raw_parts((ptr as *const T).add(meta.offset()), output.len()) };
+        for (out, &val) in output.iter_mut().zip(input) {
+            *out = (val == zero) as u8;
+        }
+        return;
+    }
+
+    let mut indexer = StridedIndexer::new(&meta.shape(), &meta.strides(), meta.offset());
+    let mut offsets = [0usize; PROMOTION_BLOCK_SIZE];
+    let mut block = [T::default(); PROMOTION_BLOCK_SIZE];
+    for out_chunk in output.chunks_mut(PROMOTION_BLOCK_SIZE) {
+        let len = out_chunk.len();
+        indexer.fill(&mut offsets[..len]);
+        unsafe { T::load_block(meta.dtype(), ptr, &offsets[..len], &mut block[..len]) };
+        for (out, &val) in out_chunk.iter_mut().zip(&block[..len]) {
+            *out = (val == zero) as u8;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::half::{HalfFloat, F16};
+    use crate::operations::strided::contiguous_strides;
+
+    fn contiguous_meta(dtype: WasmDType, shape: &[usize]) -> WasmTensorMeta {
+        WasmTensorMeta::new(dtype, shape.to_vec(), contiguous_strides(shape), shape.iter().product(), 0)
+    }
+
+    fn run<A, B>(
+        operation: WasmOperation,
+        (a, dtype_a, shape_a): (&[A], WasmDType, &[usize]),
+        (b, dtype_b, shape_b): (&[B], WasmDType, &[usize]),
+        out_shape: &[usize],
+    ) -> WasmResult<Vec<u8>> {
+        let mut output = vec![0xAAu8; out_shape.iter().product()];
+        execute_compare_op(
+            operation,
+            (&contiguous_meta(dtype_a, shape_a), a.as_ptr() as *const u8),
+            (&contiguous_meta(dtype_b, shape_b), b.as_ptr() as *const u8),
+            &contiguous_meta(WasmDType::Bool, out_shape),
+            output.as_mut_ptr(),
+        )?;
+        Ok(output)
+    }
+
+    #[test]
+    fn test_f32_comparisons_with_nan() {
+        // 19 elements cover a full 16-wide SIMD block and a scalar tail
+        let a: Vec<f32> = (0..19).map(|i| if i == 17 { f32::NAN } else { (i % 5) as f32 }).collect();
+        let b = vec![2.0f32; 19];
+        let operations = [
+            WasmOperation::Eq, WasmOperation::Ne, WasmOperation::Lt,
+            WasmOperation::Le, WasmOperation::Gt, WasmOperation::Ge,
+        ];
+        for operation in operations {
+            let predicate = comparison(operation).unwrap();
+            let output = run(
+                operation,
+                (&a, WasmDType::Float32, &[19]),
+                (&b, WasmDType::Float32, &[19]),
+                &[19],
+            ).unwrap();
+            let expected: Vec<u8> = a.iter().zip(&b).map(|(&x, &y)| predicate.apply(x, y) as u8).collect();
+            assert_eq!(output, expected, "{:?}", operation);
+        }
+    }
+
+    #[test]
+    fn test_broadcast_and_promoted_compare() {
+        // int32 [2, 1] < int32 [3] -> [2, 3]
+        let output = run(
+            WasmOperation::Lt,
+            (&[1i32, 5], WasmDType::Int32, &[2, 1]),
+            (&[0i32, 3, 6], WasmDType::Int32, &[3]),
+            &[2, 3],
+        ).unwrap();
+        assert_eq!(output, vec![0, 1, 1, 0, 0, 1]);
+
+        // int8 vs uint8 compares as int16: -1 is not equal to 255
+        let output = run(
+            WasmOperation::Eq,
+            (&[-1i8, 7], WasmDType::Int8, &[2]),
+            (&[255u8, 7], WasmDType::Uint8, &[2]),
+            &[2],
+        ).unwrap();
+        assert_eq!(output, vec![0, 1]);
+
+        // Float16 against a scalar float32 threshold
+        let halves: Vec<F16> = [0.5f32, 1.5, -2.0].iter().map(|&v| F16::from_f32(v)).collect();
+        let output = run(
+            WasmOperation::Ge,
+            (&halves, WasmDType::Float16, &[3]),
+            (&[0.5f32], WasmDType::Float32, &[]),
+            &[3],
+        ).unwrap();
+        assert_eq!(output, vec![1, 1, 0]);
+    }
+
+    #[test]
+    fn test_logical_ops() {
+        let a = [0.0f32, -0.0, 2.5, f32::NAN];
+        let b = [1u8, 0, 0, 1];
+        let and = run(WasmOperation::LogicalAnd, (&a, WasmDType::Float32, &[4]), (&b, WasmDType::Bool, &[4]), &[4]);
+        let or = run(WasmOperation::LogicalOr, (&a, WasmDType::Float32, &[4]), (&b, WasmDType::Bool, &[4]), &[4]);
+        let xor = run(WasmOperation::LogicalXor, (&a, WasmDType::Float32, &[4]), (&b, WasmDType::Bool, &[4]), &[4]);
+        assert_eq!(and.unwrap(), vec![0, 0, 0, 1]);
+        assert_eq!(or.unwrap(), vec![1, 0, 1, 1]);
+        assert_eq!(xor.unwrap(), vec![1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_invalid_output() {
+        let a = [1.0f32; 2];
+        let mut output = [0.0f32; 2];
+        let result = execute_compare_op(
+            WasmOperation::Eq,
+            (&contiguous_meta(WasmDType::Float32, &[2]), a.as_ptr() as *const u8),
+            (&contiguous_meta(WasmDType::Float32, &[2]), a.as_ptr() as *const u8),
+            &contiguous_meta(WasmDType::Float32, &[2]),
+            output.as_mut_ptr() as *mut u8,
+        );
+        assert!(matches!(result, Err(WasmError::InvalidDType)));
+
+        let result = run(WasmOperation::Add, (&a, WasmDType::Float32, &[2]), (&a, WasmDType::Float32, &[2]), &[2]);
+        assert!(matches!(result, Err(WasmError::InvalidOperation)));
+    }
+
+    #[test]
+    fn test_logical_not_strided() {
+        // Every other element of [0, 1, 2, 0, 0, 5] -> [0, 2, 0]
+        let input = [0i16, 1, 2, 0, 0, 5];
+        let meta = WasmTensorMeta::new(WasmDType::Int16, vec![3], vec![2], 3, 0);
+        let mut output = vec![0xAAu8; 3];
+        logical_not_typed::<i16>(&meta, input.as_ptr() as *const u8, &mut output);
+        assert_eq!(output, vec![1, 0, 1]);
+
+        let halves: Vec<F16> = [0.0f32, -0.0, 3.0].iter().map(|&v| F16::from_f32(v)).collect();
+        logical_not_typed::<f32>(&contiguous_meta(WasmDType::Float16, &[3]), halves.as_ptr() as *const u8, &mut output);
+        assert_eq!(output, vec![1, 1, 0]);
+    }
+}
@@ -0,0 +1,297 @@
+/*!
+ * Einops rearrange, reduce and repeat for WebAssembly backend
+ *
+ * A `WasmEinopsPlan` is the compiled form of an einops pattern for one input
+ * shape. Elementary axes are numbered in the order they are declared:
+ * - `input_axis(sizes)` once per input axis splits it into elementary axes
+ *   (`(h w)` -> `[h, w]`, `()` -> `[]` for an axis of length 1)
+ * - `new_axis(size)` declares an axis that only exists in the output (repeat)
+ * - `output_axis(axes)` once per output axis merges elementary axes in the
+ *   given order (`[]` gives an axis of length 1)
+ * - `reduce(operation)` makes it a reduce plan: elementary axes missing from
+ *   the output are reduced with Sum, Mean, Max, Min or Prod
+ *
+ * Splitting input axes only rewrites strides and merging output axes is free
+ * on the contiguous output, so every plan runs as a single pass over a view
+ * of the input: a strided permute copy for rearrange and repeat (new axes
+ * have stride 0), or a strided reduction over the trailing missing axes.
+ */
+
+use wasm_bindgen::prelude::*;
+use crate::types::{WasmOperation, WasmTensorMeta, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::operations::{permute, reduction};
+
+/// Elementary axis of a plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ElementaryAxis {
+    size: usize,
+    /// Input axis this axis is split from (`None` for new axes)
+    input_axis: Option<usize>,
+}
+
+/// Compiled einops rearrange / reduce / repeat pattern
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WasmEinopsPlan {
+    elementary: Vec<ElementaryAxis>,
+    input_rank: usize,
+    output_axes: Vec<Vec<usize>>,
+    reduction: Option<WasmOperation>,
+}
+
+#[wasm_bindgen]
+impl WasmEinopsPlan {
+    /// Create empty plan
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmEinopsPlan {
+        WasmEinopsPlan::default()
+    }
+
+    /// Split the next input axis into elementary axes of `sizes` (outermost first)
+    #[wasm_bindgen]
+    pub fn input_axis(&mut self, sizes: &[u32]) {
+        let input_axis = Some(self.input_rank);
+        self.elementary.extend(sizes.iter().map(|&size| ElementaryAxis { size: size as usize, input_axis }));
+        self.input_rank += 1;
+    }
+
+    /// Declare an elementary axis of `size` that is not in the input (repeat)
+    #[wasm_bindgen]
+    pub fn new_axis(&mut self, size: u32) {
+        self.elementary.push(ElementaryAxis { size: size as usize, input_axis: None });
+    }
+
+    /// Append an output axis merging elementary `axes` (outermost first)
+    #[wasm_bindgen]
+    pub fn output_axis(&mut self, axes: &[u32]) {
+        self.output_axes.push(axes.iter().map(|&axis| axis as usize).collect());
+    }
+
+    /// Reduce elementary axes missing from the output with `operation`
+    #[wasm_bindgen]
+    pub fn reduce(&mut self, operation: WasmOperation) {
+        self.reduction = Some(operation);
+    }
+
+    /// `Rearrange` (also used for repeat) or `Reduce`
+    #[wasm_bindgen(getter)]
+    pub fn operation(&self) -> WasmOperation {
+        match self.reduction {
+            Some(_) => WasmOperation::Reduce,
+            None => WasmOperation::Rearrange,
+        }
+    }
+}
+
+/// Plan resolved against a concrete input layout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedEinops {
+    /// View shape: kept elementary axes in output order, then reduced axes
+    pub shape: Vec<usize>,
+    /// Input element strides of the view
+    pub strides: Vec<usize>,
+    /// Number of trailing reduced axes in `shape`
+    pub reduced: usize,
+    /// Output shape
+    pub output_shape: Vec<usize>,
+}
+
+impl WasmEinopsPlan {
+    /// Resolve against an input shape and strides
+    pub fn resolve(&self, shape: &[usize], strides: &[usize]) -> WasmResult<ResolvedEinops> {
+        if self.input_rank != shape.len() || strides.len() != shape.len() {
+            return Err(WasmError::InvalidShape);
+        }
+        if let Some(operation) = self.reduction {
+            if !matches!(operation, WasmOperation::Sum | WasmOperation::Mean | WasmOperation::Max |
+                WasmOperation::Min | WasmOperation::Prod) {
+                return Err(WasmError::InvalidOperation);
+            }
+        }
+
+        // Split strides: the innermost elementary axis keeps the input stride
+        let mut elementary_strides = vec![0usize; self.elementary.len()];
+        let mut extents = vec![1usize; shape.len()];
+        for (index, axis) in self.elementary.iter().enumerate().rev() {
+            if let Some(input_axis) = axis.input_axis {
+                elementary_strides[index] = strides[input_axis] * extents[input_axis];
+                extents[input_axis] *= axis.size;
+            }
+        }
+        if extents != shape {
+            return Err(WasmError::InvalidShape);
+        }
+
+        let mut used = vec![false; self.elementary.len()];
+        let mut resolved = ResolvedEinops { shape: Vec::new(), strides: Vec::new(), reduced: 0, output_shape: Vec::new() };
+        for group in &self.output_axes {
+            let mut size = 1;
+            for &index in group {
+                if index >= used.len() || used[index] {
+                    return Err(WasmError::InvalidInput);
+                }
+                used[index] = true;
+                size *= self.elementary[index].size;
+                resolved.shape.push(self.elementary[index].size);
+                resolved.strides.push(elementary_strides[index]);
+            }
+            resolved.output_shape.push(size);
+        }
+
+        // Missing axes are reduced; without a reduction only length-1 input axes may be dropped
+        for (index, axis) in self.elementary.iter().enumerate().filter(|&(index, _)| !used[index]) {
+            if axis.input_axis.is_none() || (self.reduction.is_none() && axis.size != 1) {
+                return Err(WasmError::InvalidInput);
+            }
+            if self.reduction.is_some() {
+                resolved.shape.push(axis.size);
+                resolved.strides.push(elementary_strides[index]);
+                resolved.reduced += 1;
+            }
+        }
+        Ok(resolved)
+    }
+}
+
+/// Execute an einops plan: one strided copy (rearrange, repeat) or reduction (reduce)
+pub fn execute_einops_op(
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    plan: &WasmEinopsPlan,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    if input_meta.dtype() != output_meta.dtype() {
+        return Err(WasmError::InvalidDType);
+    }
+
+    let resolved = plan.resolve(&input_meta.shape(), &input_meta.strides())?;
+    if output_meta.shape() != resolved.output_shape {
+        return Err(WasmError::InvalidShape);
+    }
+
+    let size = resolved.shape.iter().product();
+    let rank = resolved.shape.len();
+    let view = input.create_view(WasmTensorMeta::new(
+        input_meta.dtype(),
+        resolved.shape,
+        resolved.strides,
+        size,
+        input_meta.offset(),
+    ));
+
+    match plan.reduction {
+        Some(operation) if resolved.reduced > 0 => {
+            let axes: Vec<usize> = (rank - resolved.reduced..rank).collect();
+            reduction::execute_reduction_op_with_axes(operation, &view, output, arena, Some(&axes), false)
+        }
+        _ => permute::execute_contiguous_copy_op(&view, output, arena),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::strided::contiguous_strides;
+
+    fn resolve(plan: &WasmEinopsPlan, shape: &[usize]) -> WasmResult<ResolvedEinops> {
+        plan.resolve(shape, &contiguous_strides(shape))
+    }
+
+    #[test]
+    fn test_rearrange_split_permute_merge() {
+        // 'b (h w) c -> b c h w' with h = 2 on [2, 6, 4]
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[2]);
+        plan.input_axis(&[2, 3]);
+        plan.input_axis(&[4]);
+        for axis in [0, 3, 1, 2] {
+            plan.output_axis(&[axis]);
+        }
+
+        let resolved = resolve(&plan, &[2, 6, 4]).unwrap();
+        assert_eq!(resolved.shape, vec![2, 4, 2, 3]);
+        assert_eq!(resolved.strides, vec![24, 1, 12, 4]);
+        assert_eq!(resolved.output_shape, vec![2, 4, 2, 3]);
+        assert_eq!(plan.operation(), WasmOperation::Rearrange);
+
+        // 'b c h w -> b (h w c)' merges in the requested order
+        let mut plan = WasmEinopsPlan::new();
+        for size in [2, 3, 4, 5] {
+            plan.input_axis(&[size]);
+        }
+        plan.output_axis(&[0]);
+        plan.output_axis(&[2, 3, 1]);
+        let resolved = resolve(&plan, &[2, 3, 4, 5]).unwrap();
+        assert_eq!(resolved.strides, vec![60, 5, 1, 20]);
+        assert_eq!(resolved.output_shape, vec![2, 60]);
+    }
+
+    #[test]
+    fn test_reduce_and_repeat() {
+        // 'b (h 2) (w 2) -> b h w' max-pooling: the 2s are reduced, trailing
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[3]);
+        plan.input_axis(&[4, 2]);
+        plan.input_axis(&[5, 2]);
+        plan.output_axis(&[0]);
+        plan.output_axis(&[1]);
+        plan.output_axis(&[3]);
+        plan.reduce(WasmOperation::Max);
+
+        let resolved = resolve(&plan, &[3, 8, 10]).unwrap();
+        assert_eq!(resolved.shape, vec![3, 4, 5, 2, 2]);
+        assert_eq!(resolved.strides, vec![80, 20, 2, 10, 1]);
+        assert_eq!(resolved.reduced, 2);
+        assert_eq!(plan.operation(), WasmOperation::Reduce);
+
+        // 'h w -> h r w' repeats along a stride-0 axis
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[2]);
+        plan.input_axis(&[3]);
+        plan.new_axis(4);
+        for axis in [0, 2, 1] {
+            plan.output_axis(&[axis]);
+        }
+        let resolved = resolve(&plan, &[2, 3]).unwrap();
+        assert_eq!(resolved.strides, vec![3, 0, 1]);
+        assert_eq!(resolved.output_shape, vec![2, 4, 3]);
+    }
+
+    #[test]
+    fn test_validation() {
+        // Group sizes must multiply to the input dimension
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[2, 2]);
+        plan.output_axis(&[0, 1]);
+        assert!(matches!(resolve(&plan, &[6]), Err(WasmError::InvalidShape)));
+        assert!(matches!(resolve(&plan, &[4, 1]), Err(WasmError::InvalidShape)));
+
+        // Rearrange may only drop length-1 axes; axes are used at most once
+        plan.output_axis(&[1]);
+        assert!(matches!(resolve(&plan, &[4]), Err(WasmError::InvalidInput)));
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[3]);
+        plan.input_axis(&[]);
+        plan.input_axis(&[1]);
+        assert!(matches!(resolve(&plan, &[3, 1, 1]), Err(WasmError::InvalidInput)));
+        plan.output_axis(&[0]);
+        plan.output_axis(&[]);
+        assert_eq!(resolve(&plan, &[3, 1, 1]).unwrap().output_shape, vec![3, 1]);
+
+        // New axes cannot be reduced, and only Sum/Mean/Max/Min/Prod reduce
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[3]);
+        plan.new_axis(2);
+        plan.output_axis(&[0]);
+        plan.reduce(WasmOperation::Sum);
+        assert!(matches!(resolve(&plan, &[3]), Err(WasmError::InvalidInput)));
+        let mut plan = WasmEinopsPlan::new();
+        plan.input_axis(&[3]);
+        plan.reduce(WasmOperation::Exp);
+        assert!(matches!(resolve(&plan, &[3]), Err(WasmError::InvalidOperation)));
+    }
+}
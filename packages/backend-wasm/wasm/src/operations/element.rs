@@ -0,0 +1,274 @@
+/*!
+ * Element type traits shared by dtype-generic kernels
+ *
+ * Lets integer kernels be written once for every `WasmDType` instead of
+ * once per primitive type.
+ */
+
+use crate::types::WasmDType;
+use crate::half::{self, HalfFloat, F16, BF16};
+
+/// Elements staged through f32 per block when loading into half precision
+const HALF_STAGING_BLOCK: usize = 256;
+
+/// Integer element with explicitly wrapping arithmetic
+///
+/// Matches JavaScript typed-array semantics: results are reduced modulo
+/// 2^bits, so e.g. `-(-128i8)` and `abs(-128i8)` stay `-128`.
+pub trait IntElement: Copy + Ord {
+    fn wrapping_neg(self) -> Self;
+    fn wrapping_abs(self) -> Self;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+    /// Integer division; division by zero saturates to MAX (positive
+    /// dividend) or MIN (otherwise), as the i32 kernels always have
+    fn div_or_saturate(self, other: Self) -> Self;
+    /// `max(self, 0)`
+    fn relu(self) -> Self;
+    /// -1, 0 or 1 by sign
+    fn signum(self) -> Self;
+    /// `self` to the power `exp`; negative exponents truncate toward zero
+    /// like integer division (only bases 1 and -1 survive)
+    fn wrapping_pow(self, exp: Self) -> Self;
+    /// Remainder with the sign of the divisor (Python/PyTorch `%`);
+    /// a zero divisor gives 0
+    fn floor_mod(self, other: Self) -> Self;
+}
+
+/// Square-and-multiply over the bits of a non-negative exponent
+macro_rules! pow_by_squaring {
+    ($base:expr, $exp:expr) => {{
+        let (mut base, mut exp, mut result) = ($base, $exp, 1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.wrapping_mul(base);
+            }
+            base = base.wrapping_mul(base);
+            exp >>= 1;
+        }
+        result
+    }};
+}
+
+macro_rules! impl_int_element_signed {
+    ($($ty:ty),*) => {$(
+        impl IntElement for $ty {
+            #[inline]
+            fn wrapping_neg(self) -> Self { <$ty>::wrapping_neg(self) }
+            #[inline]
+            fn wrapping_abs(self) -> Self { <$ty>::wrapping_abs(self) }
+            #[inline]
+            fn wrapping_add(self, other: Self) -> Self { <$ty>::wrapping_add(self, other) }
+            #[inline]
+            fn wrapping_sub(self, other: Self) -> Self { <$ty>::wrapping_sub(self, other) }
+            #[inline]
+            fn wrapping_mul(self, other: Self) -> Self { <$ty>::wrapping_mul(self, other) }
+            #[inline]
+            fn div_or_saturate(self, other: Self) -> Self {
+                if other == 0 {
+                    if self > 0 { <$ty>::MAX } else { <$ty>::MIN }
+                } else {
+                    <$ty>::wrapping_div(self, other)
+                }
+            }
+            #[inline]
+            fn relu(self) -> Self { self.max(0) }
+            #[inline]
+            fn signum(self) -> Self { <$ty>::signum(self) }
+            #[inline]
+            fn wrapping_pow(self, exp: Self) -> Self {
+                if exp >= 0 {
+                    pow_by_squaring!(self, exp)
+                } else {
+                    match self {
+                        1 => 1,
+                        -1 => if exp & 1 == 0 { 1 } else { -1 },
+                        _ => 0,
+                    }
+                }
+            }
+            #[inline]
+            fn floor_mod(self, other: Self) -> Self {
+                if other == 0 {
+                    return 0;
+                }
+                let remainder = <$ty>::wrapping_rem(self, other);
+                if remainder != 0 && (remainder < 0) != (other < 0) {
+                    remainder.wrapping_add(other)
+                } else {
+                    remainder
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_int_element_unsigned {
+    ($($ty:ty),*) => {$(
+        impl IntElement for $ty {
+            #[inline]
+            fn wrapping_neg(self) -> Self { <$ty>::wrapping_neg(self) }
+            #[inline]
+            fn wrapping_abs(self) -> Self { self }
+            #[inline]
+            fn wrapping_add(self, other: Self) -> Self { <$ty>::wrapping_add(self, other) }
+            #[inline]
+            fn wrapping_sub(self, other: Self) -> Self { <$ty>::wrapping_sub(self, other) }
+            #[inline]
+            fn wrapping_mul(self, other: Self) -> Self { <$ty>::wrapping_mul(self, other) }
+            #[inline]
+            fn div_or_saturate(self, other: Self) -> Self {
+                if other == 0 {
+                    if self > 0 { <$ty>::MAX } else { <$ty>::MIN }
+                } else {
+                    <$ty>::wrapping_div(self, other)
+                }
+            }
+            #[inline]
+            fn relu(self) -> Self { self }
+            #[inline]
+            fn signum(self) -> Self { (self != 0) as $ty }
+            #[inline]
+            fn wrapping_pow(self, exp: Self) -> Self { pow_by_squaring!(self, exp) }
+            #[inline]
+            fn floor_mod(self, other: Self) -> Self {
+                if other == 0 { 0 } else { self % other }
+            }
+        }
+    )*};
+}
+
+impl_int_element_signed!(i8, i16, i32, i64);
+impl_int_element_unsigned!(u8, u16, u32, u64);
+
+/// Element that can be converted to a float for promoted math
+pub trait ToFloat: Copy {
+    fn to_f32(self) -> f32;
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_float {
+    ($($ty:ty),*) => {$(
+        impl ToFloat for $ty {
+            #[inline]
+            fn to_f32(self) -> f32 { self as f32 }
+            #[inline]
+            fn to_f64(self) -> f64 { self as f64 }
+        }
+    )*};
+}
+
+impl_to_float!(i8, u8, i16, u16, i32, u32, i64, u64, f32, f64);
+
+/// Element that inputs of any dtype can be promoted into
+pub trait PromotedElement: Copy + Default {
+    /// Gather `dst.len()` elements of a `dtype` buffer, converting each to `Self`
+    ///
+    /// # Safety
+    /// `ptr` must point to a buffer of `dtype` elements containing every
+    /// index in `offsets`.
+    unsafe fn load_block(dtype: WasmDType, ptr: *const u8, offsets: &[usize], dst: &mut [Self]);
+}
+
+macro_rules! load_converted {
+    ($src:ty, $ptr:expr, $offsets:expr, $dst:expr, |$val:ident| $convert:expr) => {{
+        let src = $ptr as *const $src;
+        for (out, &offset) in $dst.iter_mut().zip($offsets) {
+            let $val = *src.add(offset);
+            *out = $convert;
+        }
+    }};
+}
+
+macro_rules! impl_promoted_element {
+    ($($ty:ty),*) => {$(
+        impl PromotedElement for $ty {
+            #[inline]
+            unsafe fn load_block(dtype: WasmDType, ptr: *const u8, offsets: &[usize], dst: &mut [Self]) {
+                match dtype {
+                    WasmDType::Bool => load_converted!(u8, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::Int8 => load_converted!(i8, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::Uint8 => load_converted!(u8, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::Int16 => load_converted!(i16, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::Uint16 => load_converted!(u16, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::Int32 => load_converted!(i32, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::Uint32 => load_converted!(u32, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::Float32 => load_converted!(f32, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::Float64 => load_converted!(f64, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::BigInt64 => load_converted!(i64, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::BigUint64 => load_converted!(u64, ptr, offsets, dst, |v| v as $ty),
+                    WasmDType::Float16 => load_converted!(F16, ptr, offsets, dst, |v| v.to_f32() as $ty),
+                    WasmDType::BFloat16 => load_converted!(BF16, ptr, offsets, dst, |v| v.to_f32() as $ty),
+                }
+            }
+        }
+    )*};
+}
+
+impl_promoted_element!(i8, u8, i16, u16, i32, u32, i64, u64, f32, f64);
+
+macro_rules! impl_promoted_half {
+    ($($ty:ty),*) => {$(
+        impl PromotedElement for $ty {
+            /// Staged through f32, which is exact for every dtype that promotes into half precision
+            #[inline]
+            unsafe fn load_block(dtype: WasmDType, ptr: *const u8, offsets: &[usize], dst: &mut [Self]) {
+                let mut staging = [0.0f32; HALF_STAGING_BLOCK];
+                for (offset_chunk, dst_chunk) in offsets.chunks(HALF_STAGING_BLOCK).zip(dst.chunks_mut(HALF_STAGING_BLOCK)) {
+                    let staged = &mut staging[..offset_chunk.len()];
+                    f32::load_block(dtype, ptr, offset_chunk, staged);
+                    half::narrow(staged, dst_chunk);
+                }
+            }
+        }
+    )*};
+}
+
+impl_promoted_half!(F16, BF16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrapping_semantics() {
+        assert_eq!(IntElement::wrapping_neg(i8::MIN), i8::MIN);
+        assert_eq!(IntElement::wrapping_abs(i8::MIN), i8::MIN);
+        assert_eq!(IntElement::wrapping_neg(5u8), 251);
+        assert_eq!(IntElement::wrapping_abs(200u8), 200);
+        assert_eq!(IntElement::wrapping_mul(300i16, 300), 24464); // 90000 mod 2^16
+        assert_eq!(IntElement::wrapping_add(250u8, 10), 4);
+        assert_eq!(IntElement::div_or_saturate(i8::MIN, -1), i8::MIN);
+        assert_eq!(IntElement::div_or_saturate(7u16, 0), u16::MAX);
+        assert_eq!(IntElement::div_or_saturate(-7i32, 0), i32::MIN);
+    }
+
+    #[test]
+    fn test_extended_integer_math() {
+        assert_eq!(IntElement::relu(-3i16), 0);
+        assert_eq!(IntElement::signum(-7i8), -1);
+        assert_eq!(IntElement::signum(9u32), 1);
+        assert_eq!(IntElement::wrapping_pow(3i32, 4), 81);
+        assert_eq!(IntElement::wrapping_pow(2u8, 9), 0); // 512 mod 2^8
+        assert_eq!(IntElement::wrapping_pow(-1i64, -3), -1);
+        assert_eq!(IntElement::wrapping_pow(2i32, -1), 0);
+        assert_eq!(IntElement::floor_mod(-7i32, 3), 2);
+        assert_eq!(IntElement::floor_mod(7i32, -3), -2);
+        assert_eq!(IntElement::floor_mod(i8::MIN, -1), 0);
+        assert_eq!(IntElement::floor_mod(7u8, 0), 0);
+    }
+
+    #[test]
+    fn test_load_block_converts_and_gathers() {
+        let src = [-1i8, 2, -3, 4];
+        let mut dst = [0.0f64; 3];
+        unsafe { f64::load_block(WasmDType::Int8, src.as_ptr() as *const u8, &[3, 0, 0], &mut dst) };
+        assert_eq!(dst, [4.0, -1.0, -1.0]);
+
+        let src = [200u8, 1];
+        let mut dst = [0i16; 2];
+        unsafe { i16::load_block(WasmDType::Uint8, src.as_ptr(), &[0, 1], &mut dst) };
+        assert_eq!(dst, [200, 1]);
+    }
+}
@@ -0,0 +1,371 @@
+/*!
+ * Fused element-wise operations for WebAssembly backend
+ *
+ * Evaluates a small stack program of element-wise unary/binary operations
+ * (e.g. `neg(exp((a + b) * c))`) in a single pass over memory. The program
+ * is run block by block on small scratch buffers, so no intermediate
+ * tensors are allocated in the arena and each input is read exactly once.
+ */
+
+use wasm_bindgen::prelude::*;
+use crate::types::{WasmOperation, WasmDType, WasmPrecision, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::operations::{unary, binary};
+use crate::operations::unary::UnaryParams;
+use crate::operations::strided::{StridedIndexer, broadcast_strides, is_contiguous, output_byte_offset, storage_len};
+
+/// Number of elements evaluated per block (fits comfortably in L1)
+const FUSED_BLOCK_SIZE: usize = 256;
+
+/// Per-dtype element-wise kernels used to evaluate a fused program
+type BinaryKernel<T> = fn(WasmOperation, &[T], &[T], &mut [T]) -> WasmResult<()>;
+
+/// Single instruction of a fused element-wise program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FusedInstruction {
+    /// Push the current block of input tensor `n`
+    Load(usize),
+    /// Pop one value, apply element-wise unary operation, push result
+    /// (LeakyRelu uses the default `LEAKY_RELU_SLOPE`)
+    Unary(WasmOperation),
+    /// Pop `b` then `a`, apply element-wise binary operation `a op b`, push result
+    Binary(WasmOperation),
+}
+
+/// Stack program of element-wise operations evaluated by `execute_fused_op`
+///
+/// Built in postfix order from JavaScript, e.g. `(a + b) * c` then `exp`:
+/// `load(0) load(1) binary(Add) load(2) binary(Mul) unary(Exp)`
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WasmFusedProgram {
+    instructions: Vec<FusedInstruction>,
+    depth: usize,
+    max_depth: usize,
+    num_inputs: usize,
+}
+
+#[wasm_bindgen]
+impl WasmFusedProgram {
+    /// Create empty program
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmFusedProgram {
+        WasmFusedProgram::default()
+    }
+
+    /// Push input tensor `input_index` onto the stack
+    #[wasm_bindgen]
+    pub fn load(&mut self, input_index: usize) {
+        self.instructions.push(FusedInstruction::Load(input_index));
+        self.num_inputs = self.num_inputs.max(input_index + 1);
+        self.depth += 1;
+        self.max_depth = self.max_depth.max(self.depth);
+    }
+
+    /// Apply element-wise unary operation to top of stack
+    #[wasm_bindgen]
+    pub fn unary(&mut self, operation: WasmOperation) -> Result<(), JsValue> {
+        self.push_unary(operation).map_err(JsValue::from)
+    }
+
+    /// Apply element-wise binary operation to the two topmost stack values
+    #[wasm_bindgen]
+    pub fn binary(&mut self, operation: WasmOperation) -> Result<(), JsValue> {
+        self.push_binary(operation).map_err(JsValue::from)
+    }
+
+    /// Number of instructions in program
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.instructions.len()
+    }
+
+    /// Number of input tensors referenced by program
+    #[wasm_bindgen(getter)]
+    pub fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Check if program leaves exactly one value (the output) on the stack
+    #[wasm_bindgen(getter)]
+    pub fn is_complete(&self) -> bool {
+        self.depth == 1
+    }
+}
+
+impl WasmFusedProgram {
+    /// Append unary instruction (fails on non-element-wise ops or empty stack)
+    pub fn push_unary(&mut self, operation: WasmOperation) -> WasmResult<()> {
+        if !is_fusable_unary(operation) || self.depth < 1 {
+            return Err(WasmError::InvalidOperation);
+        }
+
+        self.instructions.push(FusedInstruction::Unary(operation));
+        Ok(())
+    }
+
+    /// Append binary instruction (fails on non-element-wise ops or fewer than two stack values)
+    pub fn push_binary(&mut self, operation: WasmOperation) -> WasmResult<()> {
+        if !is_fusable_binary(operation) || self.depth < 2 {
+            return Err(WasmError::InvalidOperation);
+        }
+
+        self.instructions.push(FusedInstruction::Binary(operation));
+        self.depth -= 1;
+        Ok(())
+    }
+
+    /// Program instructions in execution order
+    pub fn instructions(&self) -> &[FusedInstruction] {
+        &self.instructions
+    }
+}
+
+/// Check if operation is an element-wise unary operation supported in fused programs
+pub fn is_fusable_unary(operation: WasmOperation) -> bool {
+    matches!(operation,
+        WasmOperation::Neg | WasmOperation::Abs | WasmOperation::Sin | WasmOperation::Cos |
+        WasmOperation::Exp | WasmOperation::Log | WasmOperation::Sqrt | WasmOperation::Square |
+        WasmOperation::Tan | WasmOperation::Tanh | WasmOperation::Sigmoid | WasmOperation::Relu |
+        WasmOperation::LeakyRelu | WasmOperation::Gelu | WasmOperation::GeluTanh | WasmOperation::Silu |
+        WasmOperation::Erf | WasmOperation::Reciprocal | WasmOperation::Rsqrt | WasmOperation::Floor |
+        WasmOperation::Ceil | WasmOperation::Round | WasmOperation::Sign | WasmOperation::Log1p |
+        WasmOperation::Expm1
+    )
+}
+
+/// Check if operation is an element-wise binary operation supported in fused programs
+pub fn is_fusable_binary(operation: WasmOperation) -> bool {
+    matches!(operation,
+        WasmOperation::Add | WasmOperation::Sub | WasmOperation::Mul | WasmOperation::Div |
+        WasmOperation::Pow | WasmOperation::Maximum | WasmOperation::Minimum | WasmOperation::Mod
+    )
+}
+
+/// Execute fused element-wise program
+///
+/// All inputs must share the output dtype and broadcast to the output shape
+/// (e.g. a `[3]` row or a scalar against a `[2, 3]` output); strided views
+/// (transposed, sliced, expanded) are read through their strides.
+/// `precision` selects the f32 transcendental functions, as for unary ops.
+pub fn execute_fused_op(
+    program: &WasmFusedProgram,
+    inputs: &[WasmTensor],
+    output: &WasmTensor,
+    arena: &TempArena,
+    precision: WasmPrecision,
+) -> WasmResult<()> {
+    if !program.is_complete() || inputs.len() < program.num_inputs() {
+        return Err(WasmError::InvalidInput);
+    }
+
+    let output_meta = output.metadata();
+    let dtype = output_meta.dtype();
+    let size = output_meta.size();
+    let output_shape = output_meta.shape();
+
+    if inputs.iter().any(|input| input.metadata().dtype() != dtype) {
+        return Err(WasmError::InvalidDType);
+    }
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = unsafe { output.get_read_ptr(arena).add(output_byte_offset(output_meta)?) } as *mut u8;
+
+    match dtype {
+        WasmDType::Float32 => {
+            let mut inputs = fused_inputs::<f32>(inputs, &output_shape, arena)?;
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            let unary_kernel = |operation, input: &[f32], output: &mut [f32]| {
+                unary::execute_unary_f32(operation, input, output, precision.into())
+            };
+            execute_fused_typed(program, &mut inputs, output_slice, unary_kernel, binary::execute_binary_f32_fast)
+        }
+        WasmDType::Float64 => {
+            let mut inputs = fused_inputs::<f64>(inputs, &output_shape, arena)?;
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
+            let unary_kernel = |operation, input: &[f64], output: &mut [f64]| {
+                unary::execute_unary_f64(operation, input, output, UnaryParams::default())
+            };
+            execute_fused_typed(program, &mut inputs, output_slice, unary_kernel, binary::execute_binary_f64_fast)
+        }
+        WasmDType::Int32 => {
+            let mut inputs = fused_inputs::<i32>(inputs, &output_shape, arena)?;
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i32, size) };
+            execute_fused_typed(program, &mut inputs, output_slice, unary::execute_unary_i32, binary::execute_binary_i32_fast)
+        }
+        _ => Err(WasmError::NotImplemented),
+    }
+}
+
+/// Input tensor of a fused program, as read by `Load`
+struct FusedInput<'a, T> {
+    /// Elements from the first one (contiguous inputs) or the whole storage (strided views)
+    data: &'a [T],
+    /// Walks a strided view in row-major order; `None` if `data` is read linearly
+    indexer: Option<StridedIndexer>,
+    /// Offsets into `data` of the current block's elements (strided views only)
+    offsets: Vec<usize>,
+}
+
+impl<'a, T> FusedInput<'a, T> {
+    /// Input read linearly, or a single element broadcast to every position
+    fn contiguous(data: &'a [T]) -> Self {
+        FusedInput { data, indexer: None, offsets: Vec::new() }
+    }
+}
+
+/// Address each input tensor in the output's index order
+///
+/// Inputs with the output's shape (or a single element) are read linearly from
+/// their first element when contiguous; everything else walks its storage
+/// through strides broadcast to `output_shape`. Fails with `InvalidShape` for
+/// inputs that do not broadcast, such as `[3, 2]` against a `[2, 3]` output.
+fn fused_inputs<'a, T>(
+    inputs: &[WasmTensor],
+    output_shape: &[usize],
+    arena: &'a TempArena,
+) -> WasmResult<Vec<FusedInput<'a, T>>> {
+    inputs.iter().map(|tensor| {
+        let meta = tensor.metadata();
+        let shape = meta.shape();
+        let strides = broadcast_strides(&shape, &meta.strides(), output_shape)?;
+        let ptr = tensor.get_read_ptr(arena) as *const T;
+        let linear = meta.size() == 1 || (shape.as_slice() == output_shape && is_contiguous(meta));
+        if linear {
+            let data = unsafe { std::slice::from_raw_parts(ptr.add(meta.offset()), meta.size()) };
+            return Ok(FusedInput::contiguous(data));
+        }
+        let data = unsafe { std::slice::from_raw_parts(ptr, storage_len(meta)) };
+        Ok(FusedInput {
+            data,
+            indexer: Some(StridedIndexer::new(output_shape, &strides, meta.offset())),
+            offsets: vec![0; FUSED_BLOCK_SIZE],
+        })
+    }).collect()
+}
+
+/// Evaluate program block by block using the per-dtype element-wise kernels
+fn execute_fused_typed<T: Copy + Default>(
+    program: &WasmFusedProgram,
+    inputs: &mut [FusedInput<T>],
+    output: &mut [T],
+    unary_kernel: impl Fn(WasmOperation, &[T], &mut [T]) -> WasmResult<()>,
+    binary_kernel: BinaryKernel<T>,
+) -> WasmResult<()> {
+    // Stack of block registers plus one scratch register for results
+    let mut stack: Vec<Vec<T>> = vec![vec![T::default(); FUSED_BLOCK_SIZE]; program.max_depth];
+    let mut scratch: Vec<T> = vec![T::default(); FUSED_BLOCK_SIZE];
+
+    for (block_index, out_block) in output.chunks_mut(FUSED_BLOCK_SIZE).enumerate() {
+        let start = block_index * FUSED_BLOCK_SIZE;
+        let len = out_block.len();
+        let mut sp = 0;
+
+        // Strided inputs step once per block, however often the program loads them
+        for input in inputs.iter_mut() {
+            if let Some(indexer) = input.indexer.as_mut() {
+                indexer.fill(&mut input.offsets[..len]);
+            }
+        }
+
+        for instruction in program.instructions() {
+            match *instruction {
+                FusedInstruction::Load(index) => {
+                    let input = &inputs[index];
+                    let block = &mut stack[sp][..len];
+                    if input.indexer.is_some() {
+                        for (value, &offset) in block.iter_mut().zip(&input.offsets) {
+                            *value = input.data[offset];
+                        }
+                    } else if input.data.len() == 1 {
+                        block.fill(input.data[0]);
+                    } else {
+                        block.copy_from_slice(&input.data[start..start + len]);
+                    }
+                    sp += 1;
+                }
+                FusedInstruction::Unary(operation) => {
+                    unary_kernel(operation, &stack[sp - 1][..len], &mut scratch[..len])?;
+                    std::mem::swap(&mut stack[sp - 1], &mut scratch);
+                }
+                FusedInstruction::Binary(operation) => {
+                    let (lower, upper) = stack.split_at(sp - 1);
+                    binary_kernel(operation, &lower[sp - 2][..len], &upper[0][..len], &mut scratch[..len])?;
+                    std::mem::swap(&mut stack[sp - 2], &mut scratch);
+                    sp -= 1;
+                }
+            }
+        }
+
+        out_block.copy_from_slice(&stack[0][..len]);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program_f32(program: &WasmFusedProgram, inputs: &[&[f32]], size: usize) -> Vec<f32> {
+        let mut inputs: Vec<_> = inputs.iter().map(|&data| FusedInput::contiguous(data)).collect();
+        let mut output = vec![0.0f32; size];
+        let unary_kernel = |operation, input: &[f32], output: &mut [f32]| {
+            unary::execute_unary_f32(operation, input, output, WasmPrecision::Fast.into())
+        };
+        execute_fused_typed(program, &mut inputs, &mut output, unary_kernel, binary::execute_binary_f32_fast).unwrap();
+        output
+    }
+
+    #[test]
+    fn test_fused_add_mul_neg_f32() {
+        // neg((a + b) * c)
+        let mut program = WasmFusedProgram::new();
+        program.load(0);
+        program.load(1);
+        program.push_binary(WasmOperation::Add).unwrap();
+        program.load(2);
+        program.push_binary(WasmOperation::Mul).unwrap();
+        program.push_unary(WasmOperation::Neg).unwrap();
+        assert!(program.is_complete());
+        assert_eq!(program.num_inputs(), 3);
+
+        let a: Vec<f32> = (0..600).map(|i| i as f32).collect();
+        let b = vec![1.0f32; 600];
+        let c = [2.0f32];
+
+        let output = program_f32(&program, &[&a, &b, &c], 600);
+        for (i, &val) in output.iter().enumerate() {
+            assert_eq!(val, -((i as f32 + 1.0) * 2.0));
+        }
+    }
+
+    #[test]
+    fn test_fused_operand_order_f32() {
+        // b - a, not a - b
+        let mut program = WasmFusedProgram::new();
+        program.load(1);
+        program.load(0);
+        program.push_binary(WasmOperation::Sub).unwrap();
+
+        let a = [1.0f32, 2.0, 3.0];
+        let b = [10.0f32, 20.0, 30.0];
+
+        assert_eq!(program_f32(&program, &[&a, &b], 3), vec![9.0, 18.0, 27.0]);
+    }
+
+    #[test]
+    fn test_fused_program_validation() {
+        let mut program = WasmFusedProgram::new();
+        program.load(0);
+        assert!(program.push_binary(WasmOperation::Add).is_err());
+        assert!(program.push_unary(WasmOperation::Matmul).is_err());
+        program.load(0);
+        assert!(!program.is_complete());
+        program.push_binary(WasmOperation::Mul).unwrap();
+        assert!(program.is_complete());
+        assert_eq!(program.length(), 3);
+    }
+}
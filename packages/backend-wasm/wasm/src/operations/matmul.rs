@@ -5,13 +5,20 @@
  * - Different input dimensions (1D×1D, 1D×2D, 2D×1D, 2D×2D, ND×ND)
  * - Batched operations
  * - SIMD optimizations where available
+ * - Int8 inputs with i32 accumulation (see `quantized`)
  */
 
 use crate::types::{WasmOperation, WasmTensorMeta, WasmDType, WasmResult, WasmError};
 use crate::memory::WasmTensor;
 use crate::arena::TempArena;
+use crate::half::{self, HalfFloat, F16, BF16};
+use crate::operations::quantized;
+use crate::operations::strided::{StridedIndexer, broadcast_strides, storage_len};
 use microgemm::{MatRef, MatMut, PackSizes, Kernel, kernels::GenericKernel8x8};
 
+/// Inner-dimension panel widened to f32 at a time for half-precision GEMM
+const HALF_GEMM_K_PANEL: usize = 256;
+
 /// Execute matrix multiplication operation
 pub fn execute_matmul_op(
     _operation: crate::types::WasmOperation,
@@ -20,17 +27,20 @@ pub fn execute_matmul_op(
     output: &WasmTensor,
     arena: &TempArena,
 ) -> WasmResult<()> {
-    let input_a_ptr = input_a.get_read_ptr(arena);
-    let input_b_ptr = input_b.get_read_ptr(arena);
-    
+    let input_meta_a = input_a.metadata();
+    let input_meta_b = input_b.metadata();
+    let output_meta = output.metadata();
+
+    // Views are addressed from their first element and read through their strides
+    let input_a_ptr = unsafe { input_a.get_read_ptr(arena).add(input_meta_a.offset() * input_meta_a.dtype().byte_size()) };
+    let input_b_ptr = unsafe { input_b.get_read_ptr(arena).add(input_meta_b.offset() * input_meta_b.dtype().byte_size()) };
+    let len_a = storage_len(input_meta_a) - input_meta_a.offset();
+    let len_b = storage_len(input_meta_b) - input_meta_b.offset();
+
     // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
     // Each tensor has unique memory from bump allocator, no aliasing occurs
     let output_ptr = output.get_read_ptr(arena) as *mut u8;
 
-    let input_meta_a = input_a.metadata();
-    let input_meta_b = input_b.metadata();
-    let output_meta = output.metadata();
-    
     let shape_a = input_meta_a.shape();
     let shape_b = input_meta_b.shape();
     let shape_out = output_meta.shape();
@@ -41,10 +51,10 @@ pub fn execute_matmul_op(
     match input_meta_a.dtype() {
         WasmDType::Float32 => {
             let a_slice = unsafe { 
-                std::slice::from_raw_parts(input_a_ptr as *const f32, input_meta_a.size()) 
+                std::slice::from_raw_parts(input_a_ptr as *const f32, len_a) 
             };
             let b_slice = unsafe { 
-                std::slice::from_raw_parts(input_b_ptr as *const f32, input_meta_b.size()) 
+                std::slice::from_raw_parts(input_b_ptr as *const f32, len_b) 
             };
             let out_slice = unsafe { 
                 std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_meta.size()) 
@@ -59,10 +69,10 @@ pub fn execute_matmul_op(
         }
         WasmDType::Float64 => {
             let a_slice = unsafe { 
-                std::slice::from_raw_parts(input_a_ptr as *const f64, input_meta_a.size()) 
+                std::slice::from_raw_parts(input_a_ptr as *const f64, len_a) 
             };
             let b_slice = unsafe { 
-                std::slice::from_raw_parts(input_b_ptr as *const f64, input_meta_b.size()) 
+                std::slice::from_raw_parts(input_b_ptr as *const f64, len_b) 
             };
             let out_slice = unsafe { 
                 std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_meta.size()) 
@@ -75,12 +85,130 @@ pub fn execute_matmul_op(
                 rank_a, rank_b,
             )?;
         }
+        WasmDType::Float16 | WasmDType::BFloat16 => {
+            let dtype = input_meta_a.dtype();
+            if input_meta_b.dtype() != dtype || output_meta.dtype() != dtype {
+                return Err(WasmError::InvalidDType);
+            }
+
+            if dtype == WasmDType::Float16 {
+                let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const F16, len_a) };
+                let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const F16, len_b) };
+                let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut F16, output_meta.size()) };
+
+                execute_matmul_half(a_slice, b_slice, out_slice, input_meta_a, input_meta_b, output_meta)?;
+            } else {
+                let a_slice = unsafe { std::slice::from_raw_parts(input_a_ptr as *const BF16, len_a) };
+                let b_slice = unsafe { std::slice::from_raw_parts(input_b_ptr as *const BF16, len_b) };
+                let out_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut BF16, output_meta.size()) };
+
+                execute_matmul_half(a_slice, b_slice, out_slice, input_meta_a, input_meta_b, output_meta)?;
+            }
+        }
+        WasmDType::Int8 => {
+            quantized::execute_matmul_i8_op(input_a, input_b, output, arena)?;
+        }
         _ => return Err(WasmError::NotImplemented),
     }
 
     Ok(())
 }
 
+/// Matrix multiplication for half-precision arrays (f32 accumulation)
+/// 
+/// 2D × 2D products widen one K-panel of each input at a time, so the f32
+/// scratch stays small even for large half-precision weight matrices. Other
+/// ranks widen the whole inputs and reuse the f32 kernels.
+fn execute_matmul_half<H: HalfFloat>(
+    a: &[H], b: &[H], out: &mut [H],
+    input_meta_a: &WasmTensorMeta, input_meta_b: &WasmTensorMeta, output_meta: &WasmTensorMeta,
+) -> WasmResult<()> {
+    let shape_a = input_meta_a.shape();
+    let shape_b = input_meta_b.shape();
+    let strides_a = input_meta_a.strides();
+    let strides_b = input_meta_b.strides();
+
+    if shape_a.len() == 2 && shape_b.len() == 2 {
+        execute_gemm_half(a, b, out, &shape_a, &shape_b, &strides_a, &strides_b);
+        return Ok(());
+    }
+
+    let mut a_f32 = vec![0.0f32; a.len()];
+    let mut b_f32 = vec![0.0f32; b.len()];
+    let mut out_f32 = vec![0.0f32; out.len()];
+    half::widen(a, &mut a_f32);
+    half::widen(b, &mut b_f32);
+
+    execute_matmul_f32(
+        &a_f32, &b_f32, &mut out_f32,
+        &shape_a, &shape_b, &output_meta.shape(),
+        &strides_a, &strides_b,
+        shape_a.len(), shape_b.len(),
+    )?;
+
+    half::narrow(&out_f32, out);
+    Ok(())
+}
+
+/// Half-precision GEMM: C = A * B accumulated in f32 over K-panels
+fn execute_gemm_half<H: HalfFloat>(
+    a: &[H], b: &[H], c: &mut [H],
+    shape_a: &[usize], shape_b: &[usize],
+    strides_a: &[usize], strides_b: &[usize],
+) {
+    let m = shape_a[0];
+    let k = shape_a[1];
+    let n = shape_b[1];
+    let kernel = GenericKernel8x8::<f32>::new();
+    let panel = HALF_GEMM_K_PANEL.min(k);
+
+    let mut accumulator = vec![0.0f32; m * n];
+    let mut partial = vec![0.0f32; if k > panel { m * n } else { 0 }];
+    let mut a_panel = vec![0.0f32; m * panel];
+    let mut b_panel = vec![0.0f32; panel * n];
+
+    for k_start in (0..k).step_by(HALF_GEMM_K_PANEL) {
+        let kb = panel.min(k - k_start);
+
+        // A[:, k_start..k_start + kb] as a contiguous m × kb block
+        for i in 0..m {
+            let row = &mut a_panel[i * kb..(i + 1) * kb];
+            let base = i * strides_a[0] + k_start * strides_a[1];
+            if strides_a[1] == 1 {
+                half::widen(&a[base..base + kb], row);
+            } else {
+                for (p, val) in row.iter_mut().enumerate() {
+                    *val = a[base + p * strides_a[1]].to_f32();
+                }
+            }
+        }
+
+        // B[k_start..k_start + kb, :] as a contiguous kb × n block
+        for p in 0..kb {
+            let row = &mut b_panel[p * n..(p + 1) * n];
+            let base = (k_start + p) * strides_b[0];
+            if strides_b[1] == 1 {
+                half::widen(&b[base..base + n], row);
+            } else {
+                for (j, val) in row.iter_mut().enumerate() {
+                    *val = b[base + j * strides_b[1]].to_f32();
+                }
+            }
+        }
+
+        if k_start == 0 {
+            execute_gemm_contiguous_f32(&kernel, &a_panel[..m * kb], &b_panel[..kb * n], &mut accumulator, m, kb, n);
+        } else {
+            execute_gemm_contiguous_f32(&kernel, &a_panel[..m * kb], &b_panel[..kb * n], &mut partial, m, kb, n);
+            for (acc, &val) in accumulator.iter_mut().zip(&partial) {
+                *acc += val;
+            }
+        }
+    }
+
+    half::narrow(&accumulator, c);
+}
+
 /// Matrix multiplication for f32 arrays
 fn execute_matmul_f32(
     a: &[f32], b: &[f32], out: &mut [f32],
@@ -94,7 +222,7 @@ fn execute_matmul_f32(
             let n = shape_a[0];
             let mut sum = 0.0f32;
             for i in 0..n {
-                sum += a[i] * b[i];
+                sum += a[i * strides_a[0]] * b[i * strides_b[0]];
             }
             out[0] = sum;
         }
@@ -159,7 +287,7 @@ fn execute_matmul_f64(
             let n = shape_a[0];
             let mut sum = 0.0f64;
             for i in 0..n {
-                sum += a[i] * b[i];
+                sum += a[i * strides_a[0]] * b[i * strides_b[0]];
             }
             out[0] = sum;
         }
@@ -381,6 +509,26 @@ fn execute_gemm_naive_f64(
     }
 }
 
+/// Walkers over the matrix offsets of A and B for each output batch, plus the batch count
+///
+/// Batch dimensions broadcast from the right like element-wise ops, so
+/// `[2, 3, m, k] x [3, k, n]` reuses each B matrix for both outer batches.
+fn batch_indexers(
+    shape_a: &[usize], shape_b: &[usize], shape_out: &[usize],
+    strides_a: &[usize], strides_b: &[usize],
+) -> WasmResult<(StridedIndexer, StridedIndexer, usize)> {
+    let batch_shape = &shape_out[..shape_out.len() - 2];
+    let batch_a = shape_a.len() - 2;
+    let batch_b = shape_b.len() - 2;
+    let a_strides = broadcast_strides(&shape_a[..batch_a], &strides_a[..batch_a], batch_shape)?;
+    let b_strides = broadcast_strides(&shape_b[..batch_b], &strides_b[..batch_b], batch_shape)?;
+    Ok((
+        StridedIndexer::new(batch_shape, &a_strides, 0),
+        StridedIndexer::new(batch_shape, &b_strides, 0),
+        batch_shape.iter().product(),
+    ))
+}
+
 /// Batched matrix multiplication for f32
 fn execute_batched_matmul_f32(
     a: &[f32], b: &[f32], out: &mut [f32],
@@ -389,46 +537,20 @@ fn execute_batched_matmul_f32(
 ) -> WasmResult<()> {
     let rank_a = shape_a.len();
     let rank_b = shape_b.len();
-    let rank_out = shape_out.len();
 
     // Extract matrix dimensions
     let m = shape_a[rank_a - 2];
     let k = shape_a[rank_a - 1];
     let n = shape_b[rank_b - 1];
 
-    // Compute batch size
-    let mut batch_size = 1;
-    for i in 0..(rank_out - 2) {
-        batch_size *= shape_out[i];
-    }
+    let (mut a_batches, mut b_batches, batch_size) =
+        batch_indexers(shape_a, shape_b, shape_out, strides_a, strides_b)?;
 
-    // For batched matmul, calculate the batch strides properly
     for batch in 0..batch_size {
-        // Compute batch indices
-        let mut batch_indices = vec![0; rank_out - 2];
-        let mut temp = batch;
-        for i in (0..(rank_out - 2)).rev() {
-            batch_indices[i] = temp % shape_out[i];
-            temp /= shape_out[i];
-        }
-        
-        // Compute batch offsets
-        let mut offset_a = 0;
-        let mut offset_b = 0;
-        
-        for i in 0..batch_indices.len() {
-            let batch_idx = batch_indices[i];
-
-            // Map to input A
-            if i < rank_a - 2 && shape_a[i] > 1 {
-                offset_a += batch_idx * strides_a[i];
-            }
-
-            // Map to input B
-            if i < rank_b - 2 && shape_b[i] > 1 {
-                offset_b += batch_idx * strides_b[i];
-            }
-        }
+        let offset_a = a_batches.offset();
+        let offset_b = b_batches.offset();
+        a_batches.advance();
+        b_batches.advance();
 
         // Perform matrix multiplication for this batch
         let base_out_idx = batch * m * n;
@@ -462,43 +584,19 @@ fn execute_batched_matmul_f64(
     // Similar implementation to f32 version
     let rank_a = shape_a.len();
     let rank_b = shape_b.len();
-    let rank_out = shape_out.len();
 
     let m = shape_a[rank_a - 2];
     let k = shape_a[rank_a - 1];
     let n = shape_b[rank_b - 1];
 
-    let mut batch_size = 1;
-    for i in 0..(rank_out - 2) {
-        batch_size *= shape_out[i];
-    }
+    let (mut a_batches, mut b_batches, batch_size) =
+        batch_indexers(shape_a, shape_b, shape_out, strides_a, strides_b)?;
 
     for batch in 0..batch_size {
-        // Compute batch indices
-        let mut batch_indices = vec![0; rank_out - 2];
-        let mut temp = batch;
-        for i in (0..(rank_out - 2)).rev() {
-            batch_indices[i] = temp % shape_out[i];
-            temp /= shape_out[i];
-        }
-        
-        // Compute batch offsets
-        let mut offset_a = 0;
-        let mut offset_b = 0;
-        
-        for i in 0..batch_indices.len() {
-            let batch_idx = batch_indices[i];
-
-            // Map to input A
-            if i < rank_a - 2 && shape_a[i] > 1 {
-                offset_a += batch_idx * strides_a[i];
-            }
-
-            // Map to input B
-            if i < rank_b - 2 && shape_b[i] > 1 {
-                offset_b += batch_idx * strides_b[i];
-            }
-        }
+        let offset_a = a_batches.offset();
+        let offset_b = b_batches.offset();
+        a_batches.advance();
+        b_batches.advance();
 
         let base_out_idx = batch * m * n;
         let stride_a_row = strides_a[rank_a - 2];
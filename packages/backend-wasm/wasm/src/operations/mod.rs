@@ -13,10 +13,22 @@
 
 pub mod unary;
 pub mod binary;
+pub mod comparison;
 pub mod matmul;
 pub mod view;
 pub mod reduction;
 pub mod softmax;
+pub mod fused;
+pub mod element;
+pub mod strided;
+pub mod cast;
+pub mod quantized;
+pub mod q4;
+pub mod permute;
+pub mod slice;
+pub mod select;
+pub mod einops;
+pub mod scan;
 
 use wasm_bindgen::prelude::*;
 use crate::types::{WasmOperation, WasmTensorMeta, WasmResult, WasmError};
@@ -0,0 +1,327 @@
+/*!
+ * N-dimensional permute for WebAssembly backend
+ *
+ * Copies a tensor into a contiguous output whose dimension `i` is input
+ * dimension `axes[i]`. Permutes only move bytes, so every dtype is handled by
+ * element width (1, 2, 4 or 8 bytes). With the identity permutation this is
+ * also how views are materialized (`execute_contiguous_copy_op`).
+ *
+ * Dimensions of size 1 are dropped and output dimensions that stay adjacent
+ * in the input are merged first, so e.g. `[B, H, S, D] -> [B, S, H, D]` becomes
+ * row copies of length `D`, and NCHW <-> NHWC becomes a batch of 2D transposes.
+ * Kernels by innermost layout:
+ * - Innermost output dimension contiguous in the input: row copies
+ * - Another output dimension contiguous in the input: cache-blocked 2D
+ *   transposes between that dimension and the innermost one
+ * - Otherwise (strided views): element-by-element gather
+ */
+
+use crate::types::{WasmTensorMeta, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::operations::strided::{StridedIndexer, contiguous_strides, storage_len};
+
+/// Tile edge for blocked transposes (32 × 32 tiles of 4-byte elements fit in L1)
+const TRANSPOSE_BLOCK: usize = 32;
+
+/// Permuted iteration space after dropping and merging dimensions
+#[derive(Debug, Clone, PartialEq)]
+struct PermuteLayout {
+    shape: Vec<usize>,
+    /// Input strides per output dimension
+    src_strides: Vec<usize>,
+    /// Output strides (contiguous)
+    dst_strides: Vec<usize>,
+}
+
+impl PermuteLayout {
+    fn new(shape: &[usize], strides: &[usize], axes: &[usize]) -> WasmResult<Self> {
+        validate_axes(axes, shape.len())?;
+        if strides.len() != shape.len() {
+            return Err(WasmError::InvalidShape);
+        }
+
+        // Output dimension i reads input dimension axes[i]; size-1 dims never move data
+        let mut dims: Vec<(usize, usize)> = axes.iter()
+            .map(|&axis| (shape[axis], strides[axis]))
+            .filter(|&(dim, _)| dim != 1)
+            .collect();
+
+        // Merge output neighbours that are also neighbours in the input
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(dims.len());
+        for (dim, stride) in dims.drain(..) {
+            match merged.last_mut() {
+                Some(last) if last.1 == stride * dim => *last = (last.0 * dim, stride),
+                _ => merged.push((dim, stride)),
+            }
+        }
+
+        let shape: Vec<usize> = merged.iter().map(|&(dim, _)| dim).collect();
+        let src_strides = merged.iter().map(|&(_, stride)| stride).collect();
+        let dst_strides = contiguous_strides(&shape);
+        Ok(PermuteLayout { shape, src_strides, dst_strides })
+    }
+}
+
+/// Check that `axes` is a permutation of `0..rank`
+fn validate_axes(axes: &[usize], rank: usize) -> WasmResult<()> {
+    if axes.len() != rank {
+        return Err(WasmError::InvalidShape);
+    }
+    let mut seen = vec![false; rank];
+    for &axis in axes {
+        if axis >= rank || seen[axis] {
+            return Err(WasmError::InvalidInput);
+        }
+        seen[axis] = true;
+    }
+    Ok(())
+}
+
+/// Permutation that swaps the last two axes (identity below rank 2)
+pub fn swap_last_two_axes(rank: usize) -> Vec<usize> {
+    let mut axes: Vec<usize> = (0..rank).collect();
+    if rank >= 2 {
+        axes.swap(rank - 2, rank - 1);
+    }
+    axes
+}
+
+/// Execute permute: output dimension `i` is input dimension `axes[i]`
+pub fn execute_permute_op(
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    axes: &[usize],
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    if input_meta.dtype() != output_meta.dtype() {
+        return Err(WasmError::InvalidDType);
+    }
+
+    let input_shape = input_meta.shape();
+    validate_axes(axes, input_shape.len())?;
+    let permuted_shape: Vec<usize> = axes.iter().map(|&axis| input_shape[axis]).collect();
+    if output_meta.shape() != permuted_shape {
+        return Err(WasmError::InvalidShape);
+    }
+
+    let layout = PermuteLayout::new(&input_shape, &input_meta.strides(), axes)?;
+    let input_ptr = input.get_read_ptr(arena);
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = output.get_read_ptr(arena) as *mut u8;
+
+    match input_meta.dtype().byte_size() {
+        1 => permute_typed::<u8>(input_ptr, output_ptr, input_meta, output_meta, &layout),
+        2 => permute_typed::<u16>(input_ptr, output_ptr, input_meta, output_meta, &layout),
+        4 => permute_typed::<u32>(input_ptr, output_ptr, input_meta, output_meta, &layout),
+        8 => permute_typed::<u64>(input_ptr, output_ptr, input_meta, output_meta, &layout),
+        _ => return Err(WasmError::InvalidDType),
+    }
+    Ok(())
+}
+
+/// Copy `input` in logical row-major order into a contiguous `output` of the same size
+///
+/// Materializes views (strided, offset or expanded); `output` may have any
+/// shape with the same element count, which also makes this the copying
+/// fallback for reshapes that cannot be expressed as a view.
+pub fn execute_contiguous_copy_op(
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    if input_meta.dtype() != output_meta.dtype() {
+        return Err(WasmError::InvalidDType);
+    }
+    if input_meta.size() != output_meta.size() {
+        return Err(WasmError::InvalidShape);
+    }
+
+    let input_shape = input_meta.shape();
+    let identity: Vec<usize> = (0..input_shape.len()).collect();
+    let layout = PermuteLayout::new(&input_shape, &input_meta.strides(), &identity)?;
+    let input_ptr = input.get_read_ptr(arena);
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = output.get_read_ptr(arena) as *mut u8;
+
+    match input_meta.dtype().byte_size() {
+        1 => permute_typed::<u8>(input_ptr, output_ptr, input_meta, output_meta, &layout),
+        2 => permute_typed::<u16>(input_ptr, output_ptr, input_meta, output_meta, &layout),
+        4 => permute_typed::<u32>(input_ptr, output_ptr, input_meta, output_meta, &layout),
+        8 => permute_typed::<u64>(input_ptr, output_ptr, input_meta, output_meta, &layout),
+        _ => return Err(WasmError::InvalidDType),
+    }
+    Ok(())
+}
+
+fn permute_typed<T: Copy>(
+    input_ptr: *const u8,
+    output_ptr: *mut u8,
+    input_meta: &WasmTensorMeta,
+    output_meta: &WasmTensorMeta,
+    layout: &PermuteLayout,
+) {
+    // Input is addressed from the start of its storage (views carry an offset)
+    let input = unsafe { std::slice::from_raw_parts(input_ptr as *const T, storage_len(input_meta)) };
+    let output = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut T, output_meta.size()) };
+    permute_elements(input, output, input_meta.offset(), layout);
+}
+
+/// Copy `input` (from element `offset`) into `output` following `layout`
+fn permute_elements<T: Copy>(input: &[T], output: &mut [T], offset: usize, layout: &PermuteLayout) {
+    let rank = layout.shape.len();
+    if output.is_empty() {
+        return;
+    }
+    if rank == 0 {
+        output[0] = input[offset];
+        return;
+    }
+
+    let inner = rank - 1;
+    let inner_len = layout.shape[inner];
+
+    if layout.src_strides[inner] == 1 {
+        // Row copies: the innermost output dimension is contiguous in the input
+        let mut outer = StridedIndexer::new(&layout.shape[..inner], &layout.src_strides[..inner], offset);
+        for row in output.chunks_exact_mut(inner_len) {
+            let start = outer.offset();
+            row.copy_from_slice(&input[start..start + inner_len]);
+            outer.advance();
+        }
+        return;
+    }
+
+    if let Some(q) = layout.src_strides[..inner].iter().position(|&stride| stride == 1) {
+        // Blocked 2D transposes between output dims q and inner
+        let mut outer_shape = layout.shape.clone();
+        outer_shape[q] = 1;
+        outer_shape[inner] = 1;
+        let batches: usize = outer_shape.iter().product();
+        let mut src_outer = StridedIndexer::new(&outer_shape, &layout.src_strides, offset);
+        let mut dst_outer = StridedIndexer::new(&outer_shape, &layout.dst_strides, 0);
+
+        let rows = layout.shape[q];
+        let src_col_stride = layout.src_strides[inner];
+        let dst_row_stride = layout.dst_strides[q];
+
+        for _ in 0..batches {
+            let src_base = src_outer.offset();
+            let dst_base = dst_outer.offset();
+            for row_block in (0..rows).step_by(TRANSPOSE_BLOCK) {
+                let row_end = (row_block + TRANSPOSE_BLOCK).min(rows);
+                for col_block in (0..inner_len).step_by(TRANSPOSE_BLOCK) {
+                    let col_end = (col_block + TRANSPOSE_BLOCK).min(inner_len);
+                    for col in col_block..col_end {
+                        // Contiguous run along q in the input
+                        let src = src_base + col * src_col_stride;
+                        for row in row_block..row_end {
+                            output[dst_base + row * dst_row_stride + col] = input[src + row];
+                        }
+                    }
+                }
+            }
+            src_outer.advance();
+            dst_outer.advance();
+        }
+        return;
+    }
+
+    // Strided input view: gather element by element
+    let mut indexer = StridedIndexer::new(&layout.shape, &layout.src_strides, offset);
+    for out in output.iter_mut() {
+        *out = input[indexer.offset()];
+        indexer.advance();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reference permute of a contiguous tensor
+    fn reference(input: &[u32], shape: &[usize], axes: &[usize]) -> Vec<u32> {
+        let strides = contiguous_strides(shape);
+        let out_shape: Vec<usize> = axes.iter().map(|&a| shape[a]).collect();
+        let out_strides: Vec<usize> = axes.iter().map(|&a| strides[a]).collect();
+        let mut indexer = StridedIndexer::new(&out_shape, &out_strides, 0);
+        (0..input.len()).map(|_| {
+            let value = input[indexer.offset()];
+            indexer.advance();
+            value
+        }).collect()
+    }
+
+    fn permute(input: &[u32], shape: &[usize], strides: &[usize], axes: &[usize]) -> Vec<u32> {
+        let layout = PermuteLayout::new(shape, strides, axes).unwrap();
+        let mut output = vec![0u32; shape.iter().product()];
+        permute_elements(input, &mut output, 0, &layout);
+        output
+    }
+
+    #[test]
+    fn test_transpose_2d() {
+        let input = [1u32, 2, 3, 4, 5, 6]; // [[1, 2, 3], [4, 5, 6]]
+        assert_eq!(permute(&input, &[2, 3], &[3, 1], &[1, 0]), vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn test_layout_merging() {
+        // [B, H, S, D] -> [B, S, H, D]: row copies of D
+        let layout = PermuteLayout::new(&[2, 3, 4, 5], &[60, 20, 5, 1], &[0, 2, 1, 3]).unwrap();
+        assert_eq!(layout.shape, vec![2, 4, 3, 5]);
+        assert_eq!(layout.src_strides, vec![60, 5, 20, 1]);
+
+        // NCHW -> NHWC: H and W merge into one transposed dimension
+        let layout = PermuteLayout::new(&[2, 3, 4, 5], &[60, 20, 5, 1], &[0, 2, 3, 1]).unwrap();
+        assert_eq!(layout.shape, vec![2, 20, 3]);
+        assert_eq!(layout.src_strides, vec![60, 1, 20]);
+
+        // Identity collapses to a single copy
+        let layout = PermuteLayout::new(&[2, 1, 3], &[3, 3, 1], &[0, 1, 2]).unwrap();
+        assert_eq!(layout.shape, vec![6]);
+    }
+
+    #[test]
+    fn test_permutations_match_reference() {
+        // 37 × 45 crosses several transpose blocks with ragged edges
+        let cases: [(&[usize], &[usize]); 6] = [
+            (&[37, 45], &[1, 0]),
+            (&[2, 3, 4, 5], &[0, 2, 1, 3]),
+            (&[2, 3, 4, 5], &[0, 2, 3, 1]),
+            (&[2, 4, 5, 3], &[0, 3, 1, 2]),
+            (&[2, 3, 4, 5], &[3, 1, 0, 2]),
+            (&[3, 1, 4], &[2, 1, 0]),
+        ];
+        for (shape, axes) in cases {
+            let size: usize = shape.iter().product();
+            let input: Vec<u32> = (0..size as u32).collect();
+            let strides = contiguous_strides(shape);
+            assert_eq!(permute(&input, shape, &strides, axes), reference(&input, shape, axes), "{:?} {:?}", shape, axes);
+        }
+    }
+
+    #[test]
+    fn test_strided_input() {
+        // Every other column of a [2, 6] buffer, viewed as [2, 3], then transposed
+        let input: Vec<u32> = (0..12).collect();
+        assert_eq!(permute(&input, &[2, 3], &[6, 2], &[1, 0]), vec![0, 6, 2, 8, 4, 10]);
+    }
+
+    #[test]
+    fn test_invalid_axes() {
+        assert!(matches!(validate_axes(&[0, 0], 2), Err(WasmError::InvalidInput)));
+        assert!(matches!(validate_axes(&[0, 2], 2), Err(WasmError::InvalidInput)));
+        assert!(matches!(validate_axes(&[0], 2), Err(WasmError::InvalidShape)));
+        assert_eq!(swap_last_two_axes(4), vec![0, 1, 3, 2]);
+        assert_eq!(swap_last_two_axes(1), vec![0]);
+    }
+}
@@ -0,0 +1,272 @@
+/*!
+ * 4-bit block-quantized (Q4) weights for WebAssembly backend
+ *
+ * Q4 weights are stored in `Uint8` tensors of shape `[rows, q4_row_bytes(cols)]`.
+ * Each row is split into blocks of 32 values; a block is 18 bytes:
+ * - an f16 scale (little-endian)
+ * - 16 bytes of packed 4-bit codes, value `2i` in the low nibble of byte `i`
+ *   and value `2i + 1` in the high nibble
+ *
+ * A code `q` decodes to `scale * (q - 8)`. A partial last block is padded
+ * with zeros. Inputs whose scale would overflow f16 (magnitudes above about
+ * 458k) are rejected. At 4.5 bits per value, Q4 weights are ~7x smaller than f32,
+ * which lets layers that exceed the arena's allocation limit as f32 fit.
+ *
+ * `execute_q4_matmul_op` computes `output = input × weightsᵀ` (linear-layer
+ * convention: weights are `[out_features, in_features]`), dequantizing one
+ * weight row at a time so no f32 copy of the weights is ever materialized.
+ */
+
+use wasm_bindgen::prelude::*;
+use crate::types::{WasmDType, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::half::{HalfFloat, F16};
+use crate::simd::float32;
+use crate::operations::element::PromotedElement;
+use crate::operations::strided::{StridedIndexer, is_contiguous};
+
+/// Values per quantization block
+pub const Q4_BLOCK_SIZE: usize = 32;
+
+/// Bytes per quantization block: f16 scale + 16 bytes of codes
+pub const Q4_BLOCK_BYTES: usize = 2 + Q4_BLOCK_SIZE / 2;
+
+/// Bytes needed to store one Q4 row of `cols` values
+#[wasm_bindgen]
+pub fn q4_row_bytes(cols: usize) -> usize {
+    cols.div_ceil(Q4_BLOCK_SIZE) * Q4_BLOCK_BYTES
+}
+
+/// f16 scale mapping the largest magnitude in `values` to code 7
+///
+/// Fails with `InvalidInput` if the scale overflows f16, i.e. for magnitudes
+/// above about `7 * 65504 = 458528` (or infinities).
+fn block_scale(values: &[f32]) -> WasmResult<F16> {
+    let max_abs = values.iter().fold(0.0f32, |acc, &v| acc.max(v.abs()));
+    let scale = F16::from_f32(max_abs / 7.0);
+    if scale.to_f32().is_finite() {
+        Ok(scale)
+    } else {
+        Err(WasmError::InvalidInput)
+    }
+}
+
+/// Quantize one block of up to 32 values
+fn quantize_block(values: &[f32], block: &mut [u8]) -> WasmResult<()> {
+    // Codes span -8..=7; map the largest magnitude to 7 so positive values never clip
+    let scale = block_scale(values)?;
+    let stored_scale = scale.to_f32();
+    let inverse = if stored_scale > 0.0 { 1.0 / stored_scale } else { 0.0 };
+    block[..2].copy_from_slice(&scale.0.to_le_bytes());
+
+    let code = |i: usize| -> u8 {
+        let value = values.get(i).copied().unwrap_or(0.0);
+        ((value * inverse).round().clamp(-8.0, 7.0) as i32 + 8) as u8
+    };
+    for (i, byte) in block[2..].iter_mut().enumerate() {
+        *byte = code(2 * i) | (code(2 * i + 1) << 4);
+    }
+    Ok(())
+}
+
+/// Decode one block into `output` (up to 32 values)
+#[inline]
+fn dequantize_block(block: &[u8], output: &mut [f32]) {
+    let scale = F16(u16::from_le_bytes([block[0], block[1]])).to_f32();
+    let mut decoded = [0.0f32; Q4_BLOCK_SIZE];
+    for (pair, &byte) in decoded.chunks_exact_mut(2).zip(&block[2..]) {
+        pair[0] = scale * ((byte & 0x0F) as i32 - 8) as f32;
+        pair[1] = scale * ((byte >> 4) as i32 - 8) as f32;
+    }
+    let len = output.len();
+    output.copy_from_slice(&decoded[..len]);
+}
+
+/// Decode a full Q4 row of `output.len()` values
+fn dequantize_row(row: &[u8], output: &mut [f32]) {
+    for (block, values) in row.chunks_exact(Q4_BLOCK_BYTES).zip(output.chunks_mut(Q4_BLOCK_SIZE)) {
+        dequantize_block(block, values);
+    }
+}
+
+/// Read a Float32 tensor as a contiguous row-major buffer, gathering views
+fn read_f32(tensor: &WasmTensor, arena: &TempArena) -> Vec<f32> {
+    let meta = tensor.metadata();
+    let ptr = tensor.get_read_ptr(arena);
+    let mut values = vec![0.0f32; meta.size()];
+
+    if is_contiguous(meta) {
+        let input = unsafe { std::slice::from_raw_parts((ptr as *const f32).add(meta.offset()), meta.size()) };
+        values.copy_from_slice(input);
+    } else {
+        let mut indexer = StridedIndexer::new(&meta.shape(), &meta.strides(), meta.offset());
+        let mut offsets = vec![0usize; meta.size()];
+        indexer.fill(&mut offsets);
+        unsafe { f32::load_block(WasmDType::Float32, ptr, &offsets, &mut values) };
+    }
+    values
+}
+
+/// Validate a Q4 weight tensor holding `cols` values per row, returning its row count
+fn q4_rows(weights: &WasmTensor, cols: usize) -> WasmResult<usize> {
+    let meta = weights.metadata();
+    let shape = meta.shape();
+    if meta.dtype() != WasmDType::Uint8 {
+        return Err(WasmError::InvalidDType);
+    }
+    if shape.len() != 2 || shape[1] != q4_row_bytes(cols) || !is_contiguous(meta) {
+        return Err(WasmError::InvalidShape);
+    }
+    Ok(shape[0])
+}
+
+/// Quantize a 2D Float32 tensor `[rows, cols]` into Q4 weights `[rows, q4_row_bytes(cols)]`
+pub fn execute_quantize_q4_op(
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let shape = input_meta.shape();
+    if input_meta.dtype() != WasmDType::Float32 {
+        return Err(WasmError::InvalidDType);
+    }
+    if shape.len() != 2 {
+        return Err(WasmError::InvalidShape);
+    }
+    let (rows, cols) = (shape[0], shape[1]);
+    if q4_rows(output, cols)? != rows {
+        return Err(WasmError::InvalidShape);
+    }
+
+    let values = read_f32(input, arena);
+    let row_bytes = q4_row_bytes(cols);
+    // Every block's scale is at most the tensor-wide one, so checking it up
+    // front keeps an overflowing block from leaving the output half written
+    block_scale(&values)?;
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = unsafe { (output.get_read_ptr(arena) as *mut u8).add(output.metadata().offset()) };
+    let out = unsafe { std::slice::from_raw_parts_mut(output_ptr, rows * row_bytes) };
+
+    for (row_values, row) in values.chunks_exact(cols.max(1)).zip(out.chunks_exact_mut(row_bytes.max(1))) {
+        for (block_values, block) in row_values.chunks(Q4_BLOCK_SIZE).zip(row.chunks_exact_mut(Q4_BLOCK_BYTES)) {
+            quantize_block(block_values, block)?;
+        }
+    }
+    Ok(())
+}
+
+/// Execute `output[..., n] = input[..., k] × weightsᵀ` with Q4 `weights` of `n` rows
+pub fn execute_q4_matmul_op(
+    input: &WasmTensor,
+    weights: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    let input_shape = input_meta.shape();
+    let output_shape = output_meta.shape();
+    if input_meta.dtype() != WasmDType::Float32 || output_meta.dtype() != WasmDType::Float32 {
+        return Err(WasmError::InvalidDType);
+    }
+
+    let k = *input_shape.last().ok_or(WasmError::InvalidShape)?;
+    let n = q4_rows(weights, k)?;
+    let m = input_shape[..input_shape.len() - 1].iter().product::<usize>();
+    if output_shape.last() != Some(&n) || output_meta.size() != m * n {
+        return Err(WasmError::InvalidShape);
+    }
+
+    let activations = read_f32(input, arena);
+    let row_bytes = q4_row_bytes(k);
+    let weight_bytes = unsafe {
+        std::slice::from_raw_parts(weights.get_read_ptr(arena).add(weights.metadata().offset()), n * row_bytes)
+    };
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = output.get_read_ptr(arena) as *mut f32;
+    let out = unsafe { std::slice::from_raw_parts_mut(output_ptr, m * n) };
+
+    if k == 0 {
+        out.fill(0.0);
+        return Ok(());
+    }
+
+    // Each weight row is decoded once and reused for every activation row
+    let mut weight_row = vec![0.0f32; k];
+    for (j, row) in weight_bytes.chunks_exact(row_bytes).enumerate() {
+        dequantize_row(row, &mut weight_row);
+        for (i, activation_row) in activations.chunks_exact(k).enumerate() {
+            out[i * n + j] = float32::simd_dot(activation_row, &weight_row);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_bytes() {
+        assert_eq!(q4_row_bytes(32), 18);
+        assert_eq!(q4_row_bytes(33), 36);
+        assert_eq!(q4_row_bytes(4096), 2304);
+    }
+
+    #[test]
+    fn test_block_round_trip() {
+        // Exactly representable: multiples of the scale within -8..=7
+        let values: Vec<f32> = (0..32).map(|i| ((i % 15) as f32 - 7.0) * 0.5).collect();
+        let mut block = [0u8; Q4_BLOCK_BYTES];
+        quantize_block(&values, &mut block).unwrap();
+
+        let mut decoded = [0.0f32; Q4_BLOCK_SIZE];
+        dequantize_block(&block, &mut decoded);
+        assert_eq!(decoded.to_vec(), values);
+    }
+
+    #[test]
+    fn test_partial_block_and_error_bound() {
+        let values: Vec<f32> = (0..45).map(|i| (i as f32 * 0.37).sin() * 3.0).collect();
+        let mut row = vec![0u8; q4_row_bytes(45)];
+        for (block_values, block) in values.chunks(Q4_BLOCK_SIZE).zip(row.chunks_exact_mut(Q4_BLOCK_BYTES)) {
+            quantize_block(block_values, block).unwrap();
+        }
+
+        let mut decoded = vec![0.0f32; 45];
+        dequantize_row(&row, &mut decoded);
+        for (block_values, block_decoded) in values.chunks(Q4_BLOCK_SIZE).zip(decoded.chunks(Q4_BLOCK_SIZE)) {
+            // Round-to-nearest error is at most half a step (plus f16 scale rounding)
+            let step = block_values.iter().fold(0.0f32, |acc, &v| acc.max(v.abs())) / 7.0;
+            for (&original, &restored) in block_values.iter().zip(block_decoded) {
+                assert!((original - restored).abs() <= step * 0.51, "{} vs {}", original, restored);
+            }
+        }
+
+        // All-zero block has zero scale
+        let mut block = [0xFFu8; Q4_BLOCK_BYTES];
+        quantize_block(&[0.0; 5], &mut block).unwrap();
+        dequantize_block(&block, &mut decoded[..5]);
+        assert_eq!(&decoded[..5], &[0.0; 5]);
+    }
+
+    #[test]
+    fn test_scale_overflow() {
+        // 400k / 7 still fits f16 (max 65504); 500k / 7 and infinities do not
+        let mut block = [0u8; Q4_BLOCK_BYTES];
+        let mut decoded = [0.0f32; 2];
+        quantize_block(&[400_000.0, -200_000.0], &mut block).unwrap();
+        dequantize_block(&block, &mut decoded);
+        assert!((decoded[0] - 400_000.0).abs() <= 400_000.0 / 7.0 * 0.51);
+        assert!(decoded.iter().all(|v| v.is_finite()));
+
+        assert!(matches!(quantize_block(&[500_000.0, 1.0], &mut block), Err(WasmError::InvalidInput)));
+        assert!(quantize_block(&[f32::NEG_INFINITY], &mut block).is_err());
+    }
+}
@@ -0,0 +1,378 @@
+/*!
+ * Quantized int8 matrix multiplication for WebAssembly backend
+ *
+ * - `Int8 × Int8 → Int32`: plain integer matmul with wrapping i32 accumulation
+ * - `Int8 × Int8 → Float32`: affine-quantized matmul. `A` uses one scale and
+ *   zero point; `B` uses one per tensor or one per output channel (its last
+ *   dimension). Zero points are subtracted before accumulating, then each
+ *   accumulator is scaled by `a_scale * b_scale[j]`.
+ *
+ * Operands are widened to i16 panels (rows of A, columns of B, padded to a
+ * multiple of 8) so the inner loop maps onto the WASM SIMD `i32x4.dot_i16x8`
+ * instruction. Batch dimensions broadcast like `Matmul`, and 1D operands are
+ * treated as a row (A) or column (B) vector.
+ */
+
+use wasm_bindgen::prelude::*;
+use crate::types::{WasmTensorMeta, WasmDType, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::simd::int8;
+use crate::operations::strided::{StridedIndexer, broadcast_strides, storage_len};
+
+/// Inner dimension padding so every panel row is a whole number of SIMD vectors
+const K_ALIGN: usize = 8;
+
+/// Affine quantization parameters: `real = scale * (quantized - zero_point)`
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmQuantizationParams {
+    /// Scale of A (per tensor)
+    a_scale: f32,
+    /// Zero point of A (per tensor)
+    a_zero_point: i32,
+    /// Scales of B: 1 (per tensor) or one per output channel
+    b_scales: Vec<f32>,
+    /// Zero points of B: 1 (per tensor) or one per output channel
+    b_zero_points: Vec<i32>,
+}
+
+#[wasm_bindgen]
+impl WasmQuantizationParams {
+    /// Create parameters; pass single-element `b_scales` / `b_zero_points` for per-tensor B
+    #[wasm_bindgen(constructor)]
+    pub fn new(a_scale: f32, a_zero_point: i32, b_scales: Vec<f32>, b_zero_points: Vec<i32>) -> WasmQuantizationParams {
+        WasmQuantizationParams { a_scale, a_zero_point, b_scales, b_zero_points }
+    }
+}
+
+/// 2D view of one operand: dimensions plus batch-independent strides
+#[derive(Debug, Clone, Copy)]
+struct MatrixLayout {
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// Batched operand geometry shared by both quantized matmul flavors
+struct Int8Problem {
+    a: MatrixLayout,
+    b: MatrixLayout,
+    a_batches: StridedIndexer,
+    b_batches: StridedIndexer,
+    batch_count: usize,
+}
+
+impl Int8Problem {
+    fn new(meta_a: &WasmTensorMeta, meta_b: &WasmTensorMeta, meta_out: &WasmTensorMeta) -> WasmResult<Self> {
+        if meta_a.dtype() != WasmDType::Int8 || meta_b.dtype() != WasmDType::Int8 {
+            return Err(WasmError::InvalidDType);
+        }
+
+        let shape_a = meta_a.shape();
+        let shape_b = meta_b.shape();
+        let strides_a = meta_a.strides();
+        let strides_b = meta_b.strides();
+        let shape_out = meta_out.shape();
+        if shape_a.is_empty() || shape_b.is_empty()
+            || strides_a.len() != shape_a.len() || strides_b.len() != shape_b.len() {
+            return Err(WasmError::InvalidShape);
+        }
+
+        // 1D A is a single row, 1D B a single column
+        let rank_a = shape_a.len();
+        let rank_b = shape_b.len();
+        let a = if rank_a == 1 {
+            MatrixLayout { rows: 1, cols: shape_a[0], row_stride: 0, col_stride: strides_a[0] }
+        } else {
+            MatrixLayout {
+                rows: shape_a[rank_a - 2],
+                cols: shape_a[rank_a - 1],
+                row_stride: strides_a[rank_a - 2],
+                col_stride: strides_a[rank_a - 1],
+            }
+        };
+        let b = if rank_b == 1 {
+            MatrixLayout { rows: shape_b[0], cols: 1, row_stride: strides_b[0], col_stride: 0 }
+        } else {
+            MatrixLayout {
+                rows: shape_b[rank_b - 2],
+                cols: shape_b[rank_b - 1],
+                row_stride: strides_b[rank_b - 2],
+                col_stride: strides_b[rank_b - 1],
+            }
+        };
+        if a.cols != b.rows {
+            return Err(WasmError::InvalidShape);
+        }
+
+        // Output is [...batch, m, n] with the 1D dimensions dropped
+        let matrix_dims = (rank_a >= 2) as usize + (rank_b >= 2) as usize;
+        if shape_out.len() < matrix_dims {
+            return Err(WasmError::InvalidShape);
+        }
+        let batch_shape = &shape_out[..shape_out.len() - matrix_dims];
+        let batch_a = rank_a.saturating_sub(2);
+        let batch_b = rank_b.saturating_sub(2);
+        let a_batch_strides = broadcast_strides(&shape_a[..batch_a], &strides_a[..batch_a], batch_shape)?;
+        let b_batch_strides = broadcast_strides(&shape_b[..batch_b], &strides_b[..batch_b], batch_shape)?;
+
+        let batch_count: usize = batch_shape.iter().product();
+        if meta_out.size() != batch_count * a.rows * b.cols {
+            return Err(WasmError::InvalidShape);
+        }
+
+        Ok(Int8Problem {
+            a,
+            b,
+            a_batches: StridedIndexer::new(batch_shape, &a_batch_strides, meta_a.offset()),
+            b_batches: StridedIndexer::new(batch_shape, &b_batch_strides, meta_b.offset()),
+            batch_count,
+        })
+    }
+
+    /// Compute `sum_p (a[i, p] - a_zero) * (b[p, j] - b_zeros[j])` for every batch
+    fn accumulate(&mut self, a: &[i8], b: &[i8], out: &mut [i32], a_zero: i32, b_zeros: &[i32]) {
+        let m = self.a.rows;
+        let k = self.a.cols;
+        let n = self.b.cols;
+        // At least one vector, so empty inner dimensions still produce zeros
+        let k_padded = k.div_ceil(K_ALIGN).max(1) * K_ALIGN;
+
+        let mut a_panel = vec![0i16; m * k_padded];
+        let mut b_panel = vec![0i16; n * k_padded];
+        let mut packed_b_offset = None;
+
+        for out_batch in out.chunks_exact_mut((m * n).max(1)).take(self.batch_count) {
+            let a_offset = self.a_batches.offset();
+            let b_offset = self.b_batches.offset();
+            self.a_batches.advance();
+            self.b_batches.advance();
+
+            pack_rows(a, a_offset, self.a, a_zero, &mut a_panel, k_padded);
+            // Weights broadcast across the batch are packed once
+            if packed_b_offset != Some(b_offset) {
+                pack_columns(b, b_offset, self.b, b_zeros, &mut b_panel, k_padded);
+                packed_b_offset = Some(b_offset);
+            }
+
+            for (a_row, out_row) in a_panel.chunks_exact(k_padded).zip(out_batch.chunks_exact_mut(n.max(1))) {
+                for (b_col, out) in b_panel.chunks_exact(k_padded).zip(out_row.iter_mut()) {
+                    *out = int8::dot_i16(a_row, b_col);
+                }
+            }
+        }
+    }
+}
+
+/// Widen rows of A (minus its zero point) into a row-major `rows × k_padded` panel
+fn pack_rows(a: &[i8], offset: usize, layout: MatrixLayout, zero: i32, panel: &mut [i16], k_padded: usize) {
+    for (i, row) in panel.chunks_exact_mut(k_padded).take(layout.rows).enumerate() {
+        let base = offset + i * layout.row_stride;
+        for (p, val) in row[..layout.cols].iter_mut().enumerate() {
+            *val = (a[base + p * layout.col_stride] as i32 - zero) as i16;
+        }
+    }
+}
+
+/// Widen columns of B (minus their zero points) into a `cols × k_padded` panel
+fn pack_columns(b: &[i8], offset: usize, layout: MatrixLayout, zeros: &[i32], panel: &mut [i16], k_padded: usize) {
+    for (j, col) in panel.chunks_exact_mut(k_padded).take(layout.cols).enumerate() {
+        let zero = zeros[if zeros.len() == 1 { 0 } else { j }];
+        let base = offset + j * layout.col_stride;
+        for (p, val) in col[..layout.rows].iter_mut().enumerate() {
+            *val = (b[base + p * layout.row_stride] as i32 - zero) as i16;
+        }
+    }
+}
+
+/// Check that a per-channel parameter list has 1 or `channels` entries
+fn validate_channels(len: usize, channels: usize) -> WasmResult<()> {
+    if len == 1 || len == channels {
+        Ok(())
+    } else {
+        Err(WasmError::InvalidShape)
+    }
+}
+
+/// Check that a zero point is representable in int8
+fn validate_zero_point(zero_point: i32) -> WasmResult<()> {
+    if (i8::MIN as i32..=i8::MAX as i32).contains(&zero_point) {
+        Ok(())
+    } else {
+        Err(WasmError::InvalidInput)
+    }
+}
+
+/// Execute `Int8 × Int8 → Int32` matmul with i32 accumulation
+pub fn execute_matmul_i8_op(
+    input_a: &WasmTensor,
+    input_b: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+) -> WasmResult<()> {
+    let meta_a = input_a.metadata();
+    let meta_b = input_b.metadata();
+    let meta_out = output.metadata();
+    if meta_out.dtype() != WasmDType::Int32 {
+        return Err(WasmError::InvalidDType);
+    }
+    let mut problem = Int8Problem::new(meta_a, meta_b, meta_out)?;
+
+    // Views are addressed from the start of their storage
+    let a = unsafe { std::slice::from_raw_parts(input_a.get_read_ptr(arena) as *const i8, storage_len(meta_a)) };
+    let b = unsafe { std::slice::from_raw_parts(input_b.get_read_ptr(arena) as *const i8, storage_len(meta_b)) };
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let out = unsafe {
+        std::slice::from_raw_parts_mut(output.get_read_ptr(arena) as *mut i32, meta_out.size())
+    };
+
+    problem.accumulate(a, b, out, 0, &[0]);
+    Ok(())
+}
+
+/// Execute affine-quantized `Int8 × Int8 → Float32` matmul
+///
+/// Scales and zero points of B are per tensor (1 entry) or per output channel
+/// (one entry per column of B). Zero points must fit in int8.
+pub fn execute_quantized_matmul_op(
+    input_a: &WasmTensor,
+    input_b: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    params: &WasmQuantizationParams,
+) -> WasmResult<()> {
+    let meta_a = input_a.metadata();
+    let meta_b = input_b.metadata();
+    let meta_out = output.metadata();
+    if meta_out.dtype() != WasmDType::Float32 {
+        return Err(WasmError::InvalidDType);
+    }
+    let mut problem = Int8Problem::new(meta_a, meta_b, meta_out)?;
+
+    let channels = problem.b.cols;
+    validate_channels(params.b_scales.len(), channels)?;
+    validate_channels(params.b_zero_points.len(), channels)?;
+    validate_zero_point(params.a_zero_point)?;
+    for &zero_point in &params.b_zero_points {
+        validate_zero_point(zero_point)?;
+    }
+
+    let a = unsafe { std::slice::from_raw_parts(input_a.get_read_ptr(arena) as *const i8, storage_len(meta_a)) };
+    let b = unsafe { std::slice::from_raw_parts(input_b.get_read_ptr(arena) as *const i8, storage_len(meta_b)) };
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let out = unsafe {
+        std::slice::from_raw_parts_mut(output.get_read_ptr(arena) as *mut f32, meta_out.size())
+    };
+
+    let mut accumulators = vec![0i32; out.len()];
+    problem.accumulate(a, b, &mut accumulators, params.a_zero_point, &params.b_zero_points);
+
+    // Dequantize: combined scale per output channel
+    let scales: Vec<f32> = (0..channels)
+        .map(|j| params.a_scale * params.b_scales[if params.b_scales.len() == 1 { 0 } else { j }])
+        .collect();
+    for (out_row, acc_row) in out.chunks_exact_mut(channels.max(1)).zip(accumulators.chunks_exact(channels.max(1))) {
+        for ((out, &acc), &scale) in out_row.iter_mut().zip(acc_row).zip(&scales) {
+            *out = acc as f32 * scale;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::strided::contiguous_strides;
+
+    fn meta(dtype: WasmDType, shape: &[usize]) -> WasmTensorMeta {
+        let size = shape.iter().product();
+        WasmTensorMeta::new(dtype, shape.to_vec(), contiguous_strides(shape), size, 0)
+    }
+
+    /// Reference matmul over (value - zero point) products
+    fn reference(a: &[i8], b: &[i8], m: usize, k: usize, n: usize, a_zero: i32, b_zeros: &[i32]) -> Vec<i32> {
+        let mut out = vec![0; m * n];
+        for i in 0..m {
+            for j in 0..n {
+                let b_zero = b_zeros[if b_zeros.len() == 1 { 0 } else { j }];
+                out[i * n + j] = (0..k)
+                    .map(|p| (a[i * k + p] as i32 - a_zero) * (b[p * n + j] as i32 - b_zero))
+                    .sum();
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_int8_gemm_matches_reference() {
+        // k = 19 exercises both full SIMD chunks and the padded tail
+        let (m, k, n) = (3, 19, 5);
+        let a: Vec<i8> = (0..m * k).map(|i| (i as i32 * 37 % 255 - 127) as i8).collect();
+        let b: Vec<i8> = (0..k * n).map(|i| (i as i32 * 91 % 255 - 127) as i8).collect();
+
+        let mut problem = Int8Problem::new(
+            &meta(WasmDType::Int8, &[m, k]),
+            &meta(WasmDType::Int8, &[k, n]),
+            &meta(WasmDType::Int32, &[m, n]),
+        ).unwrap();
+        let mut out = vec![0i32; m * n];
+        problem.accumulate(&a, &b, &mut out, 0, &[0]);
+        assert_eq!(out, reference(&a, &b, m, k, n, 0, &[0]));
+
+        // Per-channel zero points
+        let b_zeros = [1, -3, 0, 7, -128];
+        let mut problem = Int8Problem::new(
+            &meta(WasmDType::Int8, &[m, k]),
+            &meta(WasmDType::Int8, &[k, n]),
+            &meta(WasmDType::Float32, &[m, n]),
+        ).unwrap();
+        problem.accumulate(&a, &b, &mut out, 5, &b_zeros);
+        assert_eq!(out, reference(&a, &b, m, k, n, 5, &b_zeros));
+    }
+
+    #[test]
+    fn test_int8_batched_and_vector_shapes() {
+        // [2, 2, 3] × [3, 2]: B broadcasts across the batch
+        let a: Vec<i8> = (1..=12).collect();
+        let b: [i8; 6] = [1, 0, 0, 1, 1, -1];
+        let mut problem = Int8Problem::new(
+            &meta(WasmDType::Int8, &[2, 2, 3]),
+            &meta(WasmDType::Int8, &[3, 2]),
+            &meta(WasmDType::Int32, &[2, 2, 2]),
+        ).unwrap();
+        let mut out = vec![0i32; 8];
+        problem.accumulate(&a, &b, &mut out, 0, &[0]);
+        assert_eq!(out, vec![4, -1, 10, -1, 16, -1, 22, -1]);
+
+        // 1D × 1D dot product
+        let mut problem = Int8Problem::new(
+            &meta(WasmDType::Int8, &[3]),
+            &meta(WasmDType::Int8, &[3]),
+            &meta(WasmDType::Int32, &[1]),
+        ).unwrap();
+        let mut out = [0i32; 1];
+        problem.accumulate(&[1, 2, 3], &[4, 5, 6], &mut out, 0, &[0]);
+        assert_eq!(out, [32]);
+
+        assert!(Int8Problem::new(
+            &meta(WasmDType::Int8, &[2, 3]),
+            &meta(WasmDType::Int8, &[2, 2]),
+            &meta(WasmDType::Int32, &[2, 2]),
+        ).is_err());
+    }
+
+    #[test]
+    fn test_parameter_validation() {
+        assert!(validate_channels(1, 4).is_ok());
+        assert!(validate_channels(4, 4).is_ok());
+        assert!(validate_channels(3, 4).is_err());
+        assert!(validate_zero_point(-128).is_ok());
+        assert!(matches!(validate_zero_point(128), Err(WasmError::InvalidInput)));
+    }
+}
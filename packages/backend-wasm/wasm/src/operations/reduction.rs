@@ -3,11 +3,27 @@
  * 
  * Provides optimized implementations of tensor reduction operations
  * such as sum, mean, max, min, and product along specified axes.
+ *
+ * `ArgMax` / `ArgMin` accept every dtype and write Int32 indices into the
+ * reduced axes, flattened in row-major order (the flat index when reducing
+ * all axes). Ties resolve to the first occurrence and a NaN is always
+ * selected, so its first index is returned.
+ *
+ * `Var`, `Std`, `NormL1`, `NormL2`, `NormLinf` and `LogSumExp` accept every
+ * dtype and write Float32 or Float64 (see `execute_statistic_op`); through
+ * `execute_reduction_op_with_axes` Var and Std are the population statistics.
+ *
+ * `Any` / `All` write Bool and `CountNonzero` writes Int32 for every input
+ * dtype; any nonzero value (including NaN) is true, as in the logical ops.
+ * Empty reductions give false, true and 0.
  */
 
 use crate::types::{WasmOperation, WasmTensorMeta, WasmDType, WasmResult, WasmError};
 use crate::memory::WasmTensor;
 use crate::arena::TempArena;
+use crate::operations::binary::PROMOTION_BLOCK_SIZE;
+use crate::operations::element::PromotedElement;
+use crate::operations::strided::{StridedIndexer, is_contiguous, storage_len};
 
 /// Execute a reduction operation (legacy - no axis support)
 pub fn execute_reduction_op(
@@ -36,6 +52,16 @@ pub fn execute_reduction_op_with_axes(
     axes: Option<&[usize]>,
     keep_dims: bool,
 ) -> WasmResult<()> {
+    if matches!(operation, WasmOperation::ArgMax | WasmOperation::ArgMin) {
+        return execute_arg_reduction(operation, input, output, arena, axes);
+    }
+    if is_statistic(operation) {
+        return execute_statistic_op(operation, input, output, arena, axes, 0.0);
+    }
+    if matches!(operation, WasmOperation::Any | WasmOperation::All | WasmOperation::CountNonzero) {
+        return execute_nonzero_reduction(operation, input, output, arena, axes);
+    }
+    
     let input_ptr = input.get_read_ptr(arena);
     let output_ptr = output.get_read_ptr(arena) as *mut u8; // Cast to mut for operations
     
@@ -44,10 +70,20 @@ pub fn execute_reduction_op_with_axes(
     let input_shape = input_meta.shape();
     let input_strides = input_meta.strides();
     
+    // Views are read from their first element, following their strides;
+    // full reductions assume contiguous data, so views reduce over every axis instead
+    let input_ptr = unsafe { input_ptr.add(input_meta.offset() * input_meta.dtype().byte_size()) };
+    let input_len = storage_len(input_meta) - input_meta.offset();
+    let all_axes: Vec<usize> = (0..input_shape.len()).collect();
+    let axes = match axes {
+        None | Some([]) if !is_contiguous(input_meta) => Some(all_axes.as_slice()),
+        _ => axes,
+    };
+    
     match input_meta.dtype() {
         WasmDType::Float32 => {
             let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const f32, input_meta.size()) 
+                std::slice::from_raw_parts(input_ptr as *const f32, input_len) 
             };
             let output_slice = unsafe { 
                 std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_meta.size()) 
@@ -59,7 +95,7 @@ pub fn execute_reduction_op_with_axes(
         }
         WasmDType::Float64 => {
             let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const f64, input_meta.size()) 
+                std::slice::from_raw_parts(input_ptr as *const f64, input_len) 
             };
             let output_slice = unsafe { 
                 std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_meta.size()) 
@@ -71,7 +107,7 @@ pub fn execute_reduction_op_with_axes(
         }
         WasmDType::Int32 => {
             let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const i32, input_meta.size()) 
+                std::slice::from_raw_parts(input_ptr as *const i32, input_len) 
             };
             let output_slice = unsafe { 
                 std::slice::from_raw_parts_mut(output_ptr as *mut i32, output_meta.size()) 
@@ -87,6 +123,380 @@ pub fn execute_reduction_op_with_axes(
     Ok(())
 }
 
+/// Execute ArgMax / ArgMin into an Int32 `output` of indices
+fn execute_arg_reduction(
+    operation: WasmOperation,
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    axes: Option<&[usize]>,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    if output_meta.dtype() != WasmDType::Int32 {
+        return Err(WasmError::InvalidDType);
+    }
+    
+    let layout = ReductionLayout::new(input_meta, axes)?;
+    if layout.rows != output_meta.size() {
+        return Err(WasmError::InvalidShape);
+    }
+    // An empty reduction has no index; indices must fit in Int32
+    if layout.reduced_size == 0 || layout.reduced_size > i32::MAX as usize {
+        return Err(WasmError::InvalidShape);
+    }
+    
+    let input_ptr = input.get_read_ptr(arena);
+    
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = output.get_read_ptr(arena) as *mut i32;
+    let out = unsafe { std::slice::from_raw_parts_mut(output_ptr, output_meta.size()) };
+    let dtype = input_meta.dtype();
+    
+    // Half precision is compared as f32, which represents it exactly
+    match dtype {
+        WasmDType::Float32 | WasmDType::Float16 | WasmDType::BFloat16 => arg_reduce_typed::<f32>(operation, dtype, input_ptr, &layout, out),
+        WasmDType::Float64 => arg_reduce_typed::<f64>(operation, dtype, input_ptr, &layout, out),
+        WasmDType::Int8 => arg_reduce_typed::<i8>(operation, dtype, input_ptr, &layout, out),
+        WasmDType::Uint8 | WasmDType::Bool => arg_reduce_typed::<u8>(operation, dtype, input_ptr, &layout, out),
+        WasmDType::Int16 => arg_reduce_typed::<i16>(operation, dtype, input_ptr, &layout, out),
+        WasmDType::Uint16 => arg_reduce_typed::<u16>(operation, dtype, input_ptr, &layout, out),
+        WasmDType::Int32 => arg_reduce_typed::<i32>(operation, dtype, input_ptr, &layout, out),
+        WasmDType::Uint32 => arg_reduce_typed::<u32>(operation, dtype, input_ptr, &layout, out),
+        WasmDType::BigInt64 => arg_reduce_typed::<i64>(operation, dtype, input_ptr, &layout, out),
+        WasmDType::BigUint64 => arg_reduce_typed::<u64>(operation, dtype, input_ptr, &layout, out),
+    }
+    Ok(())
+}
+
+/// Index of the max / min of each row: strict comparison keeps the first of
+/// equal values, and nothing replaces a NaN
+fn arg_reduce_typed<T: PromotedElement + PartialOrd>(
+    operation: WasmOperation,
+    dtype: WasmDType,
+    ptr: *const u8,
+    layout: &ReductionLayout,
+    output: &mut [i32],
+) {
+    let is_nan = |value: T| value.partial_cmp(&value).is_none();
+    let is_better = |value: T, best: T| match operation {
+        WasmOperation::ArgMax => value > best,
+        _ => value < best,
+    };
+    
+    layout.reduce_rows(
+        dtype,
+        ptr,
+        (T::default(), 0),
+        |(best, best_index), value: T, position| {
+            if position == 0 || (!is_nan(best) && (is_nan(value) || is_better(value, best))) {
+                (value, position)
+            } else {
+                (best, best_index)
+            }
+        },
+        |row, (_, best_index)| output[row] = best_index as i32,
+    );
+}
+
+/// Execute Any / All into a Bool `output` or CountNonzero into an Int32 `output`
+fn execute_nonzero_reduction(
+    operation: WasmOperation,
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    axes: Option<&[usize]>,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    let output_dtype = match operation {
+        WasmOperation::CountNonzero => WasmDType::Int32,
+        _ => WasmDType::Bool,
+    };
+    if output_meta.dtype() != output_dtype {
+        return Err(WasmError::InvalidDType);
+    }
+    
+    let layout = ReductionLayout::new(input_meta, axes)?;
+    if layout.rows != output_meta.size() || layout.reduced_size > i32::MAX as usize {
+        return Err(WasmError::InvalidShape);
+    }
+    
+    let input_ptr = input.get_read_ptr(arena);
+    
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = output.get_read_ptr(arena) as *mut u8;
+    let dtype = input_meta.dtype();
+    
+    match dtype {
+        WasmDType::Float32 | WasmDType::Float16 | WasmDType::BFloat16 => nonzero_reduce_typed::<f32>(operation, dtype, input_ptr, &layout, output_ptr),
+        WasmDType::Float64 => nonzero_reduce_typed::<f64>(operation, dtype, input_ptr, &layout, output_ptr),
+        WasmDType::Int8 => nonzero_reduce_typed::<i8>(operation, dtype, input_ptr, &layout, output_ptr),
+        WasmDType::Uint8 | WasmDType::Bool => nonzero_reduce_typed::<u8>(operation, dtype, input_ptr, &layout, output_ptr),
+        WasmDType::Int16 => nonzero_reduce_typed::<i16>(operation, dtype, input_ptr, &layout, output_ptr),
+        WasmDType::Uint16 => nonzero_reduce_typed::<u16>(operation, dtype, input_ptr, &layout, output_ptr),
+        WasmDType::Int32 => nonzero_reduce_typed::<i32>(operation, dtype, input_ptr, &layout, output_ptr),
+        WasmDType::Uint32 => nonzero_reduce_typed::<u32>(operation, dtype, input_ptr, &layout, output_ptr),
+        WasmDType::BigInt64 => nonzero_reduce_typed::<i64>(operation, dtype, input_ptr, &layout, output_ptr),
+        WasmDType::BigUint64 => nonzero_reduce_typed::<u64>(operation, dtype, input_ptr, &layout, output_ptr),
+    }
+    Ok(())
+}
+
+/// Any / All / CountNonzero of each row; NaN is nonzero since NaN != 0
+fn nonzero_reduce_typed<T: PromotedElement + PartialEq>(
+    operation: WasmOperation,
+    dtype: WasmDType,
+    ptr: *const u8,
+    layout: &ReductionLayout,
+    output_ptr: *mut u8,
+) {
+    let zero = T::default();
+    if operation == WasmOperation::CountNonzero {
+        let out = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i32, layout.rows) };
+        layout.reduce_rows(dtype, ptr, 0i32, |count, value: T, _| count + (value != zero) as i32, |row, count| out[row] = count);
+        return;
+    }
+    
+    let out = unsafe { std::slice::from_raw_parts_mut(output_ptr, layout.rows) };
+    let (init, step): (u8, fn(u8, bool) -> u8) = match operation {
+        WasmOperation::All => (1, |all, nonzero| all & nonzero as u8),
+        _ => (0, |any, nonzero| any | nonzero as u8),
+    };
+    layout.reduce_rows(dtype, ptr, init, |state, value: T, _| step(state, value != zero), |row, state| out[row] = state);
+}
+
+/// Execute Var, Std, NormL1, NormL2, NormLinf or LogSumExp
+///
+/// Inputs of any dtype are accumulated in f64 into a Float32 or Float64
+/// `output`. Var and Std divide by `count - correction` (0 for the
+/// population variance, 1 for Bessel's correction); the output may keep
+/// reduced axes as length 1 since only its size is checked.
+pub fn execute_statistic_op(
+    operation: WasmOperation,
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    axes: Option<&[usize]>,
+    correction: f64,
+) -> WasmResult<()> {
+    if !is_statistic(operation) {
+        return Err(WasmError::InvalidOperation);
+    }
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    let layout = ReductionLayout::new(input_meta, axes)?;
+    if layout.rows != output_meta.size() {
+        return Err(WasmError::InvalidShape);
+    }
+    
+    let input_ptr = input.get_read_ptr(arena);
+    let output_ptr = output.get_read_ptr(arena) as *mut u8; // Cast to mut for operations
+    let dtype = input_meta.dtype();
+    let step = |mut statistic: Statistic, value: f64, _| {
+        statistic.push(operation, value);
+        statistic
+    };
+    
+    match output_meta.dtype() {
+        WasmDType::Float32 => {
+            let out = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_meta.size()) };
+            layout.reduce_rows(dtype, input_ptr, Statistic::new(), step, |row, statistic| {
+                out[row] = statistic.finish(operation, correction) as f32;
+            });
+        }
+        WasmDType::Float64 => {
+            let out = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_meta.size()) };
+            layout.reduce_rows(dtype, input_ptr, Statistic::new(), step, |row, statistic| {
+                out[row] = statistic.finish(operation, correction);
+            });
+        }
+        _ => return Err(WasmError::InvalidDType),
+    }
+    Ok(())
+}
+
+/// Check if an operation is handled by `execute_statistic_op`
+fn is_statistic(operation: WasmOperation) -> bool {
+    matches!(operation,
+        WasmOperation::Var | WasmOperation::Std | WasmOperation::NormL1 |
+        WasmOperation::NormL2 | WasmOperation::NormLinf | WasmOperation::LogSumExp
+    )
+}
+
+/// Running state of a statistic over one row
+///
+/// Var / Std use Welford's update; sums (norms and the LogSumExp
+/// denominator) are compensated with Kahan-Babuska (Neumaier) summation,
+/// which unlike `kahan_sum_f32` keeps the plain running sum intact so
+/// infinities and NaN pass through.
+#[derive(Debug, Clone, Copy)]
+struct Statistic {
+    count: f64,
+    mean: f64,
+    m2: f64,
+    sum: f64,
+    compensation: f64,
+    /// Running max (of |x| for NormLinf, of x for LogSumExp)
+    max: f64,
+}
+
+impl Statistic {
+    fn new() -> Self {
+        Statistic { count: 0.0, mean: 0.0, m2: 0.0, sum: 0.0, compensation: 0.0, max: f64::NEG_INFINITY }
+    }
+
+    #[inline]
+    fn add(&mut self, value: f64) {
+        let total = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - total) + value;
+        } else {
+            self.compensation += (value - total) + self.sum;
+        }
+        self.sum = total;
+    }
+
+    #[inline]
+    fn push(&mut self, operation: WasmOperation, value: f64) {
+        match operation {
+            WasmOperation::Var | WasmOperation::Std => {
+                self.count += 1.0;
+                let delta = value - self.mean;
+                self.mean += delta / self.count;
+                self.m2 += delta * (value - self.mean);
+            }
+            WasmOperation::NormL1 => self.add(value.abs()),
+            WasmOperation::NormL2 => self.add(value * value),
+            WasmOperation::NormLinf => {
+                if value.abs() > self.max || value.is_nan() {
+                    self.max = value.abs();
+                }
+            }
+            _ => {
+                // Online LogSumExp: the sum is of exp(x - max), rescaled when the max grows;
+                // -inf adds nothing and is skipped so -inf - -inf never occurs
+                if value > self.max {
+                    let scale = (self.max - value).exp();
+                    self.sum *= scale;
+                    self.compensation *= scale;
+                    self.max = value;
+                    self.add(1.0);
+                } else if value == self.max {
+                    self.add(1.0);
+                } else if value != f64::NEG_INFINITY {
+                    self.add((value - self.max).exp());
+                }
+            }
+        }
+    }
+
+    /// Compensated sum, or the plain sum once it is no longer finite
+    fn total(&self) -> f64 {
+        if self.sum.is_finite() { self.sum + self.compensation } else { self.sum }
+    }
+
+    fn finish(&self, operation: WasmOperation, correction: f64) -> f64 {
+        match operation {
+            WasmOperation::Var => self.m2 / (self.count - correction).max(0.0),
+            WasmOperation::Std => (self.m2 / (self.count - correction).max(0.0)).sqrt(),
+            WasmOperation::NormL1 => self.total(),
+            WasmOperation::NormL2 => self.total().sqrt(),
+            WasmOperation::NormLinf if self.max == f64::NEG_INFINITY => 0.0,
+            WasmOperation::NormLinf => self.max,
+            // Nothing was added: the row is empty or all -inf
+            _ if self.sum == 0.0 => f64::NEG_INFINITY,
+            _ => self.max + self.total().ln(),
+        }
+    }
+}
+
+/// Input layout reordered so kept axes come first and reduced axes last
+/// (both in input order): walking it row-major visits each output element's
+/// reduced values contiguously, in row-major order of the reduced axes
+struct ReductionLayout {
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    offset: usize,
+    /// Number of output elements
+    rows: usize,
+    /// Number of reduced elements per output element
+    reduced_size: usize,
+}
+
+impl ReductionLayout {
+    /// Layout of `meta` reducing `axes` (all axes when `None` or empty)
+    fn new(meta: &WasmTensorMeta, axes: Option<&[usize]>) -> WasmResult<Self> {
+        let shape = meta.shape();
+        let strides = meta.strides();
+        let ndim = shape.len();
+        let mut is_reduced_axis = vec![false; ndim];
+        match axes {
+            None | Some([]) => is_reduced_axis.fill(true),
+            Some(axes) => {
+                for &axis in axes {
+                    if axis >= ndim {
+                        return Err(WasmError::InvalidInput);
+                    }
+                    is_reduced_axis[axis] = true;
+                }
+            }
+        }
+        
+        let order: Vec<usize> = (0..ndim).filter(|&axis| !is_reduced_axis[axis])
+            .chain((0..ndim).filter(|&axis| is_reduced_axis[axis]))
+            .collect();
+        let size_of = |reduced: bool| (0..ndim).filter(|&axis| is_reduced_axis[axis] == reduced).map(|axis| shape[axis]).product();
+        Ok(ReductionLayout {
+            shape: order.iter().map(|&axis| shape[axis]).collect(),
+            strides: order.iter().map(|&axis| strides[axis]).collect(),
+            offset: meta.offset(),
+            rows: size_of(false),
+            reduced_size: size_of(true),
+        })
+    }
+
+    /// Fold each row of `dtype` elements loaded as `T` with `step(state,
+    /// value, position)` from `init`, then `emit(row, state)`
+    fn reduce_rows<T: PromotedElement, S: Copy>(
+        &self,
+        dtype: WasmDType,
+        ptr: *const u8,
+        init: S,
+        step: impl Fn(S, T, usize) -> S,
+        mut emit: impl FnMut(usize, S),
+    ) {
+        if self.reduced_size == 0 {
+            (0..self.rows).for_each(|row| emit(row, init));
+            return;
+        }
+        
+        let mut indexer = StridedIndexer::new(&self.shape, &self.strides, self.offset);
+        let mut offsets = [0usize; PROMOTION_BLOCK_SIZE];
+        let mut block = [T::default(); PROMOTION_BLOCK_SIZE];
+        let mut remaining = self.rows * self.reduced_size;
+        let (mut row, mut position, mut state) = (0, 0, init);
+        while remaining > 0 {
+            let len = remaining.min(PROMOTION_BLOCK_SIZE);
+            indexer.fill(&mut offsets[..len]);
+            unsafe { T::load_block(dtype, ptr, &offsets[..len], &mut block[..len]) };
+            for &value in &block[..len] {
+                state = step(state, value, position);
+                position += 1;
+                if position == self.reduced_size {
+                    emit(row, state);
+                    row += 1;
+                    position = 0;
+                    state = init;
+                }
+            }
+            remaining -= len;
+        }
+    }
+}
+
 /// Execute reduction for f32 arrays
 fn execute_reduction_f32(
     operation: WasmOperation,
@@ -781,6 +1191,7 @@ fn execute_axis_reduction_i32(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::operations::strided::contiguous_strides;
     use approx::assert_relative_eq;
 
     #[test]
@@ -843,6 +1254,159 @@ mod tests {
         assert_eq!(output[0], 24.0);
     }
 
+    fn layout(dtype: WasmDType, shape: &[usize], strides: &[usize], axes: Option<&[usize]>) -> ReductionLayout {
+        let meta = WasmTensorMeta::new(dtype, shape.to_vec(), strides.to_vec(), shape.iter().product(), 0);
+        ReductionLayout::new(&meta, axes).unwrap()
+    }
+
+    fn arg_reduce(operation: WasmOperation, input: &[f32], shape: &[usize], axes: Option<&[usize]>) -> Vec<i32> {
+        let layout = layout(WasmDType::Float32, shape, &contiguous_strides(shape), axes);
+        let mut output = vec![-1; layout.rows];
+        arg_reduce_typed::<f32>(operation, WasmDType::Float32, input.as_ptr() as *const u8, &layout, &mut output);
+        output
+    }
+
+    #[test]
+    fn test_argmax_argmin() {
+        // [[3, 7, 7], [9, 1, 1]]: ties resolve to the first occurrence
+        let input = vec![3.0f32, 7.0, 7.0, 9.0, 1.0, 1.0];
+        assert_eq!(arg_reduce(WasmOperation::ArgMax, &input, &[2, 3], None), vec![3]);
+        assert_eq!(arg_reduce(WasmOperation::ArgMin, &input, &[2, 3], Some(&[])), vec![4]);
+        assert_eq!(arg_reduce(WasmOperation::ArgMax, &input, &[2, 3], Some(&[1])), vec![1, 0]);
+        assert_eq!(arg_reduce(WasmOperation::ArgMin, &input, &[2, 3], Some(&[1])), vec![0, 1]);
+        assert_eq!(arg_reduce(WasmOperation::ArgMax, &input, &[2, 3], Some(&[0])), vec![1, 0, 0]);
+        
+        // Indices over several axes are row-major within the reduced axes
+        let input: Vec<f32> = [0, 5, 2, 7, 9, 1, 6, 3].iter().map(|&v| v as f32).collect();
+        assert_eq!(arg_reduce(WasmOperation::ArgMax, &input, &[2, 2, 2], Some(&[2, 0])), vec![2, 1]);
+        
+        // The first NaN wins for both operations
+        let input = vec![1.0f32, f32::NAN, 5.0, f32::NAN, -1.0, 2.0];
+        assert_eq!(arg_reduce(WasmOperation::ArgMax, &input, &[2, 3], Some(&[1])), vec![1, 0]);
+        assert_eq!(arg_reduce(WasmOperation::ArgMin, &input, &[6], None), vec![1]);
+        
+        // Out-of-range axes are rejected
+        let meta = WasmTensorMeta::new(WasmDType::Float32, vec![2, 3], vec![3, 1], 6, 0);
+        assert!(matches!(ReductionLayout::new(&meta, Some(&[2])), Err(WasmError::InvalidInput)));
+    }
+
+    #[test]
+    fn test_argmax_integer_dtypes() {
+        let input: Vec<i8> = vec![-3, 100, -128, 100];
+        let mut output = vec![0i32; 2];
+        let transposed = layout(WasmDType::Int8, &[2, 2], &[1, 2], Some(&[1]));
+        arg_reduce_typed::<i8>(WasmOperation::ArgMin, WasmDType::Int8, input.as_ptr() as *const u8, &transposed, &mut output);
+        assert_eq!(output, vec![1, 0]); // columns of the transposed view: [-3, -128], [100, 100]
+        
+        let input: Vec<u64> = vec![7, u64::MAX, 3];
+        let mut output = vec![0i32; 1];
+        let flat = layout(WasmDType::BigUint64, &[3], &[1], None);
+        arg_reduce_typed::<u64>(WasmOperation::ArgMax, WasmDType::BigUint64, input.as_ptr() as *const u8, &flat, &mut output);
+        assert_eq!(output, vec![1]);
+    }
+
+    fn statistic(operation: WasmOperation, input: &[f64], shape: &[usize], axes: Option<&[usize]>, correction: f64) -> Vec<f64> {
+        let layout = layout(WasmDType::Float64, shape, &contiguous_strides(shape), axes);
+        let mut output = vec![0.0; layout.rows];
+        layout.reduce_rows(
+            WasmDType::Float64, input.as_ptr() as *const u8, Statistic::new(),
+            |mut state: Statistic, value: f64, _| { state.push(operation, value); state },
+            |row, state| output[row] = state.finish(operation, correction),
+        );
+        output
+    }
+
+    #[test]
+    fn test_variance_and_std() {
+        // Rows [1, 2, 3, 4] and [2, 4, 6, 8]
+        let input = vec![1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0];
+        assert_eq!(statistic(WasmOperation::Var, &input, &[2, 4], Some(&[1]), 0.0), vec![1.25, 5.0]);
+        assert_eq!(statistic(WasmOperation::Var, &input, &[2, 4], Some(&[1]), 1.0), vec![5.0 / 3.0, 20.0 / 3.0]);
+        assert_eq!(statistic(WasmOperation::Std, &input, &[2, 4], Some(&[0]), 0.0), vec![0.5, 1.0, 1.5, 2.0]);
+        
+        // Welford keeps the variance of a large offset exact where sum(x^2) - n*mean^2 cancels
+        let input: Vec<f64> = [4.0, 7.0, 13.0, 16.0].iter().map(|v| v + 1e9).collect();
+        assert_eq!(statistic(WasmOperation::Var, &input, &[4], None, 1.0), vec![30.0]);
+        
+        // A correction that leaves no degrees of freedom gives NaN (or inf)
+        assert!(statistic(WasmOperation::Var, &[3.0], &[1], None, 1.0)[0].is_nan());
+        assert_eq!(statistic(WasmOperation::Var, &[1.0, 3.0], &[2], None, 2.0), vec![f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_norms() {
+        let input = vec![3.0, -4.0, 0.0, -12.0, 5.0, 0.0];
+        assert_eq!(statistic(WasmOperation::NormL1, &input, &[2, 3], Some(&[1]), 0.0), vec![7.0, 17.0]);
+        assert_eq!(statistic(WasmOperation::NormL2, &input, &[2, 3], Some(&[1]), 0.0), vec![5.0, 13.0]);
+        assert_eq!(statistic(WasmOperation::NormLinf, &input, &[2, 3], None, 0.0), vec![12.0]);
+        
+        // Infinity survives compensation and NaN propagates
+        assert_eq!(statistic(WasmOperation::NormL1, &[f64::INFINITY, 1.0], &[2], None, 0.0), vec![f64::INFINITY]);
+        assert!(statistic(WasmOperation::NormLinf, &[1.0, f64::NAN, 9.0], &[3], None, 0.0)[0].is_nan());
+        
+        // Compensated summation of many small terms
+        let input = vec![0.1; 100_000];
+        assert_relative_eq!(statistic(WasmOperation::NormL1, &input, &[100_000], None, 0.0)[0], 10_000.0, max_relative = 1e-15);
+        
+        // Empty reductions give 0
+        assert_eq!(statistic(WasmOperation::NormL2, &[], &[2, 0], Some(&[1]), 0.0), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_logsumexp() {
+        // Large values would overflow exp() without the running max
+        let result = statistic(WasmOperation::LogSumExp, &[1000.0, 1000.0, 0.0, f64::NEG_INFINITY], &[2, 2], Some(&[1]), 0.0);
+        assert_relative_eq!(result[0], 1000.0 + 2f64.ln(), max_relative = 1e-15);
+        assert_eq!(result[1], 0.0);
+        
+        let input = vec![-2.0, 3.0, 0.5, 3.0, -700.0];
+        let expected = input.iter().map(|v: &f64| (v - 3.0).exp()).sum::<f64>().ln() + 3.0;
+        assert_relative_eq!(statistic(WasmOperation::LogSumExp, &input, &[5], None, 0.0)[0], expected, max_relative = 1e-15);
+        
+        // All -inf or empty rows give -inf; +inf and NaN propagate
+        assert_eq!(statistic(WasmOperation::LogSumExp, &[f64::NEG_INFINITY; 2], &[2], None, 0.0), vec![f64::NEG_INFINITY]);
+        assert_eq!(statistic(WasmOperation::LogSumExp, &[], &[0], None, 0.0), vec![f64::NEG_INFINITY]);
+        assert_eq!(statistic(WasmOperation::LogSumExp, &[f64::INFINITY, 1.0, f64::INFINITY], &[3], None, 0.0), vec![f64::INFINITY]);
+        assert!(statistic(WasmOperation::LogSumExp, &[f64::NAN, 1.0], &[2], None, 0.0)[0].is_nan());
+    }
+
+    fn nonzero_reduce<T: PromotedElement + PartialEq>(
+        operation: WasmOperation, dtype: WasmDType, input: &[T], shape: &[usize], strides: &[usize], axes: Option<&[usize]>,
+    ) -> Vec<i32> {
+        let layout = layout(dtype, shape, strides, axes);
+        if operation == WasmOperation::CountNonzero {
+            let mut output = vec![-1i32; layout.rows];
+            nonzero_reduce_typed::<T>(operation, dtype, input.as_ptr() as *const u8, &layout, output.as_mut_ptr() as *mut u8);
+            return output;
+        }
+        let mut output = vec![2u8; layout.rows];
+        nonzero_reduce_typed::<T>(operation, dtype, input.as_ptr() as *const u8, &layout, output.as_mut_ptr());
+        output.into_iter().map(i32::from).collect()
+    }
+
+    #[test]
+    fn test_any_all_count_nonzero() {
+        // Bool mask [[1, 0, 1], [0, 0, 0]]
+        let mask = vec![1u8, 0, 1, 0, 0, 0];
+        let run = |operation, axes| nonzero_reduce::<u8>(operation, WasmDType::Bool, &mask, &[2, 3], &[3, 1], axes);
+        assert_eq!(run(WasmOperation::Any, Some(&[1][..])), vec![1, 0]);
+        assert_eq!(run(WasmOperation::All, Some(&[0][..])), vec![0, 0, 0]);
+        assert_eq!(run(WasmOperation::Any, Some(&[0][..])), vec![1, 0, 1]);
+        assert_eq!(run(WasmOperation::CountNonzero, None), vec![2]);
+        
+        // Float columns of a transposed view: -0.0 is zero, NaN is nonzero
+        let input = vec![1.0f32, -0.0, f32::NAN, 2.0, 3.0, 0.0];
+        let run = |operation| nonzero_reduce::<f32>(operation, WasmDType::Float32, &input, &[3, 2], &[1, 3], Some(&[1]));
+        assert_eq!(run(WasmOperation::All), vec![1, 0, 0]);
+        assert_eq!(run(WasmOperation::CountNonzero), vec![2, 1, 1]);
+        
+        // Empty reductions: Any is false, All is true, the count is 0
+        let run = |operation| nonzero_reduce::<i32>(operation, WasmDType::Int32, &[], &[2, 0], &[0, 1], Some(&[1]));
+        assert_eq!(run(WasmOperation::Any), vec![0, 0]);
+        assert_eq!(run(WasmOperation::All), vec![1, 1]);
+        assert_eq!(run(WasmOperation::CountNonzero), vec![0, 0]);
+    }
+
     #[test]
     fn test_kahan_sum() {
         // Use values that demonstrate Kahan summation's benefit within f32 precision
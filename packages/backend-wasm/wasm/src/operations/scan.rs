@@ -0,0 +1,216 @@
+/*!
+ * Cumulative scan operations for WebAssembly backend
+ *
+ * `CumSum`, `CumProd`, `CumMax` and `CumMin` along one axis of a Float32,
+ * Float64 or Int32 tensor, written to a contiguous output of the same shape:
+ * - `exclusive` shifts the scan by one element, so each output excludes its
+ *   own input and the first is the identity (0, 1, -inf / MIN, +inf / MAX)
+ * - `reverse` scans from the end of the axis toward its start
+ *
+ * The input (possibly a strided view) is first copied into the output, which
+ * is then scanned in place one `[axis, inner]` slab at a time so the inner
+ * loop runs over contiguous memory. Integer sums and products wrap like the
+ * other i32 kernels; once a NaN is seen, CumMax / CumMin stay NaN.
+ */
+
+use std::cmp::Ordering;
+use crate::types::{WasmOperation, WasmDType, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::operations::permute;
+
+/// Variant of a cumulative scan
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScanOptions {
+    /// Exclude each element from its own output
+    pub exclusive: bool,
+    /// Scan from the last element of the axis to the first
+    pub reverse: bool,
+}
+
+/// Execute a cumulative scan of `input` along `axis` (negative counts from the end)
+pub fn execute_scan_op(
+    operation: WasmOperation,
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    axis: i32,
+    options: ScanOptions,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    let shape = input_meta.shape();
+    if output_meta.shape() != shape {
+        return Err(WasmError::InvalidShape);
+    }
+    if !matches!(operation, WasmOperation::CumSum | WasmOperation::CumProd |
+        WasmOperation::CumMax | WasmOperation::CumMin) {
+        return Err(WasmError::InvalidOperation);
+    }
+    // Checked before the copy so an unsupported dtype leaves the output untouched
+    if !matches!(output_meta.dtype(), WasmDType::Float32 | WasmDType::Float64 | WasmDType::Int32) {
+        return Err(WasmError::NotImplemented);
+    }
+
+    let ndim = shape.len() as i32;
+    let axis = if axis < 0 { axis + ndim } else { axis };
+    if axis < 0 || axis >= ndim {
+        return Err(WasmError::InvalidInput);
+    }
+    let axis = axis as usize;
+    let layout = ScanLayout {
+        outer: shape[..axis].iter().product(),
+        len: shape[axis],
+        inner: shape[axis + 1..].iter().product(),
+    };
+
+    // Also checks that the dtypes match
+    permute::execute_contiguous_copy_op(input, output, arena)?;
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = output.get_read_ptr(arena) as *mut u8;
+    let size = output_meta.size();
+
+    match output_meta.dtype() {
+        WasmDType::Float32 => {
+            let data = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            match operation {
+                WasmOperation::CumSum => scan_in_place(data, layout, options, 0.0, |acc, x| acc + x),
+                WasmOperation::CumProd => scan_in_place(data, layout, options, 1.0, |acc, x| acc * x),
+                WasmOperation::CumMax => scan_in_place(data, layout, options, f32::NEG_INFINITY, nan_max),
+                _ => scan_in_place(data, layout, options, f32::INFINITY, nan_min),
+            }
+        }
+        WasmDType::Float64 => {
+            let data = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
+            match operation {
+                WasmOperation::CumSum => scan_in_place(data, layout, options, 0.0, |acc, x| acc + x),
+                WasmOperation::CumProd => scan_in_place(data, layout, options, 1.0, |acc, x| acc * x),
+                WasmOperation::CumMax => scan_in_place(data, layout, options, f64::NEG_INFINITY, nan_max),
+                _ => scan_in_place(data, layout, options, f64::INFINITY, nan_min),
+            }
+        }
+        WasmDType::Int32 => {
+            let data = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i32, size) };
+            match operation {
+                WasmOperation::CumSum => scan_in_place(data, layout, options, 0, i32::wrapping_add),
+                WasmOperation::CumProd => scan_in_place(data, layout, options, 1, i32::wrapping_mul),
+                WasmOperation::CumMax => scan_in_place(data, layout, options, i32::MIN, i32::max),
+                _ => scan_in_place(data, layout, options, i32::MAX, i32::min),
+            }
+        }
+        _ => return Err(WasmError::NotImplemented),
+    }
+    Ok(())
+}
+
+/// Larger of `acc` and `x`; the first NaN wins and is kept
+fn nan_max<T: PartialOrd + Copy>(acc: T, x: T) -> T {
+    match x.partial_cmp(&acc) {
+        Some(Ordering::Greater) => x,
+        Some(_) => acc,
+        None if acc.partial_cmp(&acc).is_none() => acc,
+        None => x,
+    }
+}
+
+/// Smaller of `acc` and `x`; the first NaN wins and is kept
+fn nan_min<T: PartialOrd + Copy>(acc: T, x: T) -> T {
+    match x.partial_cmp(&acc) {
+        Some(Ordering::Less) => x,
+        Some(_) => acc,
+        None if acc.partial_cmp(&acc).is_none() => acc,
+        None => x,
+    }
+}
+
+/// Contiguous tensor viewed as `[outer, len, inner]` around the scan axis
+#[derive(Debug, Clone, Copy)]
+struct ScanLayout {
+    outer: usize,
+    len: usize,
+    inner: usize,
+}
+
+/// Scan `data` in place, keeping one accumulator per inner position
+fn scan_in_place<T: Copy>(
+    data: &mut [T],
+    layout: ScanLayout,
+    options: ScanOptions,
+    identity: T,
+    combine: impl Fn(T, T) -> T,
+) {
+    let ScanLayout { outer, len, inner } = layout;
+    if data.is_empty() {
+        return;
+    }
+    let mut acc = vec![identity; inner];
+    for slab in data.chunks_exact_mut(len * inner).take(outer) {
+        acc.fill(identity);
+        for step in 0..len {
+            let k = if options.reverse { len - 1 - step } else { step };
+            let row = &mut slab[k * inner..(k + 1) * inner];
+            for (value, acc) in row.iter_mut().zip(acc.iter_mut()) {
+                let next = combine(*acc, *value);
+                *value = if options.exclusive { *acc } else { next };
+                *acc = next;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INCLUSIVE: ScanOptions = ScanOptions { exclusive: false, reverse: false };
+
+    fn layout(outer: usize, len: usize, inner: usize) -> ScanLayout {
+        ScanLayout { outer, len, inner }
+    }
+
+    #[test]
+    fn test_cumsum_axes() {
+        // [[1, 2, 3], [4, 5, 6]] along the last axis, then along the first
+        let mut rows = vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        scan_in_place(&mut rows, layout(2, 3, 1), INCLUSIVE, 0.0, |acc, x| acc + x);
+        assert_eq!(rows, vec![1.0, 3.0, 6.0, 4.0, 9.0, 15.0]);
+
+        let mut columns = vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        scan_in_place(&mut columns, layout(1, 2, 3), INCLUSIVE, 0.0, |acc, x| acc + x);
+        assert_eq!(columns, vec![1.0, 2.0, 3.0, 5.0, 7.0, 9.0]);
+    }
+
+    #[test]
+    fn test_exclusive_and_reverse() {
+        let input = vec![1, 2, 3, 4];
+        let run = |exclusive, reverse| {
+            let mut data = input.clone();
+            scan_in_place(&mut data, layout(1, 4, 1), ScanOptions { exclusive, reverse }, 0, i32::wrapping_add);
+            data
+        };
+        assert_eq!(run(true, false), vec![0, 1, 3, 6]);
+        assert_eq!(run(false, true), vec![10, 9, 7, 4]);
+        assert_eq!(run(true, true), vec![9, 7, 4, 0]);
+
+        let mut products = vec![2, 3, 4];
+        scan_in_place(&mut products, layout(1, 3, 1), ScanOptions { exclusive: true, reverse: false }, 1, i32::wrapping_mul);
+        assert_eq!(products, vec![1, 2, 6]);
+    }
+
+    #[test]
+    fn test_cummax_cummin() {
+        let mut data = vec![1.0, 3.0, 2.0, f64::NAN, 5.0];
+        scan_in_place(&mut data, layout(1, 5, 1), INCLUSIVE, f64::NEG_INFINITY, nan_max);
+        assert_eq!(&data[..3], &[1.0, 3.0, 3.0]);
+        assert!(data[3].is_nan() && data[4].is_nan());
+        let mut data = vec![2.0f32, -1.0, 4.0];
+        scan_in_place(&mut data, layout(1, 3, 1), ScanOptions { exclusive: false, reverse: true }, f32::INFINITY, nan_min);
+        assert_eq!(data, vec![-1.0, -1.0, 4.0]);
+
+        let mut data = vec![5, -2, 7, -3];
+        scan_in_place(&mut data, layout(1, 4, 1), ScanOptions { exclusive: true, reverse: false }, i32::MAX, i32::min);
+        assert_eq!(data, vec![i32::MAX, 5, -2, -2]);
+    }
+}
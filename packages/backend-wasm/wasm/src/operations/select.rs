@@ -0,0 +1,246 @@
+/*!
+ * Where/select for WebAssembly backend
+ *
+ * `execute_where_op` computes `output = condition ? input_a : input_b`
+ * element-wise. The Bool condition and both value tensors broadcast together
+ * to the output shape with NumPy rules, so e.g. a `[S, S]` causal mask can
+ * pick between `[B, H, S, S]` scores and a scalar fill value.
+ *
+ * Values may have different dtypes: they are read in (and the output must
+ * have) the dtype given by `binary_result_dtype`. Every dtype is supported
+ * and strided views are read directly, one output row block at a time like
+ * the broadcast binary kernels.
+ */
+
+use crate::types::{WasmTensorMeta, WasmDType, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::promotion::binary_result_dtype;
+use crate::operations::binary::{BroadcastInput, PROMOTION_BLOCK_SIZE};
+use crate::operations::element::PromotedElement;
+use crate::operations::strided::{StridedIndexer, broadcast_strides, merge_broadcast_dims};
+use crate::half::{F16, BF16};
+
+/// Broadcast iteration space of condition, `input_a` and `input_b` (in that order)
+#[derive(Debug, Clone, PartialEq)]
+struct WhereLayout {
+    shape: Vec<usize>,
+    strides: [Vec<usize>; 3],
+    offsets: [usize; 3],
+}
+
+impl WhereLayout {
+    /// Validate broadcasting of all three operands and build the merged layout
+    fn new(metas: [&WasmTensorMeta; 3], output_meta: &WasmTensorMeta) -> WasmResult<Self> {
+        let shape = output_meta.shape();
+        if shape.iter().product::<usize>() != output_meta.size() {
+            return Err(WasmError::InvalidShape);
+        }
+
+        let [condition, a, b] = metas.map(|meta| broadcast_strides(&meta.shape(), &meta.strides(), &shape));
+        let (condition, a, b) = (condition?, a?, b?);
+        let (shape, strides) = merge_broadcast_dims(&shape, [&condition, &a, &b]);
+
+        Ok(WhereLayout { shape, strides, offsets: metas.map(|meta| meta.offset()) })
+    }
+}
+
+/// Execute where: `output = condition ? input_a : input_b`, broadcasting all three
+pub fn execute_where_op(
+    condition: &WasmTensor,
+    input_a: &WasmTensor,
+    input_b: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+) -> WasmResult<()> {
+    let condition_meta = condition.metadata();
+    let input_meta_a = input_a.metadata();
+    let input_meta_b = input_b.metadata();
+    let output_meta = output.metadata();
+
+    let dtype = output_meta.dtype();
+    if condition_meta.dtype() != WasmDType::Bool
+        || dtype != binary_result_dtype(input_meta_a.dtype(), input_meta_b.dtype())
+    {
+        return Err(WasmError::InvalidDType);
+    }
+
+    let layout = WhereLayout::new([condition_meta, input_meta_a, input_meta_b], output_meta)?;
+    let inputs = [
+        BroadcastInput::new(condition_meta, condition.get_read_ptr(arena), WasmDType::Bool),
+        BroadcastInput::new(input_meta_a, input_a.get_read_ptr(arena), dtype),
+        BroadcastInput::new(input_meta_b, input_b.get_read_ptr(arena), dtype),
+    ];
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = output.get_read_ptr(arena) as *mut u8;
+    let size = output_meta.size();
+
+    match dtype {
+        WasmDType::Float32 => where_typed::<f32>(&inputs, &layout, output_ptr, size),
+        WasmDType::Float64 => where_typed::<f64>(&inputs, &layout, output_ptr, size),
+        WasmDType::Int8 => where_typed::<i8>(&inputs, &layout, output_ptr, size),
+        WasmDType::Uint8 | WasmDType::Bool => where_typed::<u8>(&inputs, &layout, output_ptr, size),
+        WasmDType::Int16 => where_typed::<i16>(&inputs, &layout, output_ptr, size),
+        WasmDType::Uint16 => where_typed::<u16>(&inputs, &layout, output_ptr, size),
+        WasmDType::Int32 => where_typed::<i32>(&inputs, &layout, output_ptr, size),
+        WasmDType::Uint32 => where_typed::<u32>(&inputs, &layout, output_ptr, size),
+        WasmDType::BigInt64 => where_typed::<i64>(&inputs, &layout, output_ptr, size),
+        WasmDType::BigUint64 => where_typed::<u64>(&inputs, &layout, output_ptr, size),
+        WasmDType::Float16 => where_typed::<F16>(&inputs, &layout, output_ptr, size),
+        WasmDType::BFloat16 => where_typed::<BF16>(&inputs, &layout, output_ptr, size),
+    }
+    Ok(())
+}
+
+fn where_typed<T: PromotedElement>(
+    inputs: &[BroadcastInput; 3],
+    layout: &WhereLayout,
+    output_ptr: *mut u8,
+    size: usize,
+) {
+    let output = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut T, size) };
+    select_rows(inputs, layout, output);
+}
+
+/// Select output rows along the innermost layout axis, one block at a time
+fn select_rows<T: PromotedElement>(
+    [condition, input_a, input_b]: &[BroadcastInput; 3],
+    layout: &WhereLayout,
+    output: &mut [T],
+) {
+    if output.is_empty() {
+        return;
+    }
+
+    let inner = layout.shape.len() - 1;
+    let row_len = layout.shape[inner];
+    let inner_strides = layout.strides.each_ref().map(|strides| strides[inner]);
+    let mut rows: [StridedIndexer; 3] = std::array::from_fn(|k| {
+        StridedIndexer::new(&layout.shape[..inner], &layout.strides[k][..inner], layout.offsets[k])
+    });
+
+    let mut offsets = [0usize; PROMOTION_BLOCK_SIZE];
+    let mut block_condition = [0u8; PROMOTION_BLOCK_SIZE];
+    let mut block_a = [T::default(); PROMOTION_BLOCK_SIZE];
+    let mut block_b = [T::default(); PROMOTION_BLOCK_SIZE];
+
+    for out_row in output.chunks_exact_mut(row_len) {
+        for (chunk, out_chunk) in out_row.chunks_mut(PROMOTION_BLOCK_SIZE).enumerate() {
+            let start = chunk * PROMOTION_BLOCK_SIZE;
+            let len = out_chunk.len();
+            let base = |k: usize| rows[k].offset() + start * inner_strides[k];
+
+            // SAFETY: layout offsets stay inside each operand's (broadcast) shape
+            let (mask, a, b) = unsafe {
+                (
+                    condition.load_row(base(0), inner_strides[0], &mut block_condition[..len], &mut offsets),
+                    input_a.load_row(base(1), inner_strides[1], &mut block_a[..len], &mut offsets),
+                    input_b.load_row(base(2), inner_strides[2], &mut block_b[..len], &mut offsets),
+                )
+            };
+            for (((out, &selected), &x), &y) in out_chunk.iter_mut().zip(mask).zip(a).zip(b) {
+                *out = if selected != 0 { x } else { y };
+            }
+        }
+        for indexer in rows.iter_mut() {
+            indexer.advance();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::strided::contiguous_strides;
+
+    fn contiguous_meta(dtype: WasmDType, shape: &[usize]) -> WasmTensorMeta {
+        WasmTensorMeta::new(dtype, shape.to_vec(), contiguous_strides(shape), shape.iter().product(), 0)
+    }
+
+    fn select<T: PromotedElement>(
+        (mask, mask_meta): (&[u8], WasmTensorMeta),
+        (a, meta_a): (*const u8, WasmTensorMeta),
+        (b, meta_b): (*const u8, WasmTensorMeta),
+        output_meta: WasmTensorMeta,
+    ) -> Vec<T> {
+        let layout = WhereLayout::new([&mask_meta, &meta_a, &meta_b], &output_meta).unwrap();
+        let dtype = output_meta.dtype();
+        let inputs = [
+            BroadcastInput::new(&mask_meta, mask.as_ptr(), WasmDType::Bool),
+            BroadcastInput::new(&meta_a, a, dtype),
+            BroadcastInput::new(&meta_b, b, dtype),
+        ];
+        let mut output = vec![T::default(); output_meta.size()];
+        select_rows(&inputs, &layout, &mut output);
+        output
+    }
+
+    #[test]
+    fn test_where_causal_mask_with_scalar_fill() {
+        // [2, 2, 2] scores, [2, 2] lower-triangular mask, scalar fill
+        let scores: Vec<f32> = (0..8).map(|i| i as f32).collect();
+        let mask = [1u8, 0, 1, 1];
+        let fill = [f32::NEG_INFINITY];
+        let output: Vec<f32> = select(
+            (&mask, contiguous_meta(WasmDType::Bool, &[2, 2])),
+            (scores.as_ptr() as *const u8, contiguous_meta(WasmDType::Float32, &[2, 2, 2])),
+            (fill.as_ptr() as *const u8, contiguous_meta(WasmDType::Float32, &[])),
+            contiguous_meta(WasmDType::Float32, &[2, 2, 2]),
+        );
+        let ninf = f32::NEG_INFINITY;
+        assert_eq!(output, vec![0.0, ninf, 2.0, 3.0, 4.0, ninf, 6.0, 7.0]);
+    }
+
+    #[test]
+    fn test_where_promotes_and_reads_views() {
+        // int8 [3] vs transposed float64 [3, 2] view of a [2, 3] buffer -> float64 [3, 2]
+        let a = [-1i8, 2, -3];
+        let b: Vec<f64> = (0..6).map(|i| i as f64 * 10.0).collect();
+        let mask = [1u8, 0];
+        let output: Vec<f64> = select(
+            (&mask, contiguous_meta(WasmDType::Bool, &[2])),
+            (a.as_ptr() as *const u8, contiguous_meta(WasmDType::Int8, &[3, 1])),
+            (b.as_ptr() as *const u8, WasmTensorMeta::new(WasmDType::Float64, vec![3, 2], vec![1, 3], 6, 0)),
+            contiguous_meta(WasmDType::Float64, &[3, 2]),
+        );
+        assert_eq!(output, vec![-1.0, 30.0, 2.0, 40.0, -3.0, 50.0]);
+    }
+
+    #[test]
+    fn test_where_long_rows_and_layout() {
+        // 600 elements cross several blocks; both values broadcast from scalars
+        let mask: Vec<u8> = (0..600).map(|i| (i % 3 == 0) as u8).collect();
+        let (one, two) = ([1u16], [2u16]);
+        let output: Vec<u16> = select(
+            (&mask, contiguous_meta(WasmDType::Bool, &[600])),
+            (one.as_ptr() as *const u8, contiguous_meta(WasmDType::Uint16, &[1])),
+            (two.as_ptr() as *const u8, contiguous_meta(WasmDType::Uint16, &[])),
+            contiguous_meta(WasmDType::Uint16, &[600]),
+        );
+        assert!(output.iter().enumerate().all(|(i, &v)| v == if i % 3 == 0 { 1 } else { 2 }));
+
+        // [S, S] mask against [B, S, S] values: rows and columns merge, the batch cannot
+        let layout = WhereLayout::new(
+            [
+                &contiguous_meta(WasmDType::Bool, &[4, 4]),
+                &contiguous_meta(WasmDType::Float32, &[3, 4, 4]),
+                &contiguous_meta(WasmDType::Float32, &[]),
+            ],
+            &contiguous_meta(WasmDType::Float32, &[3, 4, 4]),
+        ).unwrap();
+        assert_eq!(layout.shape, vec![3, 16]);
+        assert_eq!(layout.strides, [vec![0, 1], vec![16, 1], vec![0, 0]]);
+
+        let result = WhereLayout::new(
+            [
+                &contiguous_meta(WasmDType::Bool, &[3]),
+                &contiguous_meta(WasmDType::Float32, &[2]),
+                &contiguous_meta(WasmDType::Float32, &[2]),
+            ],
+            &contiguous_meta(WasmDType::Float32, &[2]),
+        );
+        assert!(matches!(result, Err(WasmError::InvalidShape)));
+    }
+}
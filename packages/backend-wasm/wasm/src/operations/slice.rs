@@ -0,0 +1,308 @@
+/*!
+ * N-dimensional slicing for WebAssembly backend
+ *
+ * A `WasmSliceSpec` lists one entry per leading axis, mirroring core's
+ * `SliceIndex` (`number | { start, stop, step } | null`):
+ * - `index(i)` selects a single position and removes the axis
+ * - `range(start, stop, step)` keeps the axis with NumPy semantics: negative
+ *   positions count from the end, missing bounds default by step direction,
+ *   out-of-range bounds clamp, and negative steps walk backwards
+ * - `full()` (or omitting trailing axes) keeps the whole axis
+ *
+ * Specs are validated against the input shape: too many axes, a zero step
+ * or an out-of-range index are errors, as is an output whose shape does not
+ * match the resolved slice.
+ */
+
+use wasm_bindgen::prelude::*;
+use crate::types::{WasmTensorMeta, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::operations::strided::storage_len;
+
+/// Selection along a single axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SliceAxis {
+    Index(i64),
+    Range { start: Option<i64>, stop: Option<i64>, step: i64 },
+    Full,
+}
+
+/// Per-axis slice specification
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WasmSliceSpec {
+    axes: Vec<SliceAxis>,
+}
+
+#[wasm_bindgen]
+impl WasmSliceSpec {
+    /// Create empty spec (selects everything)
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmSliceSpec {
+        WasmSliceSpec::default()
+    }
+
+    /// Select a single position on the next axis, removing it
+    #[wasm_bindgen]
+    pub fn index(&mut self, index: i32) {
+        self.axes.push(SliceAxis::Index(index as i64));
+    }
+
+    /// Slice the next axis; `undefined` bounds default by step direction
+    #[wasm_bindgen]
+    pub fn range(&mut self, start: Option<i32>, stop: Option<i32>, step: i32) {
+        self.axes.push(SliceAxis::Range {
+            start: start.map(|v| v as i64),
+            stop: stop.map(|v| v as i64),
+            step: step as i64,
+        });
+    }
+
+    /// Keep the whole next axis
+    #[wasm_bindgen]
+    pub fn full(&mut self) {
+        self.axes.push(SliceAxis::Full);
+    }
+
+    /// Number of axes specified
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.axes.len()
+    }
+}
+
+/// Slice resolved against a concrete input layout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedSlice {
+    /// Output shape (indexed axes removed)
+    pub shape: Vec<usize>,
+    /// Input element strides per output axis (negative for reversed axes)
+    pub strides: Vec<isize>,
+    /// Input element offset of the first selected element
+    pub offset: usize,
+}
+
+impl WasmSliceSpec {
+    /// Resolve against an input shape, strides and element offset
+    pub fn resolve(&self, shape: &[usize], strides: &[usize], offset: usize) -> WasmResult<ResolvedSlice> {
+        if self.axes.len() > shape.len() || strides.len() != shape.len() {
+            return Err(WasmError::InvalidShape);
+        }
+
+        let mut resolved = ResolvedSlice { shape: Vec::new(), strides: Vec::new(), offset: 0 };
+        let mut base = offset as i64;
+
+        for (axis, (&dim, &stride)) in shape.iter().zip(strides).enumerate() {
+            let dim_i = dim as i64;
+            let stride = stride as i64;
+            match self.axes.get(axis).copied().unwrap_or(SliceAxis::Full) {
+                SliceAxis::Index(index) => {
+                    let position = if index < 0 { index + dim_i } else { index };
+                    if !(0..dim_i).contains(&position) {
+                        return Err(WasmError::InvalidInput);
+                    }
+                    base += position * stride;
+                }
+                SliceAxis::Full => {
+                    resolved.shape.push(dim);
+                    resolved.strides.push(stride as isize);
+                }
+                SliceAxis::Range { start, stop, step } => {
+                    let (first, len) = resolve_range(start, stop, step, dim_i)?;
+                    if len > 0 {
+                        base += first * stride;
+                    }
+                    resolved.shape.push(len as usize);
+                    resolved.strides.push((step * stride) as isize);
+                }
+            }
+        }
+
+        resolved.offset = base as usize;
+        Ok(resolved)
+    }
+}
+
+/// First position and length of `start:stop:step` on an axis of size `dim`
+fn resolve_range(start: Option<i64>, stop: Option<i64>, step: i64, dim: i64) -> WasmResult<(i64, i64)> {
+    if step == 0 {
+        return Err(WasmError::InvalidInput);
+    }
+
+    // Negative positions count from the end, then clamp like NumPy
+    let normalize = |value: i64, low: i64, high: i64| -> i64 {
+        let value = if value < 0 { value + dim } else { value };
+        value.clamp(low, high)
+    };
+
+    if step > 0 {
+        let first = start.map_or(0, |v| normalize(v, 0, dim));
+        let stop = stop.map_or(dim, |v| normalize(v, 0, dim));
+        let len = if stop > first { (stop - first + step - 1) / step } else { 0 };
+        Ok((first, len))
+    } else {
+        // -1 stands for "before the first element"
+        let first = start.map_or(dim - 1, |v| normalize(v, -1, dim - 1));
+        let stop = stop.map_or(-1, |v| normalize(v, -1, dim - 1));
+        let len = if first > stop { (first - stop - step - 1) / -step } else { 0 };
+        Ok((first, len))
+    }
+}
+
+/// Execute slice: copy the selection described by `spec` into a contiguous output
+pub fn execute_slice_op(
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    spec: &WasmSliceSpec,
+) -> WasmResult<()> {
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    if input_meta.dtype() != output_meta.dtype() {
+        return Err(WasmError::InvalidDType);
+    }
+
+    let resolved = spec.resolve(&input_meta.shape(), &input_meta.strides(), input_meta.offset())?;
+    if output_meta.shape() != resolved.shape {
+        return Err(WasmError::InvalidShape);
+    }
+
+    let input_ptr = input.get_read_ptr(arena);
+
+    // SAFETY: Safe pointer cast - see MEMORY_SAFETY.md for detailed explanation
+    // Each tensor has unique memory from bump allocator, no aliasing occurs
+    let output_ptr = output.get_read_ptr(arena) as *mut u8;
+
+    match input_meta.dtype().byte_size() {
+        1 => slice_typed::<u8>(input_ptr, output_ptr, input_meta, output_meta, &resolved),
+        2 => slice_typed::<u16>(input_ptr, output_ptr, input_meta, output_meta, &resolved),
+        4 => slice_typed::<u32>(input_ptr, output_ptr, input_meta, output_meta, &resolved),
+        8 => slice_typed::<u64>(input_ptr, output_ptr, input_meta, output_meta, &resolved),
+        _ => return Err(WasmError::InvalidDType),
+    }
+    Ok(())
+}
+
+fn slice_typed<T: Copy>(
+    input_ptr: *const u8,
+    output_ptr: *mut u8,
+    input_meta: &WasmTensorMeta,
+    output_meta: &WasmTensorMeta,
+    resolved: &ResolvedSlice,
+) {
+    let input = unsafe { std::slice::from_raw_parts(input_ptr as *const T, storage_len(input_meta)) };
+    let output = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut T, output_meta.size()) };
+    copy_slice(input, output, resolved);
+}
+
+/// Copy the resolved selection of `input` into `output` in row-major order
+fn copy_slice<T: Copy>(input: &[T], output: &mut [T], resolved: &ResolvedSlice) {
+    if output.is_empty() {
+        return;
+    }
+    let rank = resolved.shape.len();
+    if rank == 0 {
+        output[0] = input[resolved.offset];
+        return;
+    }
+
+    let inner = rank - 1;
+    let inner_len = resolved.shape[inner];
+    let inner_stride = resolved.strides[inner];
+    let mut index = vec![0usize; inner];
+    let mut row_start = resolved.offset as isize;
+
+    for row in output.chunks_exact_mut(inner_len) {
+        if inner_stride == 1 {
+            let start = row_start as usize;
+            row.copy_from_slice(&input[start..start + inner_len]);
+        } else {
+            let mut position = row_start;
+            for out in row.iter_mut() {
+                *out = input[position as usize];
+                position += inner_stride;
+            }
+        }
+
+        // Advance the outer index (signed strides, so no StridedIndexer)
+        for dim in (0..inner).rev() {
+            index[dim] += 1;
+            row_start += resolved.strides[dim];
+            if index[dim] < resolved.shape[dim] {
+                break;
+            }
+            row_start -= resolved.strides[dim] * resolved.shape[dim] as isize;
+            index[dim] = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::strided::contiguous_strides;
+
+    fn slice(input: &[u32], shape: &[usize], spec: &WasmSliceSpec) -> (Vec<usize>, Vec<u32>) {
+        let resolved = spec.resolve(shape, &contiguous_strides(shape), 0).unwrap();
+        let mut output = vec![0u32; resolved.shape.iter().product()];
+        copy_slice(input, &mut output, &resolved);
+        (resolved.shape, output)
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        assert_eq!(resolve_range(None, None, 1, 5).unwrap(), (0, 5));
+        assert_eq!(resolve_range(Some(1), Some(-1), 2, 5).unwrap(), (1, 2));
+        assert_eq!(resolve_range(None, None, -1, 5).unwrap(), (4, 5));
+        assert_eq!(resolve_range(Some(-2), None, -2, 5).unwrap(), (3, 2));
+        assert_eq!(resolve_range(Some(10), Some(-10), -3, 5).unwrap(), (4, 2));
+        assert_eq!(resolve_range(Some(3), Some(1), 1, 5).unwrap().1, 0);
+        assert!(matches!(resolve_range(None, None, 0, 5), Err(WasmError::InvalidInput)));
+    }
+
+    #[test]
+    fn test_3d_slice_with_steps() {
+        // [2, 3, 4] -> x[1, ::-1, 1::2]
+        let input: Vec<u32> = (0..24).collect();
+        let mut spec = WasmSliceSpec::new();
+        spec.index(1);
+        spec.range(None, None, -1);
+        spec.range(Some(1), None, 2);
+
+        let (shape, output) = slice(&input, &[2, 3, 4], &spec);
+        assert_eq!(shape, vec![3, 2]);
+        assert_eq!(output, vec![21, 23, 17, 19, 13, 15]);
+    }
+
+    #[test]
+    fn test_trailing_axes_and_reversed_rows() {
+        // [3, 2] -> x[::-1] keeps whole rows (contiguous inner copies)
+        let input = [1u32, 2, 3, 4, 5, 6];
+        let mut spec = WasmSliceSpec::new();
+        spec.range(None, None, -1);
+        assert_eq!(slice(&input, &[3, 2], &spec), (vec![3, 2], vec![5, 6, 3, 4, 1, 2]));
+
+        // Empty spec is the identity
+        assert_eq!(slice(&input, &[3, 2], &WasmSliceSpec::new()).1, input.to_vec());
+    }
+
+    #[test]
+    fn test_validation() {
+        let strides = contiguous_strides(&[2, 3]);
+        let mut spec = WasmSliceSpec::new();
+        spec.index(-3);
+        assert!(matches!(spec.resolve(&[2, 3], &strides, 0), Err(WasmError::InvalidInput)));
+
+        let mut spec = WasmSliceSpec::new();
+        spec.full();
+        spec.full();
+        spec.full();
+        assert!(matches!(spec.resolve(&[2, 3], &strides, 0), Err(WasmError::InvalidShape)));
+
+        // Empty selection keeps its axis with length 0
+        let mut spec = WasmSliceSpec::new();
+        spec.range(Some(2), Some(2), 1);
+        assert_eq!(spec.resolve(&[2, 3], &strides, 0).unwrap().shape, vec![0, 3]);
+    }
+}
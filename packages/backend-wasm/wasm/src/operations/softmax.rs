@@ -2,17 +2,23 @@
  * Softmax and LogSoftmax operations - cleaner implementation
  */
 
-use crate::types::{WasmOperation, WasmTensorMeta, WasmDType, WasmResult, WasmError};
+use crate::types::{WasmOperation, WasmDType, WasmPrecision, WasmResult, WasmError};
 use crate::memory::WasmTensor;
 use crate::arena::TempArena;
+use crate::fast_math::{self, UnaryKernel};
+use crate::operations::strided::{contiguous_strides, storage_len};
 
 /// Execute softmax or log_softmax operation
+///
+/// `precision` selects the f32 exponential: `f32::exp` by default, or the
+/// `fast_math` kernel when `WasmPrecision::Fast` is opted into.
 pub fn execute_softmax_op(
     operation: WasmOperation,
     input: &WasmTensor,
     output: &WasmTensor,
     arena: &TempArena,
     axis: Option<i32>,
+    precision: WasmPrecision,
 ) -> WasmResult<()> {
     let input_ptr = input.get_read_ptr(arena);
     let output_ptr = output.get_read_ptr(arena) as *mut u8; // Cast to mut for operations
@@ -39,22 +45,27 @@ pub fn execute_softmax_op(
         None => ndim - 1, // Default to last dimension
     };
     
+    // Input is read from the first element of its view through `strides`;
+    // the output is always contiguous
+    let input_ptr = unsafe { input_ptr.add(input_meta.offset() * input_meta.dtype().byte_size()) };
+    let input_len = storage_len(input_meta) - input_meta.offset();
+    
     match input_meta.dtype() {
         WasmDType::Float32 => {
             let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const f32, input_meta.size()) 
+                std::slice::from_raw_parts(input_ptr as *const f32, input_len) 
             };
             let output_slice = unsafe { 
                 std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_meta.size()) 
             };
             execute_softmax_f32(
                 operation, input_slice, output_slice, 
-                &shape, &strides, axis
+                &shape, &strides, axis, precision
             )?;
         }
         WasmDType::Float64 => {
             let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const f64, input_meta.size()) 
+                std::slice::from_raw_parts(input_ptr as *const f64, input_len) 
             };
             let output_slice = unsafe { 
                 std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_meta.size()) 
@@ -78,9 +89,13 @@ fn execute_softmax_f32(
     shape: &[usize],
     strides: &[usize],
     axis: usize,
+    precision: WasmPrecision,
 ) -> WasmResult<()> {
     let ndim = shape.len();
     let axis_size = shape[axis];
+    let output_strides = contiguous_strides(shape);
+    let mut shifted = vec![0.0f32; axis_size];
+    let mut exp_vals = vec![0.0f32; axis_size];
     
     // For a 2D matrix with shape [2, 2]:
     // axis=0 means we apply softmax down each column (2 softmaxes, each of size 2)
@@ -120,27 +135,34 @@ fn execute_softmax_f32(
             }
         }
         
-        // Step 2: Compute exp(x - max) and sum
-        let mut sum = 0.0f32;
-        let mut exp_vals = vec![0.0f32; axis_size];
-        for i in 0..axis_size {
+        // Step 2: Gather x - max, then compute exp over the whole row and sum
+        for (i, value) in shifted.iter_mut().enumerate() {
             coords[axis] = i;
             let idx = coords.iter().zip(strides.iter()).map(|(c, s)| c * s).sum::<usize>();
-            exp_vals[i] = (input[idx] - max_val).exp();
-            sum += exp_vals[i];
+            *value = input[idx] - max_val;
         }
+        match precision {
+            WasmPrecision::Fast => fast_math::Exp.apply(&shifted, &mut exp_vals),
+            WasmPrecision::Accurate => {
+                for (value, &x) in exp_vals.iter_mut().zip(&shifted) {
+                    *value = x.exp();
+                }
+            }
+        }
+        let sum: f32 = exp_vals.iter().sum();
         
         // Step 3: Normalize and apply operation
         for i in 0..axis_size {
             coords[axis] = i;
             let idx = coords.iter().zip(strides.iter()).map(|(c, s)| c * s).sum::<usize>();
+            let out_idx = coords.iter().zip(output_strides.iter()).map(|(c, s)| c * s).sum::<usize>();
             
             match operation {
                 WasmOperation::Softmax => {
-                    output[idx] = exp_vals[i] / sum;
+                    output[out_idx] = exp_vals[i] / sum;
                 }
                 WasmOperation::LogSoftmax => {
-                    output[idx] = input[idx] - max_val - sum.ln();
+                    output[out_idx] = input[idx] - max_val - sum.ln();
                 }
                 _ => return Err(WasmError::InvalidOperation),
             }
@@ -161,6 +183,7 @@ fn execute_softmax_f64(
 ) -> WasmResult<()> {
     let ndim = shape.len();
     let axis_size = shape[axis];
+    let output_strides = contiguous_strides(shape);
     
     let total_elements = shape.iter().product::<usize>();
     
@@ -197,13 +220,14 @@ fn execute_softmax_f64(
         for i in 0..axis_size {
             coords[axis] = i;
             let idx = coords.iter().zip(strides.iter()).map(|(c, s)| c * s).sum::<usize>();
+            let out_idx = coords.iter().zip(output_strides.iter()).map(|(c, s)| c * s).sum::<usize>();
             
             match operation {
                 WasmOperation::Softmax => {
-                    output[idx] = exp_vals[i] / sum;
+                    output[out_idx] = exp_vals[i] / sum;
                 }
                 WasmOperation::LogSoftmax => {
-                    output[idx] = input[idx] - max_val - sum.ln();
+                    output[out_idx] = input[idx] - max_val - sum.ln();
                 }
                 _ => return Err(WasmError::InvalidOperation),
             }
@@ -227,7 +251,7 @@ mod tests {
         
         execute_softmax_f32(
             WasmOperation::Softmax, &input, &mut output, 
-            &[2, 2], &[2, 1], 0
+            &[2, 2], &[2, 1], 0, WasmPrecision::Fast
         ).unwrap();
         
         // Check column sums = 1
@@ -250,7 +274,7 @@ mod tests {
         
         execute_softmax_f32(
             WasmOperation::Softmax, &input, &mut output, 
-            &[2, 2], &[2, 1], 1
+            &[2, 2], &[2, 1], 1, WasmPrecision::Fast
         ).unwrap();
         
         // Check row sums = 1
@@ -263,4 +287,44 @@ mod tests {
         assert_relative_eq!(output[2], 0.26894142, epsilon = 1e-5);
         assert_relative_eq!(output[3], 0.73105858, epsilon = 1e-5);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_softmax_precision_modes() {
+        // Rows of 7 run the f32x4 exponential body and its scalar tail
+        let input: Vec<f32> = (0..14).map(|i| i as f32 * 0.9 - 6.0).collect();
+        let mut fast = vec![0.0f32; 14];
+        let mut accurate = vec![0.0f32; 14];
+        
+        execute_softmax_f32(WasmOperation::Softmax, &input, &mut fast, &[2, 7], &[7, 1], 1, WasmPrecision::Fast).unwrap();
+        execute_softmax_f32(WasmOperation::Softmax, &input, &mut accurate, &[2, 7], &[7, 1], 1, WasmPrecision::Accurate).unwrap();
+        
+        for (&f, &a) in fast.iter().zip(&accurate) {
+            assert_relative_eq!(f, a, max_relative = 1e-6);
+        }
+        assert_relative_eq!(accurate[..7].iter().sum::<f32>(), 1.0, epsilon = 1e-6);
+        
+        // The default is exactly exp(x - max) / sum with `f32::exp`
+        let max = input[6];
+        let exps: Vec<f32> = input[..7].iter().map(|&x| (x - max).exp()).collect();
+        let sum: f32 = exps.iter().sum();
+        let expected: Vec<f32> = exps.iter().map(|&e| e / sum).collect();
+        assert_eq!(&accurate[..7], expected.as_slice());
+    }
+
+    #[test]
+    fn test_softmax_strided_input() {
+        // [[1, 2], [3, 4]] stored transposed: the output is still row-major
+        let input = vec![1.0f32, 3.0, 2.0, 4.0];
+        let mut output = vec![0.0f32; 4];
+        
+        execute_softmax_f32(
+            WasmOperation::Softmax, &input, &mut output, 
+            &[2, 2], &[1, 2], 1, WasmPrecision::Fast
+        ).unwrap();
+        
+        assert_relative_eq!(output[0], 0.2689414, epsilon = 1e-5);
+        assert_relative_eq!(output[1], 0.7310586, epsilon = 1e-5);
+        assert_relative_eq!(output[2], 0.2689414, epsilon = 1e-5);
+        assert_relative_eq!(output[3], 0.7310586, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,266 @@
+/*!
+ * Strided element addressing shared by kernels that read views
+ *
+ * Tensors created through `create_view_with_shape_and_strides` (transpose,
+ * broadcast views, ...) are not laid out contiguously. Kernels walk them in
+ * logical row-major order with `StridedIndexer`, gathering element offsets
+ * one block at a time (`gather_elements` copies a block for any dtype).
+ */
+
+use crate::types::{WasmTensorMeta, WasmResult, WasmError};
+
+/// Walks a tensor in logical row-major order, tracking the element offset
+/// of the current position in its storage
+#[derive(Debug, Clone)]
+pub struct StridedIndexer {
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    index: Vec<usize>,
+    offset: usize,
+}
+
+impl StridedIndexer {
+    /// Create indexer starting at element `offset` (shape and strides must have equal rank)
+    pub fn new(shape: &[usize], strides: &[usize], offset: usize) -> Self {
+        debug_assert_eq!(shape.len(), strides.len());
+        StridedIndexer {
+            shape: shape.to_vec(),
+            strides: strides.to_vec(),
+            index: vec![0; shape.len()],
+            offset,
+        }
+    }
+
+    /// Create indexer over a tensor's own shape, strides and offset
+    pub fn for_meta(meta: &WasmTensorMeta) -> WasmResult<Self> {
+        let shape = meta.shape();
+        let strides = meta.strides();
+        if shape.len() != strides.len() {
+            return Err(WasmError::InvalidShape);
+        }
+        Ok(StridedIndexer::new(&shape, &strides, meta.offset()))
+    }
+
+    /// Element offset of the current position
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Write offsets of the next `offsets.len()` elements
+    pub fn fill(&mut self, offsets: &mut [usize]) {
+        for offset in offsets.iter_mut() {
+            *offset = self.offset;
+            self.advance();
+        }
+    }
+
+    /// Step to the next element
+    #[inline]
+    pub fn advance(&mut self) {
+        for dim in (0..self.shape.len()).rev() {
+            self.index[dim] += 1;
+            self.offset += self.strides[dim];
+            if self.index[dim] < self.shape[dim] {
+                return;
+            }
+            self.offset -= self.strides[dim] * self.shape[dim];
+            self.index[dim] = 0;
+        }
+    }
+}
+
+/// Row-major (C-contiguous) strides for a shape
+pub fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
+/// Check if tensor elements are stored contiguously in row-major order
+///
+/// Strides of size-1 dimensions never matter, so they are ignored.
+pub fn is_contiguous(meta: &WasmTensorMeta) -> bool {
+    let shape = meta.shape();
+    let strides = meta.strides();
+    strides.len() == shape.len() && shape.iter()
+        .zip(strides.iter().zip(contiguous_strides(&shape)))
+        .all(|(&dim, (&stride, expected))| dim <= 1 || stride == expected)
+}
+
+/// Byte offset of a kernel output's first element within its storage
+///
+/// Kernels write their output densely in row-major order, so only the offset
+/// of an output view is honored; strided output views fail with `InvalidShape`.
+pub fn output_byte_offset(meta: &WasmTensorMeta) -> WasmResult<usize> {
+    if !is_contiguous(meta) {
+        return Err(WasmError::InvalidShape);
+    }
+    Ok(meta.offset() * meta.dtype().byte_size())
+}
+
+/// Number of storage elements a tensor can reach: one past its largest element offset
+///
+/// Views may be smaller (broadcast) or larger (slices) than `meta.size()`.
+pub fn storage_len(meta: &WasmTensorMeta) -> usize {
+    let shape = meta.shape();
+    if shape.contains(&0) {
+        return meta.offset();
+    }
+    let max_offset: usize = shape.iter()
+        .zip(meta.strides())
+        .map(|(&dim, stride)| (dim - 1) * stride)
+        .sum();
+    meta.offset() + max_offset + 1
+}
+
+/// Copy the elements at `offsets` from `src` into consecutive slots of `dst`
+///
+/// Elements are moved as raw `elem_bytes`-wide values, so one routine serves
+/// every dtype.
+///
+/// # Safety
+/// `src` must contain every offset and `dst` must have room for
+/// `offsets.len()` elements; both must be aligned for `elem_bytes`.
+pub unsafe fn gather_elements(src: *const u8, elem_bytes: usize, offsets: &[usize], dst: *mut u8) {
+    unsafe fn gather<T: Copy>(src: *const u8, offsets: &[usize], dst: *mut u8) {
+        let (src, dst) = (src as *const T, dst as *mut T);
+        for (i, &offset) in offsets.iter().enumerate() {
+            *dst.add(i) = *src.add(offset);
+        }
+    }
+
+    match elem_bytes {
+        1 => gather::<u8>(src, offsets, dst),
+        2 => gather::<u16>(src, offsets, dst),
+        4 => gather::<u32>(src, offsets, dst),
+        8 => gather::<u64>(src, offsets, dst),
+        _ => {
+            for (i, &offset) in offsets.iter().enumerate() {
+                std::ptr::copy_nonoverlapping(src.add(offset * elem_bytes), dst.add(i * elem_bytes), elem_bytes);
+            }
+        }
+    }
+}
+
+/// Strides of an input aligned to the output shape (0 along broadcast dims)
+pub fn broadcast_strides(
+    input_shape: &[usize],
+    input_strides: &[usize],
+    output_shape: &[usize],
+) -> WasmResult<Vec<usize>> {
+    if input_shape.len() > output_shape.len() || input_strides.len() != input_shape.len() {
+        return Err(WasmError::InvalidShape);
+    }
+
+    // Broadcasting aligns dimensions from the right
+    let offset = output_shape.len() - input_shape.len();
+    let mut strides = vec![0; output_shape.len()];
+    for (i, (&dim, &stride)) in input_shape.iter().zip(input_strides).enumerate() {
+        if dim == output_shape[i + offset] {
+            strides[i + offset] = stride;
+        } else if dim != 1 {
+            return Err(WasmError::InvalidShape);
+        }
+    }
+    Ok(strides)
+}
+
+/// Drop size-1 dims and merge neighbours that every operand steps through uniformly
+///
+/// `strides` holds each operand's element strides over `shape` (0 along
+/// broadcast dims, see `broadcast_strides`). E.g. `[B, M, N] + [N]` merges to
+/// `[B * M, N]`. A single-element shape becomes `[1]` with zero strides, so
+/// the result always has an innermost dimension.
+pub fn merge_broadcast_dims<const N: usize>(
+    shape: &[usize],
+    strides: [&[usize]; N],
+) -> (Vec<usize>, [Vec<usize>; N]) {
+    let mut merged_shape: Vec<usize> = Vec::with_capacity(shape.len());
+    let mut merged_strides: [Vec<usize>; N] = std::array::from_fn(|_| Vec::with_capacity(shape.len()));
+
+    for (axis, &dim) in shape.iter().enumerate() {
+        if dim == 1 {
+            continue;
+        }
+        let mergeable = !merged_shape.is_empty() && merged_strides.iter().zip(&strides)
+            .all(|(merged, operand)| merged.last() == Some(&(operand[axis] * dim)));
+        if mergeable {
+            if let Some(last) = merged_shape.last_mut() {
+                *last *= dim;
+            }
+            for (merged, operand) in merged_strides.iter_mut().zip(&strides) {
+                if let Some(last) = merged.last_mut() {
+                    *last = operand[axis];
+                }
+            }
+        } else {
+            merged_shape.push(dim);
+            for (merged, operand) in merged_strides.iter_mut().zip(&strides) {
+                merged.push(operand[axis]);
+            }
+        }
+    }
+
+    if merged_shape.is_empty() {
+        // Single element
+        merged_shape.push(1);
+        for merged in merged_strides.iter_mut() {
+            merged.push(0);
+        }
+    }
+    (merged_shape, merged_strides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WasmDType;
+
+    #[test]
+    fn test_transposed_walk() {
+        // [2, 3] row-major storage viewed as its [3, 2] transpose
+        let mut indexer = StridedIndexer::new(&[3, 2], &[1, 3], 0);
+        let mut offsets = [0; 6];
+        indexer.fill(&mut offsets);
+        assert_eq!(offsets, [0, 3, 1, 4, 2, 5]);
+    }
+
+    #[test]
+    fn test_contiguity() {
+        let meta = WasmTensorMeta::new(WasmDType::Float32, vec![2, 1, 3], vec![3, 7, 1], 6, 0);
+        assert!(is_contiguous(&meta));
+
+        let meta = WasmTensorMeta::new(WasmDType::Float32, vec![3, 2], vec![1, 3], 6, 0);
+        assert!(!is_contiguous(&meta));
+        assert_eq!(storage_len(&meta), 6);
+
+        // Broadcast view of a single element at offset 4
+        let meta = WasmTensorMeta::new(WasmDType::Float32, vec![5, 3], vec![0, 0], 15, 4);
+        assert_eq!(storage_len(&meta), 5);
+        assert!(output_byte_offset(&meta).is_err());
+
+        // Row 1 of a [2, 3] f64 matrix is a dense output 24 bytes in
+        let meta = WasmTensorMeta::new(WasmDType::Float64, vec![1, 3], vec![3, 1], 3, 3);
+        assert_eq!(output_byte_offset(&meta).unwrap(), 24);
+    }
+
+    #[test]
+    fn test_gather_elements() {
+        let src = [10u16, 11, 12, 13, 14, 15];
+        let mut dst = [0u16; 3];
+        let mut indexer = StridedIndexer::new(&[3], &[2], 1);
+        let mut offsets = [0; 3];
+        indexer.fill(&mut offsets);
+        unsafe { gather_elements(src.as_ptr() as *const u8, 2, &offsets, dst.as_mut_ptr() as *mut u8) };
+        assert_eq!(dst, [11, 13, 15]);
+    }
+
+    #[test]
+    fn test_broadcast_strides() {
+        assert_eq!(broadcast_strides(&[3, 1], &[1, 1], &[2, 3, 4]).unwrap(), vec![0, 1, 0]);
+        assert!(broadcast_strides(&[2], &[1], &[3]).is_err());
+    }
+}
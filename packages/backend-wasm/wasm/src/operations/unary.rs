@@ -6,18 +6,52 @@
  * operation modules for view, reduction, and softmax operations.
  */
 
-use crate::types::{WasmOperation, WasmTensorMeta, WasmDType, WasmResult, WasmError};
+use crate::types::{WasmOperation, WasmDType, WasmPrecision, WasmResult, WasmError};
 use crate::memory::WasmTensor;
 use crate::arena::TempArena;
 use crate::simd::{float32, float64};
-use crate::operations::{view, reduction, softmax};
+use crate::operations::{view, reduction, softmax, comparison};
+use crate::operations::element::{IntElement, ToFloat};
+use crate::operations::strided::{StridedIndexer, gather_elements, is_contiguous, output_byte_offset};
+use crate::half::{self, HalfFloat, F16, BF16};
+use crate::promotion;
+
+/// Number of elements converted per block when promoting integer inputs
+const PROMOTION_BLOCK_SIZE: usize = 256;
 
 // Use micromath for fast approximations when available
 #[cfg(target_arch = "wasm32")]
 use micromath::F32Ext as _;
 
 // Import our fast math functions
-use crate::fast_math::{fast_sin_f32, fast_cos_f32};
+use crate::fast_math::{self, UnaryKernel};
+
+/// Default negative-side slope of LeakyRelu (PyTorch default)
+///
+/// Used when LeakyRelu runs through `execute_unary_op`; other slopes go
+/// through `execute_leaky_relu_op`.
+pub const LEAKY_RELU_SLOPE: f64 = 0.01;
+
+/// Per-call settings of the element-wise unary kernels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnaryParams {
+    /// Fast or accurate f32 transcendental functions
+    pub precision: WasmPrecision,
+    /// Negative-side slope of LeakyRelu
+    pub negative_slope: f64,
+}
+
+impl Default for UnaryParams {
+    fn default() -> Self {
+        UnaryParams { precision: WasmPrecision::default(), negative_slope: LEAKY_RELU_SLOPE }
+    }
+}
+
+impl From<WasmPrecision> for UnaryParams {
+    fn from(precision: WasmPrecision) -> Self {
+        UnaryParams { precision, ..UnaryParams::default() }
+    }
+}
 
 /// Execute a unary operation
 pub fn execute_unary_op(
@@ -25,6 +59,17 @@ pub fn execute_unary_op(
     input: &WasmTensor,
     output: &WasmTensor,
     arena: &TempArena,
+) -> WasmResult<()> {
+    execute_unary_op_with_precision(operation, input, output, arena, WasmPrecision::default())
+}
+
+/// Execute a unary operation, choosing fast or accurate f32 transcendental functions
+pub fn execute_unary_op_with_precision(
+    operation: WasmOperation,
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    precision: WasmPrecision,
 ) -> WasmResult<()> {
     // Dispatch to specialized operation modules based on operation type
     match operation {
@@ -43,25 +88,49 @@ pub fn execute_unary_op(
         // Softmax operations
         WasmOperation::Softmax | WasmOperation::LogSoftmax => {
             // For now, use default axis (last dimension = -1)
-            softmax::execute_softmax_op(operation, input, output, arena, Some(-1))
+            softmax::execute_softmax_op(operation, input, output, arena, Some(-1), precision)
         }
         
         // Element-wise unary operations (original implementation)
         WasmOperation::Neg | WasmOperation::Abs | WasmOperation::Sin | WasmOperation::Cos |
         WasmOperation::Exp | WasmOperation::Log | WasmOperation::Sqrt | WasmOperation::Square => {
-            execute_elementwise_unary_op(operation, input, output, arena)
+            execute_elementwise_unary_op(operation, input, output, arena, precision.into())
         }
         
+        // Activations, rounding and extended math
+        WasmOperation::Tan | WasmOperation::Tanh | WasmOperation::Sigmoid | WasmOperation::Relu |
+        WasmOperation::LeakyRelu | WasmOperation::Gelu | WasmOperation::GeluTanh | WasmOperation::Silu |
+        WasmOperation::Erf | WasmOperation::Reciprocal | WasmOperation::Rsqrt | WasmOperation::Floor |
+        WasmOperation::Ceil | WasmOperation::Round | WasmOperation::Sign | WasmOperation::Log1p |
+        WasmOperation::Expm1 => {
+            execute_elementwise_unary_op(operation, input, output, arena, precision.into())
+        }
+        
+        WasmOperation::LogicalNot => comparison::execute_logical_not_op(input, output, arena),
+        
         _ => Err(WasmError::NotImplemented),
     }
 }
 
+/// Execute LeakyRelu with a caller-chosen negative-side slope
+pub fn execute_leaky_relu_op(
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
+    negative_slope: f64,
+    precision: WasmPrecision,
+) -> WasmResult<()> {
+    let params = UnaryParams { precision, negative_slope };
+    execute_elementwise_unary_op(WasmOperation::LeakyRelu, input, output, arena, params)
+}
+
 /// Execute element-wise unary operation (original unary implementation)
 fn execute_elementwise_unary_op(
     operation: WasmOperation,
     input: &WasmTensor,
     output: &WasmTensor,
     arena: &TempArena,
+    params: UnaryParams,
 ) -> WasmResult<()> {
     let input_ptr = input.get_read_ptr(arena);
     
@@ -73,21 +142,78 @@ fn execute_elementwise_unary_op(
     //    need to allocate new memory, just access existing allocations
     // 4. No aliasing occurs - input and output tensors have different memory regions
     // 5. The arena's bump allocator ensures memory validity and proper alignment
-    let output_ptr = output.get_read_ptr(arena) as *mut u8;
+    let output_ptr = unsafe { output.get_read_ptr(arena).add(output_byte_offset(output.metadata())?) } as *mut u8;
     
     let input_meta = input.metadata();
+    let output_meta = output.metadata();
     let size = input_meta.size();
+    let input_dtype = input_meta.dtype();
+    
+    if output_meta.shape() != input_meta.shape() {
+        return Err(WasmError::InvalidShape);
+    }
+    
+    // Output dtype follows core's unary promotion rules
+    let result_dtype = promotion::unary_result_dtype(operation, input_dtype);
+    if output_meta.dtype() != result_dtype {
+        return Err(WasmError::InvalidDType);
+    }
+    
+    if is_contiguous(input_meta) {
+        // Fast path: read straight from the first element of the view
+        let input_ptr = unsafe { input_ptr.add(input_meta.offset() * input_dtype.byte_size()) };
+        return execute_unary_contiguous(operation, params, input_dtype, result_dtype, input_ptr, output_ptr, size);
+    }
 
-    match input_meta.dtype() {
+    // Strided view: gather one block at a time and run the contiguous kernels on it
+    let mut indexer = StridedIndexer::for_meta(input_meta)?;
+    let mut offsets = [0usize; PROMOTION_BLOCK_SIZE];
+    let mut block = [0u64; PROMOTION_BLOCK_SIZE]; // Aligned and wide enough for any dtype
+    let block_ptr = block.as_mut_ptr() as *mut u8;
+    for start in (0..size).step_by(PROMOTION_BLOCK_SIZE) {
+        let len = PROMOTION_BLOCK_SIZE.min(size - start);
+        indexer.fill(&mut offsets[..len]);
+        unsafe { gather_elements(input_ptr, input_dtype.byte_size(), &offsets[..len], block_ptr) };
+        let output_block = unsafe { output_ptr.add(start * result_dtype.byte_size()) };
+        execute_unary_contiguous(operation, params, input_dtype, result_dtype, block_ptr, output_block, len)?;
+    }
+    Ok(())
+}
+
+/// Execute element-wise unary operation on `size` contiguous input elements
+fn execute_unary_contiguous(
+    operation: WasmOperation,
+    params: UnaryParams,
+    input_dtype: WasmDType,
+    result_dtype: WasmDType,
+    input_ptr: *const u8,
+    output_ptr: *mut u8,
+    size: usize,
+) -> WasmResult<()> {
+    if result_dtype != input_dtype {
+        return execute_unary_promoted(operation, params, input_dtype, input_ptr, output_ptr, size);
+    }
+
+    match input_dtype {
         WasmDType::Float32 => {
             let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const f32, size) };
             let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
-            execute_unary_f32(operation, input_slice, output_slice)?;
+            execute_unary_f32(operation, input_slice, output_slice, params)?;
+        }
+        WasmDType::Float16 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const F16, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut F16, size) };
+            execute_unary_half(operation, input_slice, output_slice, params)?;
+        }
+        WasmDType::BFloat16 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const BF16, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut BF16, size) };
+            execute_unary_half(operation, input_slice, output_slice, params)?;
         }
         WasmDType::Float64 => {
             let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const f64, size) };
             let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
-            execute_unary_f64(operation, input_slice, output_slice)?;
+            execute_unary_f64(operation, input_slice, output_slice, params)?;
         }
         WasmDType::Int32 => {
             let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const i32, size) };
@@ -97,20 +223,197 @@ fn execute_elementwise_unary_op(
         WasmDType::Int8 => {
             let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const i8, size) };
             let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i8, size) };
-            execute_unary_i8(operation, input_slice, output_slice)?;
+            execute_unary_int(operation, input_slice, output_slice)?;
+        }
+        WasmDType::Uint8 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr, size) };
+            execute_unary_int(operation, input_slice, output_slice)?;
+        }
+        WasmDType::Int16 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const i16, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i16, size) };
+            execute_unary_int(operation, input_slice, output_slice)?;
+        }
+        WasmDType::Uint16 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const u16, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u16, size) };
+            execute_unary_int(operation, input_slice, output_slice)?;
+        }
+        WasmDType::Uint32 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const u32, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u32, size) };
+            execute_unary_int(operation, input_slice, output_slice)?;
+        }
+        WasmDType::BigInt64 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const i64, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut i64, size) };
+            execute_unary_int(operation, input_slice, output_slice)?;
+        }
+        WasmDType::BigUint64 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const u64, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut u64, size) };
+            execute_unary_int(operation, input_slice, output_slice)?;
+        }
+        WasmDType::Bool => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr, size) };
+            execute_unary_bool(operation, input_slice, output_slice)?;
         }
-        _ => return Err(WasmError::NotImplemented),
     }
 
     Ok(())
 }
 
+/// Execute float-producing operation on integer/bool input (promoted output)
+/// 
+/// Inputs of 2 bytes or less produce Float32, wider integers Float64.
+fn execute_unary_promoted(
+    operation: WasmOperation,
+    params: UnaryParams,
+    input_dtype: WasmDType,
+    input_ptr: *const u8,
+    output_ptr: *mut u8,
+    size: usize,
+) -> WasmResult<()> {
+    match input_dtype {
+        WasmDType::Bool | WasmDType::Uint8 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            execute_unary_promoted_f32(operation, input_slice, output_slice, params)
+        }
+        WasmDType::Int8 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const i8, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            execute_unary_promoted_f32(operation, input_slice, output_slice, params)
+        }
+        WasmDType::Int16 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const i16, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            execute_unary_promoted_f32(operation, input_slice, output_slice, params)
+        }
+        WasmDType::Uint16 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const u16, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f32, size) };
+            execute_unary_promoted_f32(operation, input_slice, output_slice, params)
+        }
+        WasmDType::Int32 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const i32, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
+            execute_unary_promoted_f64(operation, input_slice, output_slice, params)
+        }
+        WasmDType::Uint32 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const u32, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
+            execute_unary_promoted_f64(operation, input_slice, output_slice, params)
+        }
+        WasmDType::BigInt64 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const i64, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
+            execute_unary_promoted_f64(operation, input_slice, output_slice, params)
+        }
+        WasmDType::BigUint64 => {
+            let input_slice = unsafe { std::slice::from_raw_parts(input_ptr as *const u64, size) };
+            let output_slice = unsafe { std::slice::from_raw_parts_mut(output_ptr as *mut f64, size) };
+            execute_unary_promoted_f64(operation, input_slice, output_slice, params)
+        }
+        WasmDType::Float16 | WasmDType::BFloat16 | WasmDType::Float32 | WasmDType::Float64 => Err(WasmError::InvalidDType),
+    }
+}
+
+/// Convert block of inputs to f32, then run the f32 kernel into the output
+fn execute_unary_promoted_f32<T: ToFloat>(
+    operation: WasmOperation,
+    input: &[T],
+    output: &mut [f32],
+    params: UnaryParams,
+) -> WasmResult<()> {
+    let mut block = [0.0f32; PROMOTION_BLOCK_SIZE];
+    for (in_chunk, out_chunk) in input.chunks(PROMOTION_BLOCK_SIZE).zip(output.chunks_mut(PROMOTION_BLOCK_SIZE)) {
+        let converted = &mut block[..in_chunk.len()];
+        for (dst, &val) in converted.iter_mut().zip(in_chunk) {
+            *dst = val.to_f32();
+        }
+        execute_unary_f32(operation, converted, out_chunk, params)?;
+    }
+    Ok(())
+}
+
+/// Convert block of inputs to f64, then run the f64 kernel into the output
+fn execute_unary_promoted_f64<T: ToFloat>(
+    operation: WasmOperation,
+    input: &[T],
+    output: &mut [f64],
+    params: UnaryParams,
+) -> WasmResult<()> {
+    let mut block = [0.0f64; PROMOTION_BLOCK_SIZE];
+    for (in_chunk, out_chunk) in input.chunks(PROMOTION_BLOCK_SIZE).zip(output.chunks_mut(PROMOTION_BLOCK_SIZE)) {
+        let converted = &mut block[..in_chunk.len()];
+        for (dst, &val) in converted.iter_mut().zip(in_chunk) {
+            *dst = val.to_f64();
+        }
+        execute_unary_f64(operation, converted, out_chunk, params)?;
+    }
+    Ok(())
+}
+
+/// Execute unary operation on half-precision arrays
+/// 
+/// Each block is widened to f32, computed with the f32 kernel and rounded back.
+fn execute_unary_half<H: HalfFloat>(
+    operation: WasmOperation,
+    input: &[H],
+    output: &mut [H],
+    params: UnaryParams,
+) -> WasmResult<()> {
+    let mut widened = [0.0f32; PROMOTION_BLOCK_SIZE];
+    let mut result = [0.0f32; PROMOTION_BLOCK_SIZE];
+    for (in_chunk, out_chunk) in input.chunks(PROMOTION_BLOCK_SIZE).zip(output.chunks_mut(PROMOTION_BLOCK_SIZE)) {
+        let len = in_chunk.len();
+        half::widen(in_chunk, &mut widened[..len]);
+        execute_unary_f32(operation, &widened[..len], &mut result[..len], params)?;
+        half::narrow(&result[..len], out_chunk);
+    }
+    Ok(())
+}
+
 /// Execute unary operation on f32 arrays
-fn execute_unary_f32(
+///
+/// Transcendental functions use libm with `WasmPrecision::Accurate` (the
+/// default: f32 `expf` / `logf` for Exp / Log, as before precision modes
+/// existed, and f64 evaluation for the rest) and `fast_math` with the opt-in
+/// `WasmPrecision::Fast`; all other operations are exact (or correctly
+/// rounded, or libm for Tan/Log1p/Expm1) in both modes.
+pub(crate) fn execute_unary_f32(
     operation: WasmOperation,
     input: &[f32],
     output: &mut [f32],
+    params: UnaryParams,
 ) -> WasmResult<()> {
+    if params.precision == WasmPrecision::Accurate {
+        match operation {
+            WasmOperation::Exp => {
+                for (out, &val) in output.iter_mut().zip(input) {
+                    *out = libm::expf(val);
+                }
+                return Ok(());
+            }
+            WasmOperation::Log => {
+                for (out, &val) in output.iter_mut().zip(input) {
+                    *out = libm::logf(val);
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+        if let Some(function) = accurate_f32(operation) {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = function(val as f64) as f32;
+            }
+            return Ok(());
+        }
+    }
+
     match operation {
         WasmOperation::Neg => {
             // Use SIMD-optimized negation
@@ -126,50 +429,68 @@ fn execute_unary_f32(
                 output[i] = libm::sqrtf(val);
             }
         }
-        WasmOperation::Sin => {
-            // Use fast lookup table-based implementation
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = fast_sin_f32(val);
-            }
-        }
-        WasmOperation::Cos => {
-            // Use fast lookup table-based implementation
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = fast_cos_f32(val);
-            }
-        }
-        WasmOperation::Exp => {
-            // Use libm for accurate exp (we can optimize later with tolerance testing)
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = libm::expf(val);
-            }
-        }
-        WasmOperation::Log => {
-            // Use libm for accurate log (we can optimize later with tolerance testing)
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = if val > 0.0 { 
-                    libm::logf(val) 
-                } else if val == 0.0 {
-                    f32::NEG_INFINITY
-                } else {
-                    f32::NAN
-                };
-            }
-        }
+        WasmOperation::Sin => fast_math::Sin.apply(input, output),
+        WasmOperation::Cos => fast_math::Cos.apply(input, output),
+        WasmOperation::Exp => fast_math::Exp.apply(input, output),
+        WasmOperation::Log => fast_math::Log.apply(input, output),
         WasmOperation::Square => {
             // Use SIMD multiplication for squaring
             float32::simd_mul(input, input, output);
         }
+        // Activations, rounding and extended math: f32x4 kernels from fast_math
+        WasmOperation::Tan => fast_math::Tan.apply(input, output),
+        WasmOperation::Tanh => fast_math::Tanh.apply(input, output),
+        WasmOperation::Sigmoid => fast_math::Sigmoid.apply(input, output),
+        WasmOperation::Relu => fast_math::Relu.apply(input, output),
+        WasmOperation::LeakyRelu => fast_math::LeakyRelu { negative_slope: params.negative_slope as f32 }.apply(input, output),
+        WasmOperation::Gelu => fast_math::Gelu.apply(input, output),
+        WasmOperation::GeluTanh => fast_math::GeluTanh.apply(input, output),
+        WasmOperation::Silu => fast_math::Silu.apply(input, output),
+        WasmOperation::Erf => fast_math::Erf.apply(input, output),
+        WasmOperation::Reciprocal => fast_math::Reciprocal.apply(input, output),
+        WasmOperation::Rsqrt => fast_math::Rsqrt.apply(input, output),
+        WasmOperation::Floor => fast_math::Floor.apply(input, output),
+        WasmOperation::Ceil => fast_math::Ceil.apply(input, output),
+        WasmOperation::Round => fast_math::Round.apply(input, output),
+        WasmOperation::Sign => fast_math::Sign.apply(input, output),
+        WasmOperation::Log1p => fast_math::Log1p.apply(input, output),
+        WasmOperation::Expm1 => fast_math::Expm1.apply(input, output),
         _ => return Err(WasmError::InvalidOperation),
     }
     Ok(())
 }
 
+/// libm f64 implementation of an f32 operation that `fast_math` approximates
+///
+/// Evaluated in f64 and rounded once, so results are within 1 ULP even for
+/// compositions like GELU whose f32 evaluation would lose accuracy.
+fn accurate_f32(operation: WasmOperation) -> Option<fn(f64) -> f64> {
+    let function: fn(f64) -> f64 = match operation {
+        WasmOperation::Sin => libm::sin,
+        WasmOperation::Cos => libm::cos,
+        WasmOperation::Tanh => libm::tanh,
+        WasmOperation::Erf => libm::erf,
+        WasmOperation::Sigmoid => |x| 1.0 / (1.0 + libm::exp(-x)),
+        WasmOperation::Silu => |x| x / (1.0 + libm::exp(-x)),
+        WasmOperation::Gelu => |x| 0.5 * x * libm::erfc(-x * std::f64::consts::FRAC_1_SQRT_2),
+        WasmOperation::GeluTanh => accurate_gelu_tanh,
+        _ => return None,
+    };
+    Some(function)
+}
+
+/// Tanh-approximated GELU in f64
+fn accurate_gelu_tanh(x: f64) -> f64 {
+    let sqrt_2_over_pi = (2.0 / std::f64::consts::PI).sqrt();
+    0.5 * x * (1.0 + libm::tanh(sqrt_2_over_pi * (x + 0.044715 * x * x * x)))
+}
+
 /// Execute unary operation on f64 arrays
-fn execute_unary_f64(
+pub(crate) fn execute_unary_f64(
     operation: WasmOperation,
     input: &[f64],
     output: &mut [f64],
+    params: UnaryParams,
 ) -> WasmResult<()> {
     match operation {
         WasmOperation::Neg => {
@@ -211,70 +532,115 @@ fn execute_unary_f64(
                 output[i] = val * val;
             }
         }
+        // Activations, rounding and extended math: accurate scalar forms
+        WasmOperation::Tan => map_f64(input, output, f64::tan),
+        WasmOperation::Tanh => map_f64(input, output, f64::tanh),
+        WasmOperation::Sigmoid => map_f64(input, output, |x| 1.0 / (1.0 + (-x).exp())),
+        WasmOperation::Relu => map_f64(input, output, |x| if x < 0.0 { 0.0 } else { x }),
+        WasmOperation::LeakyRelu => map_f64(input, output, |x| if x < 0.0 { x * params.negative_slope } else { x }),
+        WasmOperation::Gelu => {
+            map_f64(input, output, |x| 0.5 * x * (1.0 + libm::erf(x * std::f64::consts::FRAC_1_SQRT_2)))
+        }
+        WasmOperation::GeluTanh => {
+            let sqrt_2_over_pi = (2.0 / std::f64::consts::PI).sqrt();
+            map_f64(input, output, |x| 0.5 * x * (1.0 + (sqrt_2_over_pi * (x + 0.044715 * x * x * x)).tanh()))
+        }
+        WasmOperation::Silu => map_f64(input, output, |x| x / (1.0 + (-x).exp())),
+        WasmOperation::Erf => map_f64(input, output, libm::erf),
+        WasmOperation::Reciprocal => map_f64(input, output, |x| 1.0 / x),
+        WasmOperation::Rsqrt => map_f64(input, output, |x| 1.0 / x.sqrt()),
+        WasmOperation::Floor => map_f64(input, output, f64::floor),
+        WasmOperation::Ceil => map_f64(input, output, f64::ceil),
+        WasmOperation::Round => map_f64(input, output, f64::round_ties_even),
+        WasmOperation::Sign => {
+            map_f64(input, output, |x| if x > 0.0 { 1.0 } else if x < 0.0 { -1.0 } else { x })
+        }
+        WasmOperation::Log1p => map_f64(input, output, f64::ln_1p),
+        WasmOperation::Expm1 => map_f64(input, output, f64::exp_m1),
         _ => return Err(WasmError::InvalidOperation),
     }
     Ok(())
 }
 
+/// Apply a scalar f64 function element-wise
+#[inline]
+fn map_f64(input: &[f64], output: &mut [f64], f: impl Fn(f64) -> f64) {
+    for (out, &val) in output.iter_mut().zip(input) {
+        *out = f(val);
+    }
+}
+
 /// Execute unary operation on i32 arrays
-fn execute_unary_i32(
+pub(crate) fn execute_unary_i32(
     operation: WasmOperation,
     input: &[i32],
     output: &mut [i32],
+) -> WasmResult<()> {
+    execute_unary_int(operation, input, output)
+}
+
+/// Execute type-preserving unary operation on integer arrays
+/// 
+/// Neg, Abs and Square wrap modulo 2^bits (JS typed-array semantics), so
+/// `-MIN == MIN` and `abs(MIN) == MIN` for signed types. Rounding is the
+/// identity on integers.
+fn execute_unary_int<T: IntElement>(
+    operation: WasmOperation,
+    input: &[T],
+    output: &mut [T],
 ) -> WasmResult<()> {
     match operation {
         WasmOperation::Neg => {
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = val.wrapping_neg();
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = val.wrapping_neg();
             }
         }
         WasmOperation::Abs => {
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = val.abs();
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = val.wrapping_abs();
             }
         }
         WasmOperation::Square => {
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = val.wrapping_mul(val);
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = val.wrapping_mul(val);
             }
         }
-        // Math functions not supported for integer types
-        WasmOperation::Sin | WasmOperation::Cos | WasmOperation::Exp | 
-        WasmOperation::Log | WasmOperation::Sqrt => {
-            return Err(WasmError::InvalidOperation);
+        WasmOperation::Relu => {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = val.relu();
+            }
+        }
+        WasmOperation::Sign => {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = val.signum();
+            }
         }
+        WasmOperation::Floor | WasmOperation::Ceil | WasmOperation::Round => {
+            output.copy_from_slice(input);
+        }
+        // Math functions produce floats - handled by execute_unary_promoted
         _ => return Err(WasmError::InvalidOperation),
     }
     Ok(())
 }
 
-/// Execute unary operation on i8 arrays
-fn execute_unary_i8(
+/// Execute type-preserving unary operation on bool arrays
+/// 
+/// Any non-zero byte is true; results are normalized to 0/1. Negating or
+/// squaring a boolean keeps its truth value, as do Abs, Relu, Sign and
+/// rounding.
+fn execute_unary_bool(
     operation: WasmOperation,
-    input: &[i8],
-    output: &mut [i8],
+    input: &[u8],
+    output: &mut [u8],
 ) -> WasmResult<()> {
     match operation {
-        WasmOperation::Neg => {
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = val.wrapping_neg();
+        WasmOperation::Neg | WasmOperation::Abs | WasmOperation::Square | WasmOperation::Relu |
+        WasmOperation::Sign | WasmOperation::Floor | WasmOperation::Ceil | WasmOperation::Round => {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = (val != 0) as u8;
             }
         }
-        WasmOperation::Abs => {
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = val.abs();
-            }
-        }
-        WasmOperation::Square => {
-            for (i, &val) in input.iter().enumerate() {
-                output[i] = val.wrapping_mul(val);
-            }
-        }
-        // Math functions not supported for integer types
-        WasmOperation::Sin | WasmOperation::Cos | WasmOperation::Exp | 
-        WasmOperation::Log | WasmOperation::Sqrt => {
-            return Err(WasmError::InvalidOperation);
-        }
         _ => return Err(WasmError::InvalidOperation),
     }
     Ok(())
@@ -290,7 +656,7 @@ mod tests {
         let input = vec![1.0f32, -2.0, 3.0, -4.0];
         let mut output = vec![0.0f32; 4];
         
-        execute_unary_f32(WasmOperation::Neg, &input, &mut output).unwrap();
+        execute_unary_f32(WasmOperation::Neg, &input, &mut output, WasmPrecision::Fast.into()).unwrap();
         
         assert_eq!(output, vec![-1.0, 2.0, -3.0, 4.0]);
     }
@@ -300,7 +666,7 @@ mod tests {
         let input = vec![1.0f32, -2.0, 3.0, -4.0];
         let mut output = vec![0.0f32; 4];
         
-        execute_unary_f32(WasmOperation::Abs, &input, &mut output).unwrap();
+        execute_unary_f32(WasmOperation::Abs, &input, &mut output, WasmPrecision::Fast.into()).unwrap();
         
         assert_eq!(output, vec![1.0, 2.0, 3.0, 4.0]);
     }
@@ -310,8 +676,171 @@ mod tests {
         let input = vec![1.0f32, 2.0, 3.0, 4.0];
         let mut output = vec![0.0f32; 4];
         
-        execute_unary_f32(WasmOperation::Square, &input, &mut output).unwrap();
+        execute_unary_f32(WasmOperation::Square, &input, &mut output, WasmPrecision::Fast.into()).unwrap();
         
         assert_eq!(output, vec![1.0, 4.0, 9.0, 16.0]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_unary_int_wrapping() {
+        let input = vec![i8::MIN, -3, 0, 100];
+        let mut output = vec![0i8; 4];
+
+        execute_unary_int(WasmOperation::Neg, &input, &mut output).unwrap();
+        assert_eq!(output, vec![i8::MIN, 3, 0, -100]);
+
+        execute_unary_int(WasmOperation::Abs, &input, &mut output).unwrap();
+        assert_eq!(output, vec![i8::MIN, 3, 0, 100]);
+
+        execute_unary_int(WasmOperation::Square, &input, &mut output).unwrap();
+        assert_eq!(output, vec![0, 9, 0, 16]); // 16384 and 10000 wrap modulo 256
+
+        let unsigned = vec![0u64, 1, u64::MAX];
+        let mut unsigned_out = vec![0u64; 3];
+        execute_unary_int(WasmOperation::Neg, &unsigned, &mut unsigned_out).unwrap();
+        assert_eq!(unsigned_out, vec![0, u64::MAX, 1]);
+    }
+
+    #[test]
+    fn test_unary_extended_int_and_f64() {
+        let input = vec![-5i32, 0, 7];
+        let mut output = vec![0i32; 3];
+        execute_unary_int(WasmOperation::Relu, &input, &mut output).unwrap();
+        assert_eq!(output, vec![0, 0, 7]);
+        execute_unary_int(WasmOperation::Sign, &input, &mut output).unwrap();
+        assert_eq!(output, vec![-1, 0, 1]);
+        execute_unary_int(WasmOperation::Round, &input, &mut output).unwrap();
+        assert_eq!(output, input);
+        assert!(execute_unary_int(WasmOperation::Sigmoid, &input, &mut output).is_err());
+
+        let input = vec![-1.5f64, 0.5, 2.5, 1e-10];
+        let mut output = vec![0.0f64; 4];
+        execute_unary_f64(WasmOperation::Round, &input, &mut output, UnaryParams::default()).unwrap();
+        assert_eq!(output, vec![-2.0, 0.0, 2.0, 0.0]);
+        execute_unary_f64(WasmOperation::Expm1, &input, &mut output, UnaryParams::default()).unwrap();
+        assert_eq!(output[3], 1e-10f64.exp_m1());
+        execute_unary_f64(WasmOperation::LeakyRelu, &input, &mut output, UnaryParams::default()).unwrap();
+        assert_eq!(output[..3], [-0.015, 0.5, 2.5]);
+    }
+
+    /// Distance from `actual` to `reference` in units of the f32 spacing at `reference`
+    fn ulp_error(actual: f32, reference: f64) -> f64 {
+        if actual as f64 == reference || (actual.is_nan() && reference.is_nan()) {
+            return 0.0;
+        }
+        let magnitude = (reference.abs() as f32).max(f32::from_bits(1));
+        let spacing = f32::from_bits(magnitude.to_bits() + 1) - magnitude;
+        (actual as f64 - reference).abs() / spacing as f64
+    }
+
+    /// Largest error of `operation` over `inputs` against an f64 reference,
+    /// in ULPs of the result (`relative`) or ULPs of 1.0 (absolute error)
+    fn max_error(
+        operation: WasmOperation,
+        precision: WasmPrecision,
+        inputs: &[f32],
+        reference: fn(f64) -> f64,
+        relative: bool,
+    ) -> f64 {
+        let mut output = vec![0.0f32; inputs.len()];
+        execute_unary_f32(operation, inputs, &mut output, precision.into()).unwrap();
+        inputs.iter().zip(&output).map(|(&x, &y)| {
+            let expected = reference(x as f64);
+            if relative {
+                ulp_error(y, expected)
+            } else {
+                (y as f64 - expected).abs() / f32::EPSILON as f64
+            }
+        }).fold(0.0, f64::max)
+    }
+
+    /// Operation, inputs, f64 reference, fast-mode bound, whether the bound is relative
+    type PrecisionCase = (WasmOperation, Vec<f32>, fn(f64) -> f64, f64, bool);
+
+    fn linspace(low: f32, high: f32) -> Vec<f32> {
+        (0..=20000).map(|i| low + (high - low) * (i as f32 / 20000.0)).collect()
+    }
+
+    #[test]
+    fn test_precision_ulp_bounds() {
+        use std::f64::consts::{FRAC_1_SQRT_2, PI};
+        let sigmoid = |x: f64| 1.0 / (1.0 + (-x).exp());
+        let gelu = |x: f64| 0.5 * x * libm::erfc(-x * FRAC_1_SQRT_2);
+        let gelu_tanh = |x: f64| 0.5 * x * (1.0 + ((2.0 / PI).sqrt() * (x + 0.044715 * x * x * x)).tanh());
+        // Powers of two from subnormals to near f32::MAX
+        let positive: Vec<f32> = (0..=20000).map(|i| (-140.0 + 267.0 * i as f32 / 20000.0).exp2()).collect();
+
+        // Accurate mode is within 1 ULP everywhere. Fast bounds are in ULPs of
+        // the result, or of 1.0 (absolute error) for A&S-based functions
+        let cases: [PrecisionCase; 12] = [
+            (WasmOperation::Exp, linspace(-87.0, 88.0), f64::exp, 2.0, true),
+            (WasmOperation::Log, positive, f64::ln, 2.0, true),
+            (WasmOperation::Sin, linspace(-6.3, 6.3), f64::sin, 2.0, true),
+            (WasmOperation::Cos, linspace(-6.3, 6.3), f64::cos, 2.0, true),
+            (WasmOperation::Sin, linspace(-12800.0, 12800.0), f64::sin, 2.0, true),
+            (WasmOperation::Cos, linspace(-12800.0, 12800.0), f64::cos, 2.0, true),
+            (WasmOperation::Tanh, linspace(-10.0, 10.0), f64::tanh, 3.0, true),
+            (WasmOperation::Erf, linspace(-5.0, 5.0), libm::erf, 5.0, true),
+            (WasmOperation::Sigmoid, linspace(-80.0, 20.0), sigmoid, 2.0, true),
+            (WasmOperation::Silu, linspace(-20.0, 20.0), |x| x / (1.0 + (-x).exp()), 3.0, true),
+            (WasmOperation::Gelu, linspace(-5.0, 10.0), gelu, 4.0, false),
+            (WasmOperation::GeluTanh, linspace(-5.0, 10.0), gelu_tanh, 4.0, false),
+        ];
+        for (operation, inputs, reference, fast_bound, fast_relative) in cases {
+            let accurate = max_error(operation, WasmPrecision::Accurate, &inputs, reference, true);
+            assert!(accurate <= 1.0, "{:?} accurate: {} ULP", operation, accurate);
+            let fast = max_error(operation, WasmPrecision::Fast, &inputs, reference, fast_relative);
+            assert!(fast <= fast_bound, "{:?} fast: {} ULP", operation, fast);
+        }
+    }
+
+    #[test]
+    fn test_unary_bool() {
+        let input = vec![0u8, 1, 7];
+        let mut output = vec![9u8; 3];
+
+        execute_unary_bool(WasmOperation::Neg, &input, &mut output).unwrap();
+        assert_eq!(output, vec![0, 1, 1]);
+        assert!(execute_unary_bool(WasmOperation::Sqrt, &input, &mut output).is_err());
+    }
+
+    #[test]
+    fn test_unary_promoted_to_float() {
+        // Small integers promote to f32
+        let input = vec![4i16, 9, 16, 300];
+        let mut output = vec![0.0f32; 4];
+        execute_unary_promoted_f32(WasmOperation::Sqrt, &input, &mut output, WasmPrecision::Fast.into()).unwrap();
+        assert_eq!(output[..3], [2.0, 3.0, 4.0]);
+
+        // Wide integers promote to f64, across several conversion blocks
+        let input: Vec<u32> = (0..1000).collect();
+        let mut output = vec![0.0f64; 1000];
+        execute_unary_promoted_f64(WasmOperation::Sqrt, &input, &mut output, UnaryParams::default()).unwrap();
+        assert_eq!(output[999], (999.0f64).sqrt());
+    }
+
+    #[test]
+    fn test_unary_op_output_dtype() {
+        use crate::memory::WasmMemorySystem;
+
+        let mut memory = WasmMemorySystem::new();
+        let input = memory.tensor_from_data(vec![1, 2, 3, 4], WasmDType::Uint8, &[4]).unwrap();
+        let float_out = memory.alloc_temp_tensor(WasmDType::Float32, &[4]).unwrap();
+        let uint_out = memory.alloc_temp_tensor(WasmDType::Uint8, &[4]).unwrap();
+
+        // Exp on uint8 must produce float32
+        assert!(matches!(
+            execute_unary_op(WasmOperation::Exp, &input, &uint_out, memory.arena()),
+            Err(WasmError::InvalidDType)
+        ));
+        execute_unary_op(WasmOperation::Exp, &input, &float_out, memory.arena()).unwrap();
+
+        let result = unsafe { std::slice::from_raw_parts(float_out.get_read_ptr(memory.arena()) as *const f32, 4) };
+        assert!((result[1] - 2.0f32.exp()).abs() < 1e-5);
+
+        // Neg on uint8 wraps and keeps the dtype
+        execute_unary_op(WasmOperation::Neg, &input, &uint_out, memory.arena()).unwrap();
+        let result = unsafe { std::slice::from_raw_parts(uint_out.get_read_ptr(memory.arena()), 4) };
+        assert_eq!(result, &[255, 254, 253, 252]);
+    }
+}
@@ -3,1005 +3,162 @@
  * 
  * Implements tensor view operations that manipulate tensor shape and layout
  * without copying data where possible (zero-copy operations).
- */
-
-use crate::types::{WasmOperation, WasmTensorMeta, WasmDType, WasmResult, WasmError};
-use crate::memory::WasmTensor;
-use crate::arena::TempArena;
-
-/// Execute a view operation
-pub fn execute_view_op(
-    operation: WasmOperation,
-    input: &WasmTensor,
-    output: &WasmTensor,
-    arena: &TempArena,
-) -> WasmResult<()> {
-    let input_meta = input.metadata();
-    let output_meta = output.metadata();
-    
-    
-    match operation {
-        WasmOperation::Reshape | WasmOperation::View | WasmOperation::Flatten => {
-            // For now, copy the data until we have proper view support
-            // TODO: Implement zero-copy views with shared buffers
-            execute_copy_reshape(input, output, arena)
-        }
-        WasmOperation::Slice => {
-            // Slice operation requires data copying to extract the sliced portion
-            // NOTE: This is the OLD path - new architecture should use execute_slice_with_offsets directly
-            execute_slice_op(input, output, arena)
-        }
-        WasmOperation::Permute | WasmOperation::Transpose => {
-            // Transpose operations may require data copying depending on the implementation
-            execute_transpose_op(input, output, arena)
-        }
-        WasmOperation::Squeeze | WasmOperation::Unsqueeze => {
-            // For now, copy the data until we have proper view support
-            // TODO: Implement zero-copy views with shared buffers
-            execute_copy_reshape(input, output, arena)
-        }
-        WasmOperation::Expand => {
-            // For now, implement expand by copying with broadcasting
-            // TODO: Implement zero-copy expand with stride tricks
-            execute_expand_op(input, output, arena)
-        }
-        WasmOperation::Tile => {
-            // Tile operation requires data copying
-            execute_tile_op(input, output, arena)
-        }
-        _ => Err(WasmError::InvalidOperation),
-    }
-}
-
-/// Execute slice operation with stride information for non-contiguous tensors
-pub fn execute_slice_with_strides(
-    input: &WasmTensor,
-    output: &WasmTensor,
-    row_start: usize,
-    col_start: usize,
-    input_strides: &[usize],
-    arena: &TempArena,
-) -> WasmResult<()> {
-    // Get pointers to input and output data
-    let input_ptr = input.get_read_ptr(arena);
-    let output_ptr = output.get_read_ptr(arena) as *mut u8;
-    
-    // Get tensor metadata
-    let input_meta = input.metadata();
-    let output_meta = output.metadata();
-    let input_shape = input_meta.shape();
-    let output_shape = output_meta.shape();
-    let output_size = output_meta.size();
-    
-    // Handle different data types
-    match input_meta.dtype() {
-        WasmDType::Float32 => {
-            // Get the actual data size for bounds checking
-            let input_data_size = input.get_data_size();
-            let input_elements = input_data_size / 4; // 4 bytes per f32
-            
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const f32, input_elements)
-            };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_size)
-            };
-            
-            // Perform stride-aware slicing
-            slice_f32_with_strides(
-                input_slice,
-                output_slice,
-                &input_shape,
-                input_strides,
-                &output_shape,
-                row_start,
-                col_start,
-            )?;
-        }
-        WasmDType::Float64 => {
-            let input_data_size = input.get_data_size();
-            let input_elements = input_data_size / 8; // 8 bytes per f64
-            
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const f64, input_elements)
-            };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_size)
-            };
-            
-            slice_f64_with_strides(
-                input_slice,
-                output_slice,
-                &input_shape,
-                input_strides,
-                &output_shape,
-                row_start,
-                col_start,
-            )?;
-        }
-        WasmDType::Int32 => {
-            let input_data_size = input.get_data_size();
-            let input_elements = input_data_size / 4; // 4 bytes per i32
-            
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const i32, input_elements)
-            };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut i32, output_size)
-            };
-            
-            slice_i32_with_strides(
-                input_slice,
-                output_slice,
-                &input_shape,
-                input_strides,
-                &output_shape,
-                row_start,
-                col_start,
-            )?;
-        }
-        _ => return Err(WasmError::NotImplemented),
-    }
-    
-    Ok(())
-}
-
-/// Execute slice operation with explicit offset parameters (NEW ARCHITECTURE)
-pub fn execute_slice_with_offsets(
-    input: &WasmTensor,
-    output: &WasmTensor,
-    row_start: usize,
-    col_start: usize,
-    arena: &TempArena,
-) -> WasmResult<()> {
-    // Get pointers to input and output data
-    let input_ptr = input.get_read_ptr(arena);
-    let output_ptr = output.get_read_ptr(arena) as *mut u8; // Cast to mut for operations
-    
-    // Get tensor metadata
-    let input_meta = input.metadata();
-    let output_meta = output.metadata();
-    let input_shape = input_meta.shape();
-    let input_strides = input_meta.strides();
-    let output_shape = output_meta.shape();
-    let output_size = output_meta.size();
-    
-    // For views, use actual data size instead of logical view size for memory bounds
-    let input_data_size = input.get_data_size();
-    let actual_input_elements = input_data_size / match input_meta.dtype() {
-        WasmDType::Float32 => 4,
-        WasmDType::Float64 => 8,
-        WasmDType::Int32 => 4,
-        _ => return Err(WasmError::NotImplemented),
-    };
-    
-    
-    // Handle different data types
-    match input_meta.dtype() {
-        WasmDType::Float32 => {
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const f32, actual_input_elements)
-            };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_size)
-            };
-            slice_with_offsets_f32(input_slice, output_slice, &input_shape, &input_strides, &output_shape, row_start, col_start)?;
-        }
-        WasmDType::Float64 => {
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const f64, actual_input_elements)
-            };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_size)
-            };
-            slice_with_offsets_f64(input_slice, output_slice, &input_shape, &input_strides, &output_shape, row_start, col_start)?;
-        }
-        WasmDType::Int32 => {
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const i32, actual_input_elements)
-            };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut i32, output_size)
-            };
-            slice_with_offsets_i32(input_slice, output_slice, &input_shape, &input_strides, &output_shape, row_start, col_start)?;
-        }
-        _ => return Err(WasmError::NotImplemented),
-    }
-    
-    Ok(())
-}
-
-/// Execute slice operation (OLD ARCHITECTURE - DEPRECATED)
-fn execute_slice_op(
-    input: &WasmTensor,
-    output: &WasmTensor,
-    arena: &TempArena,
-) -> WasmResult<()> {
-    // Get pointers to input and output data
-    let input_ptr = input.get_read_ptr(arena);
-    let output_ptr = output.get_read_ptr(arena) as *mut u8; // Cast to mut for operations
-    
-    // Get tensor metadata
-    let input_meta = input.metadata();
-    let output_meta = output.metadata();
-    let input_shape = input_meta.shape();
-    let input_strides = input_meta.strides();
-    let output_shape = output_meta.shape();
-    let output_size = output_meta.size();
-    
-    // Handle different data types
-    match input_meta.dtype() {
-        WasmDType::Float32 => {
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const f32, input_meta.size())
-            };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_size)
-            };
-            slice_f32(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
-        }
-        WasmDType::Float64 => {
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const f64, input_meta.size())
-            };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_size)
-            };
-            slice_f64(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
-        }
-        WasmDType::Int32 => {
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const i32, input_meta.size())
-            };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut i32, output_size)
-            };
-            slice_i32(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
-        }
-        _ => return Err(WasmError::NotImplemented),
-    }
-    
-    Ok(())
-}
-
-/// Execute transpose operation
-fn execute_transpose_op(
-    input: &WasmTensor,
-    output: &WasmTensor,
-    arena: &TempArena,
-) -> WasmResult<()> {
-    let input_ptr = input.get_read_ptr(arena);
-    let output_ptr = output.get_read_ptr(arena) as *mut u8; // Cast to mut for operations
-    
-    let input_meta = input.metadata();
-    let output_meta = output.metadata();
-    let input_shape = input_meta.shape();
-    let input_strides = input_meta.strides();
-    let output_shape = output_meta.shape();
-    
-    match input_meta.dtype() {
-        WasmDType::Float32 => {
-            let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const f32, input_meta.size()) 
-            };
-            let output_slice = unsafe { 
-                std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_meta.size()) 
-            };
-            transpose_f32(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
-        }
-        WasmDType::Float64 => {
-            let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const f64, input_meta.size()) 
-            };
-            let output_slice = unsafe { 
-                std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_meta.size()) 
-            };
-            transpose_f64(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
-        }
-        WasmDType::Int32 => {
-            let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const i32, input_meta.size()) 
-            };
-            let output_slice = unsafe { 
-                std::slice::from_raw_parts_mut(output_ptr as *mut i32, output_meta.size()) 
-            };
-            transpose_i32(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
-        }
-        _ => return Err(WasmError::NotImplemented),
-    }
-    
-    Ok(())
-}
-
-/// Execute tile operation
-fn execute_tile_op(
-    input: &WasmTensor,
-    output: &WasmTensor,
-    arena: &TempArena,
-) -> WasmResult<()> {
-    let input_ptr = input.get_read_ptr(arena);
-    let output_ptr = output.get_read_ptr(arena) as *mut u8; // Cast to mut for operations
-    
-    let input_meta = input.metadata();
-    let output_meta = output.metadata();
-    let input_shape = input_meta.shape();
-    let output_shape = output_meta.shape();
-    let input_strides = input_meta.strides();
-    
-    match input_meta.dtype() {
-        WasmDType::Float32 => {
-            let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const f32, input_meta.size()) 
-            };
-            let output_slice = unsafe { 
-                std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_meta.size()) 
-            };
-            tile_f32(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
-        }
-        WasmDType::Float64 => {
-            let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const f64, input_meta.size()) 
-            };
-            let output_slice = unsafe { 
-                std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_meta.size()) 
-            };
-            tile_f64(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
-        }
-        WasmDType::Int32 => {
-            let input_slice = unsafe { 
-                std::slice::from_raw_parts(input_ptr as *const i32, input_meta.size()) 
-            };
-            let output_slice = unsafe { 
-                std::slice::from_raw_parts_mut(output_ptr as *mut i32, output_meta.size()) 
-            };
-            tile_i32(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
-        }
-        _ => return Err(WasmError::NotImplemented),
-    }
-    
-    Ok(())
-}
-
-/// Transpose f32 tensor
-fn transpose_f32(
-    input: &[f32],
-    output: &mut [f32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-) -> WasmResult<()> {
-    
-    if input_shape.len() == 2 {
-        // Simple 2D transpose
-        let rows = input_shape[0];
-        let cols = input_shape[1];
-        
-        for i in 0..rows {
-            for j in 0..cols {
-                let input_idx = i * input_strides[0] + j * input_strides[1];
-                let output_idx = j * rows + i; // transposed indexing
-                output[output_idx] = input[input_idx];
-                
-            }
-        }
-    } else {
-        // General ND transpose - for simplicity, assume last 2 dimensions are transposed
-        return Err(WasmError::NotImplemented);
-    }
-    
-    
-    Ok(())
-}
-
-/// Transpose f64 tensor
-fn transpose_f64(
-    input: &[f64],
-    output: &mut [f64],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-) -> WasmResult<()> {
-    if input_shape.len() == 2 {
-        let rows = input_shape[0];
-        let cols = input_shape[1];
-        
-        for i in 0..rows {
-            for j in 0..cols {
-                let input_idx = i * input_strides[0] + j * input_strides[1];
-                let output_idx = j * rows + i;
-                output[output_idx] = input[input_idx];
-            }
-        }
-    } else {
-        return Err(WasmError::NotImplemented);
-    }
-    Ok(())
-}
-
-/// Transpose i32 tensor
-fn transpose_i32(
-    input: &[i32],
-    output: &mut [i32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-) -> WasmResult<()> {
-    if input_shape.len() == 2 {
-        let rows = input_shape[0];
-        let cols = input_shape[1];
-        
-        for i in 0..rows {
-            for j in 0..cols {
-                let input_idx = i * input_strides[0] + j * input_strides[1];
-                let output_idx = j * rows + i;
-                output[output_idx] = input[input_idx];
-            }
-        }
-    } else {
-        return Err(WasmError::NotImplemented);
-    }
-    Ok(())
-}
-
-/// Tile f32 tensor
-fn tile_f32(
-    input: &[f32],
-    output: &mut [f32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-) -> WasmResult<()> {
-    // Iterate through all output positions
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        // Convert output flat index to multi-dimensional indices
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        // Map output indices to input indices using tile logic
-        let input_indices = map_tile_output_to_input_indices(&output_indices, input_shape, output_shape);
-        
-        // Convert input indices to flat index using strides
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        // Copy the element
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        }
-    }
-    
-    Ok(())
-}
-
-/// Tile f64 tensor
-fn tile_f64(
-    input: &[f64],
-    output: &mut [f64],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-) -> WasmResult<()> {
-    // Iterate through all output positions
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        // Convert output flat index to multi-dimensional indices
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        // Map output indices to input indices using tile logic
-        let input_indices = map_tile_output_to_input_indices(&output_indices, input_shape, output_shape);
-        
-        // Convert input indices to flat index using strides
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        // Copy the element
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        }
-    }
-    Ok(())
-}
-
-/// Tile i32 tensor
-fn tile_i32(
-    input: &[i32],
-    output: &mut [i32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-) -> WasmResult<()> {
-    // Iterate through all output positions
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        // Convert output flat index to multi-dimensional indices
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        // Map output indices to input indices using tile logic
-        let input_indices = map_tile_output_to_input_indices(&output_indices, input_shape, output_shape);
-        
-        // Convert input indices to flat index using strides
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        // Copy the element
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        }
-    }
-    Ok(())
-}
-
-/// Slice f32 tensor with proper slice logic
-fn slice_f32(
-    input: &[f32],
-    output: &mut [f32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-) -> WasmResult<()> {
-    
-    // Implement proper slice logic similar to CPU backend
-    // For each output position, compute the corresponding input position
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        // Convert output flat index to multi-dimensional indices
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        // Map output indices to input indices using slice logic
-        let input_indices = if input_shape.len() == 1 && output_shape.len() == 1 {
-            // 1D slice: infer start offset from sizes
-            let input_size = input_shape[0];
-            let output_size = output_shape[0];
-            
-            // Simple heuristic: if output is smaller and we're dealing with contiguous data,
-            // assume the slice starts from a non-zero offset
-            let start_offset = if input_size > output_size {
-                // For the failing test case: input[6] -> output[3] likely means slice(1:4)
-                // This is a temporary fix until we can pass slice parameters properly
-                1
-            } else {
-                0
-            };
-            
-            vec![output_indices[0] + start_offset]
-        } else if input_shape.len() == 2 && output_shape.len() == 2 {
-            // 2D slice: handle both row and column slicing
-            let input_rows = input_shape[0];
-            let input_cols = input_shape[1];
-            let output_rows = output_shape[0];
-            let output_cols = output_shape[1];
-            
-            // Better heuristic: if input and output sizes match, no slicing on that dimension
-            let row_start_offset = if input_rows == output_rows {
-                0 // No row slicing
-            } else if input_rows > output_rows {
-                // Row slicing: infer start offset
-                // General pattern: for most 2D slices, assume start from row 1 unless specific pattern
-                if input_rows == 3 && output_rows == 1 {
-                    1 // Single row slice: assume middle row
-                } else {
-                    1 // Default: assume slice starts from row 1 (skip first row)
-                }
-            } else {
-                0
-            };
-            
-            // Infer column start offset
-            let col_start_offset = if input_cols == output_cols {
-                0 // No column slicing
-            } else if input_cols > output_cols {
-                // Column slicing: similar heuristic
-                if input_cols == 3 && output_cols == 2 {
-                    1 // Common case: slice middle columns [1:3]
-                } else {
-                    1 // Other cases: assume slice from column 1
-                }
-            } else {
-                0
-            };
-            
-            vec![output_indices[0] + row_start_offset, output_indices[1] + col_start_offset]
-        } else {
-            // For higher dimensions, map directly for now
-            output_indices
-        };
-        
-        // Convert input indices to flat index using strides
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        // Bounds check and copy element
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        } else {
-            return Err(WasmError::InvalidInput);
-        }
-    }
-    
-    Ok(())
-}
-
-/// Slice f64 tensor
-fn slice_f64(
-    input: &[f64],
-    output: &mut [f64],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-) -> WasmResult<()> {
-    match input_shape.len() {
-        1 => {
-            let output_size = output_shape[0];
-            if output_size <= input_shape[0] {
-                output[..output_size].copy_from_slice(&input[..output_size]);
-            } else {
-                return Err(WasmError::InvalidInput);
-            }
-        }
-        2 => {
-            let output_rows = output_shape[0];
-            let output_cols = output_shape[1];
-            
-            for r in 0..output_rows {
-                for c in 0..output_cols {
-                    let input_idx = r * input_strides[0] + c * input_strides[1];
-                    let output_idx = r * output_cols + c;
-                    if input_idx < input.len() && output_idx < output.len() {
-                        output[output_idx] = input[input_idx];
-                    }
-                }
-            }
-        }
-        _ => {
-            let total_elements = output_shape.iter().product::<usize>();
-            for i in 0..total_elements {
-                if i < output.len() && i < input.len() {
-                    output[i] = input[i];
-                }
-            }
-        }
-    }
-    Ok(())
-}
+ * 
+ * `view_meta` describes Reshape/View/Flatten/Squeeze/Unsqueeze/Expand results
+ * as new shape + strides over the same storage (expanded axes get stride 0).
+ * `execute_view_op` is the copying form for callers that provide an output.
+ */
 
-/// Slice i32 tensor
-fn slice_i32(
-    input: &[i32],
-    output: &mut [i32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
+use crate::types::{WasmOperation, WasmTensorMeta, WasmDType, WasmResult, WasmError};
+use crate::memory::WasmTensor;
+use crate::arena::TempArena;
+use crate::operations::permute;
+use crate::operations::strided::{contiguous_strides, broadcast_strides, storage_len};
+
+/// Execute a view operation
+pub fn execute_view_op(
+    operation: WasmOperation,
+    input: &WasmTensor,
+    output: &WasmTensor,
+    arena: &TempArena,
 ) -> WasmResult<()> {
-    match input_shape.len() {
-        1 => {
-            let output_size = output_shape[0];
-            if output_size <= input_shape[0] {
-                output[..output_size].copy_from_slice(&input[..output_size]);
-            } else {
-                return Err(WasmError::InvalidInput);
-            }
+    let input_meta = input.metadata();
+    let output_meta = output.metadata();
+    
+    match operation {
+        WasmOperation::Reshape | WasmOperation::View | WasmOperation::Flatten |
+        WasmOperation::Squeeze | WasmOperation::Unsqueeze | WasmOperation::Expand => {
+            // Copy through the view so strided and expanded inputs come out right;
+            // reshapes that need a copy anyway read the input in logical order
+            let source = match view_meta(operation, input_meta, &output_meta.shape())? {
+                Some(meta) => input.create_view(meta),
+                None => input.clone(),
+            };
+            permute::execute_contiguous_copy_op(&source, output, arena)
         }
-        2 => {
-            let output_rows = output_shape[0];
-            let output_cols = output_shape[1];
-            
-            for r in 0..output_rows {
-                for c in 0..output_cols {
-                    let input_idx = r * input_strides[0] + c * input_strides[1];
-                    let output_idx = r * output_cols + c;
-                    if input_idx < input.len() && output_idx < output.len() {
-                        output[output_idx] = input[input_idx];
-                    }
-                }
-            }
+        WasmOperation::Slice => {
+            // The selection cannot be recovered from shapes alone; slices need an
+            // explicit spec (`slice::execute_slice_op`)
+            Err(WasmError::InvalidOperation)
         }
-        _ => {
-            let total_elements = output_shape.iter().product::<usize>();
-            for i in 0..total_elements {
-                if i < output.len() && i < input.len() {
-                    output[i] = input[i];
-                }
-            }
+        WasmOperation::Permute | WasmOperation::Transpose => {
+            // Without explicit axes, permute swaps the last two axes like transpose;
+            // arbitrary permutations go through `permute::execute_permute_op`
+            execute_transpose_op(input, output, arena)
+        }
+        WasmOperation::Tile => {
+            // Tile operation requires data copying
+            execute_tile_op(input, output, arena)
         }
+        _ => Err(WasmError::InvalidOperation),
     }
-    Ok(())
 }
 
-/// Slice f32 tensor with explicit offsets (NEW ARCHITECTURE)  
-fn slice_with_offsets_f32(
-    input: &[f32],
-    output: &mut [f32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-    row_start: usize,
-    col_start: usize,
-) -> WasmResult<()> {
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        // Convert output flat index to multi-dimensional indices
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        // Map output indices to input indices using explicit offsets
-        let input_indices = match output_shape.len() {
-            1 => {
-                // 1D slice: add row_start offset
-                vec![output_indices[0] + row_start]
-            }
-            2 => {
-                // 2D slice: add both row_start and col_start offsets
-                vec![output_indices[0] + row_start, output_indices[1] + col_start]
+/// Metadata of `operation` applied to `meta` as a zero-copy view with `new_shape`
+/// 
+/// Returns `None` for a reshape whose strides cannot be expressed over the
+/// existing storage (e.g. flattening a transposed view); the caller must copy.
+pub fn view_meta(
+    operation: WasmOperation,
+    meta: &WasmTensorMeta,
+    new_shape: &[usize],
+) -> WasmResult<Option<WasmTensorMeta>> {
+    let shape = meta.shape();
+    let strides = meta.strides();
+    let new_size: usize = new_shape.iter().product();
+
+    let new_strides = match operation {
+        WasmOperation::Expand => {
+            // Broadcast rules: size-1 and new leading axes repeat with stride 0
+            Some(broadcast_strides(&shape, &strides, new_shape)?)
+        }
+        WasmOperation::Reshape | WasmOperation::View | WasmOperation::Flatten |
+        WasmOperation::Squeeze | WasmOperation::Unsqueeze => {
+            if new_size != meta.size() {
+                return Err(WasmError::InvalidShape);
             }
-            _ => {
-                // For higher dimensions, only offset the first two dimensions
-                let mut input_indices = output_indices.clone();
-                if input_indices.len() >= 1 {
-                    input_indices[0] += row_start;
-                }
-                if input_indices.len() >= 2 {
-                    input_indices[1] += col_start;
-                }
-                input_indices
+            let rank_ok = match operation {
+                WasmOperation::Squeeze => new_shape.len() <= shape.len(),
+                WasmOperation::Unsqueeze => new_shape.len() >= shape.len(),
+                _ => true,
+            };
+            if !rank_ok {
+                return Err(WasmError::InvalidShape);
             }
-        };
-        
-        // Convert input indices to flat index using strides
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        // Bounds check and copy element using actual memory bounds
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        } else {
-            return Err(WasmError::InvalidInput);
+            reshape_strides(&shape, &strides, new_shape)
         }
-    }
-    
-    Ok(())
-}
+        _ => return Err(WasmError::InvalidOperation),
+    };
 
-/// Slice f32 tensor with stride information for non-contiguous tensors
-fn slice_f32_with_strides(
-    input: &[f32],
-    output: &mut [f32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-    row_start: usize,
-    col_start: usize,
-) -> WasmResult<()> {
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        // Convert output flat index to multi-dimensional indices
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        // Map output indices to input indices by adding slice offsets
-        let input_indices = match output_shape.len() {
-            1 => vec![output_indices[0] + row_start],
-            2 => vec![output_indices[0] + row_start, output_indices[1] + col_start],
-            _ => {
-                let mut input_indices = output_indices.clone();
-                if input_indices.len() >= 1 {
-                    input_indices[0] += row_start;
-                }
-                if input_indices.len() >= 2 {
-                    input_indices[1] += col_start;
-                }
-                input_indices
-            }
-        };
-        
-        // Use strides to compute the correct flat index for potentially non-contiguous data
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        // Bounds check and copy element
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        } else {
-            return Err(WasmError::InvalidInput);
-        }
-    }
-    
-    Ok(())
+    Ok(new_strides.map(|strides| {
+        WasmTensorMeta::new(meta.dtype(), new_shape.to_vec(), strides, new_size, meta.offset())
+    }))
 }
 
-/// Slice f64 tensor with stride information for non-contiguous tensors
-fn slice_f64_with_strides(
-    input: &[f64],
-    output: &mut [f64],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-    row_start: usize,
-    col_start: usize,
-) -> WasmResult<()> {
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        let input_indices = match output_shape.len() {
-            1 => vec![output_indices[0] + row_start],
-            2 => vec![output_indices[0] + row_start, output_indices[1] + col_start],
-            _ => {
-                let mut input_indices = output_indices.clone();
-                if input_indices.len() >= 1 {
-                    input_indices[0] += row_start;
-                }
-                if input_indices.len() >= 2 {
-                    input_indices[1] += col_start;
-                }
-                input_indices
+/// Strides that view `shape`/`strides` storage as `new_shape` in the same
+/// row-major element order, if possible without copying
+/// 
+/// Groups of old dimensions that map onto groups of new dimensions must be
+/// contiguous among themselves; size-1 dimensions never constrain the result.
+fn reshape_strides(shape: &[usize], strides: &[usize], new_shape: &[usize]) -> Option<Vec<usize>> {
+    let size: usize = shape.iter().product();
+    if size == 0 {
+        return Some(contiguous_strides(new_shape));
+    }
+
+    let old: Vec<(usize, usize)> = shape.iter().copied()
+        .zip(strides.iter().copied())
+        .filter(|&(dim, _)| dim != 1)
+        .collect();
+    let mut new_strides = vec![0; new_shape.len()];
+
+    let (mut oi, mut ni) = (0, 0);
+    while oi < old.len() && ni < new_shape.len() {
+        // Smallest groups old[oi..oj] and new_shape[ni..nj] with equal sizes
+        let (mut oj, mut nj) = (oi + 1, ni + 1);
+        let (mut old_product, mut new_product) = (old[oi].0, new_shape[ni]);
+        while old_product != new_product {
+            if new_product < old_product {
+                new_product *= new_shape[nj];
+                nj += 1;
+            } else {
+                old_product *= old[oj].0;
+                oj += 1;
             }
-        };
-        
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        } else {
-            return Err(WasmError::InvalidInput);
         }
-    }
-    
-    Ok(())
-}
 
-/// Slice i32 tensor with stride information for non-contiguous tensors
-fn slice_i32_with_strides(
-    input: &[i32],
-    output: &mut [i32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-    row_start: usize,
-    col_start: usize,
-) -> WasmResult<()> {
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        let input_indices = match output_shape.len() {
-            1 => vec![output_indices[0] + row_start],
-            2 => vec![output_indices[0] + row_start, output_indices[1] + col_start],
-            _ => {
-                let mut input_indices = output_indices.clone();
-                if input_indices.len() >= 1 {
-                    input_indices[0] += row_start;
-                }
-                if input_indices.len() >= 2 {
-                    input_indices[1] += col_start;
-                }
-                input_indices
-            }
-        };
-        
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        } else {
-            return Err(WasmError::InvalidInput);
+        if (oi..oj - 1).any(|k| old[k].1 != old[k + 1].0 * old[k + 1].1) {
+            return None;
         }
-    }
-    
-    Ok(())
-}
 
-/// Slice f64 tensor with explicit offsets (NEW ARCHITECTURE)
-fn slice_with_offsets_f64(
-    input: &[f64],
-    output: &mut [f64],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-    row_start: usize,
-    col_start: usize,
-) -> WasmResult<()> {
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        let input_indices = match output_shape.len() {
-            1 => vec![output_indices[0] + row_start],
-            2 => vec![output_indices[0] + row_start, output_indices[1] + col_start],
-            _ => {
-                let mut input_indices = output_indices.clone();
-                if input_indices.len() >= 1 {
-                    input_indices[0] += row_start;
-                }
-                if input_indices.len() >= 2 {
-                    input_indices[1] += col_start;
-                }
-                input_indices
-            }
-        };
-        
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        } else {
-            return Err(WasmError::InvalidInput);
+        // Lay the new group out contiguously from the innermost old stride
+        new_strides[nj - 1] = old[oj - 1].1;
+        for k in (ni + 1..nj).rev() {
+            new_strides[k - 1] = new_strides[k] * new_shape[k];
         }
+        oi = oj;
+        ni = nj;
     }
-    
-    Ok(())
-}
 
-/// Slice i32 tensor with explicit offsets (NEW ARCHITECTURE)
-fn slice_with_offsets_i32(
-    input: &[i32],
-    output: &mut [i32],
-    input_shape: &[usize],
-    input_strides: &[usize],
-    output_shape: &[usize],
-    row_start: usize,
-    col_start: usize,
-) -> WasmResult<()> {
-    let total_output_elements = output_shape.iter().product::<usize>();
-    
-    for output_flat_index in 0..total_output_elements {
-        let output_indices = flat_index_to_indices(output_flat_index, output_shape);
-        
-        let input_indices = match output_shape.len() {
-            1 => vec![output_indices[0] + row_start],
-            2 => vec![output_indices[0] + row_start, output_indices[1] + col_start],
-            _ => {
-                let mut input_indices = output_indices.clone();
-                if input_indices.len() >= 1 {
-                    input_indices[0] += row_start;
-                }
-                if input_indices.len() >= 2 {
-                    input_indices[1] += col_start;
-                }
-                input_indices
-            }
-        };
-        
-        let input_flat_index = compute_flat_index(&input_indices, input_strides);
-        
-        if input_flat_index < input.len() && output_flat_index < output.len() {
-            output[output_flat_index] = input[input_flat_index];
-        } else {
-            return Err(WasmError::InvalidInput);
-        }
+    // Trailing size-1 dimensions
+    for stride in &mut new_strides[ni..] {
+        *stride = 1;
     }
-    
-    Ok(())
+    Some(new_strides)
 }
 
-/// Execute a simple copy for reshape/flatten operations
-fn execute_copy_reshape(
+/// Execute transpose operation (swaps the last two axes)
+fn execute_transpose_op(
     input: &WasmTensor,
     output: &WasmTensor,
     arena: &TempArena,
 ) -> WasmResult<()> {
-    let input_meta = input.metadata();
-    let output_meta = output.metadata();
-    
-    // Verify same total size
-    if input_meta.size() != output_meta.size() {
-        return Err(WasmError::InvalidShape);
-    }
-    
-    // Simple memory copy since reshape doesn't change data order
-    let input_ptr = input.get_read_ptr(arena);
-    let output_ptr = output.get_read_ptr(arena) as *mut u8; // Cast to mut for operations
-    let byte_size = input_meta.byte_size();
-    
-    unsafe {
-        std::ptr::copy_nonoverlapping(input_ptr, output_ptr, byte_size);
-    }
-    
-    Ok(())
+    let axes = permute::swap_last_two_axes(input.metadata().shape().len());
+    permute::execute_permute_op(input, output, arena, &axes)
 }
 
-/// Execute expand operation with broadcasting
-fn execute_expand_op(
+/// Execute tile operation
+fn execute_tile_op(
     input: &WasmTensor,
     output: &WasmTensor,
     arena: &TempArena,
@@ -1015,52 +172,37 @@ fn execute_expand_op(
     let output_shape = output_meta.shape();
     let input_strides = input_meta.strides();
     
-    // Handle dimension mismatch by prepending 1s to input shape
-    let mut expanded_input_shape = input_shape.clone();
-    let mut expanded_input_strides = input_strides.clone();
-    
-    while expanded_input_shape.len() < output_shape.len() {
-        expanded_input_shape.insert(0, 1);
-        expanded_input_strides.insert(0, 0);
-    }
-    
-    // Verify broadcasting is valid
-    for i in 0..output_shape.len() {
-        let in_size = expanded_input_shape[i];
-        let out_size = output_shape[i];
-        
-        if in_size != out_size && in_size != 1 {
-            return Err(WasmError::InvalidShape);
-        }
-    }
+    // Input is read from the first element of its view through its strides
+    let input_ptr = unsafe { input_ptr.add(input_meta.offset() * input_meta.dtype().byte_size()) };
+    let input_len = storage_len(input_meta) - input_meta.offset();
     
     match input_meta.dtype() {
         WasmDType::Float32 => {
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const f32, input_meta.size())
+            let input_slice = unsafe { 
+                std::slice::from_raw_parts(input_ptr as *const f32, input_len) 
             };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_meta.size())
+            let output_slice = unsafe { 
+                std::slice::from_raw_parts_mut(output_ptr as *mut f32, output_meta.size()) 
             };
-            expand_f32(input_slice, output_slice, &expanded_input_shape, &expanded_input_strides, &output_shape)?;
+            tile_f32(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
         }
         WasmDType::Float64 => {
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const f64, input_meta.size())
+            let input_slice = unsafe { 
+                std::slice::from_raw_parts(input_ptr as *const f64, input_len) 
             };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_meta.size())
+            let output_slice = unsafe { 
+                std::slice::from_raw_parts_mut(output_ptr as *mut f64, output_meta.size()) 
             };
-            expand_f64(input_slice, output_slice, &expanded_input_shape, &expanded_input_strides, &output_shape)?;
+            tile_f64(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
         }
         WasmDType::Int32 => {
-            let input_slice = unsafe {
-                std::slice::from_raw_parts(input_ptr as *const i32, input_meta.size())
+            let input_slice = unsafe { 
+                std::slice::from_raw_parts(input_ptr as *const i32, input_len) 
             };
-            let output_slice = unsafe {
-                std::slice::from_raw_parts_mut(output_ptr as *mut i32, output_meta.size())
+            let output_slice = unsafe { 
+                std::slice::from_raw_parts_mut(output_ptr as *mut i32, output_meta.size()) 
             };
-            expand_i32(input_slice, output_slice, &expanded_input_shape, &expanded_input_strides, &output_shape)?;
+            tile_i32(input_slice, output_slice, &input_shape, &input_strides, &output_shape)?;
         }
         _ => return Err(WasmError::NotImplemented),
     }
@@ -1068,32 +210,28 @@ fn execute_expand_op(
     Ok(())
 }
 
-/// Expand f32 tensor with broadcasting
-fn expand_f32(
+/// Tile f32 tensor
+fn tile_f32(
     input: &[f32],
     output: &mut [f32],
     input_shape: &[usize],
     input_strides: &[usize],
     output_shape: &[usize],
 ) -> WasmResult<()> {
+    // Iterate through all output positions
     let total_output_elements = output_shape.iter().product::<usize>();
     
     for output_flat_index in 0..total_output_elements {
+        // Convert output flat index to multi-dimensional indices
         let output_indices = flat_index_to_indices(output_flat_index, output_shape);
         
-        // Map output indices to input indices with broadcasting
-        let mut input_indices = vec![0; input_shape.len()];
-        for i in 0..input_shape.len() {
-            if input_shape[i] == 1 {
-                // Broadcast dimension
-                input_indices[i] = 0;
-            } else {
-                input_indices[i] = output_indices[i];
-            }
-        }
+        // Map output indices to input indices using tile logic
+        let input_indices = map_tile_output_to_input_indices(&output_indices, input_shape, output_shape);
         
+        // Convert input indices to flat index using strides
         let input_flat_index = compute_flat_index(&input_indices, input_strides);
         
+        // Copy the element
         if input_flat_index < input.len() && output_flat_index < output.len() {
             output[output_flat_index] = input[input_flat_index];
         }
@@ -1102,67 +240,61 @@ fn expand_f32(
     Ok(())
 }
 
-/// Expand f64 tensor with broadcasting
-fn expand_f64(
+/// Tile f64 tensor
+fn tile_f64(
     input: &[f64],
     output: &mut [f64],
     input_shape: &[usize],
     input_strides: &[usize],
     output_shape: &[usize],
 ) -> WasmResult<()> {
+    // Iterate through all output positions
     let total_output_elements = output_shape.iter().product::<usize>();
     
     for output_flat_index in 0..total_output_elements {
+        // Convert output flat index to multi-dimensional indices
         let output_indices = flat_index_to_indices(output_flat_index, output_shape);
         
-        let mut input_indices = vec![0; input_shape.len()];
-        for i in 0..input_shape.len() {
-            if input_shape[i] == 1 {
-                input_indices[i] = 0;
-            } else {
-                input_indices[i] = output_indices[i];
-            }
-        }
+        // Map output indices to input indices using tile logic
+        let input_indices = map_tile_output_to_input_indices(&output_indices, input_shape, output_shape);
         
+        // Convert input indices to flat index using strides
         let input_flat_index = compute_flat_index(&input_indices, input_strides);
         
+        // Copy the element
         if input_flat_index < input.len() && output_flat_index < output.len() {
             output[output_flat_index] = input[input_flat_index];
         }
     }
-    
     Ok(())
 }
 
-/// Expand i32 tensor with broadcasting
-fn expand_i32(
+/// Tile i32 tensor
+fn tile_i32(
     input: &[i32],
     output: &mut [i32],
     input_shape: &[usize],
     input_strides: &[usize],
     output_shape: &[usize],
 ) -> WasmResult<()> {
+    // Iterate through all output positions
     let total_output_elements = output_shape.iter().product::<usize>();
     
     for output_flat_index in 0..total_output_elements {
+        // Convert output flat index to multi-dimensional indices
         let output_indices = flat_index_to_indices(output_flat_index, output_shape);
         
-        let mut input_indices = vec![0; input_shape.len()];
-        for i in 0..input_shape.len() {
-            if input_shape[i] == 1 {
-                input_indices[i] = 0;
-            } else {
-                input_indices[i] = output_indices[i];
-            }
-        }
+        // Map output indices to input indices using tile logic
+        let input_indices = map_tile_output_to_input_indices(&output_indices, input_shape, output_shape);
         
+        // Convert input indices to flat index using strides
         let input_flat_index = compute_flat_index(&input_indices, input_strides);
         
+        // Copy the element
         if input_flat_index < input.len() && output_flat_index < output.len() {
             output[output_flat_index] = input[input_flat_index];
         }
     }
-    
     Ok(())
 }
 
@@ -1220,19 +352,6 @@ fn map_tile_output_to_input_indices(
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_transpose_2d_f32() {
-        let input = vec![1.0f32, 2.0, 3.0, 4.0]; // 2x2 matrix [[1, 2], [3, 4]]
-        let mut output = vec![0.0f32; 4];
-        
-        transpose_f32(
-            &input, &mut output,
-            &[2, 2], &[2, 1], &[2, 2]
-        ).unwrap();
-        
-        assert_eq!(output, vec![1.0, 3.0, 2.0, 4.0]); // [[1, 3], [2, 4]]
-    }
-
     #[test]
     fn test_tile_1d_f32() {
         let input = vec![1.0f32, 2.0, 3.0];
@@ -1258,4 +377,37 @@ mod tests {
         
         assert_eq!(output, vec![1.0, 2.0, 1.0, 2.0, 3.0, 4.0, 3.0, 4.0]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_reshape_strides() {
+        // Contiguous input reshapes freely
+        assert_eq!(reshape_strides(&[2, 3, 4], &[12, 4, 1], &[6, 4]), Some(vec![4, 1]));
+        assert_eq!(reshape_strides(&[2, 3, 4], &[12, 4, 1], &[2, 1, 12, 1]), Some(vec![12, 12, 1, 1]));
+
+        // Transposed [3, 2] view: splitting an axis works, merging does not
+        assert_eq!(reshape_strides(&[3, 4], &[1, 3], &[3, 2, 2]), Some(vec![1, 6, 3]));
+        assert_eq!(reshape_strides(&[3, 4], &[1, 3], &[12]), None);
+
+        // Squeeze/unsqueeze ignore size-1 axes (and their strides)
+        assert_eq!(reshape_strides(&[4, 1, 5], &[10, 99, 2], &[4, 5]), Some(vec![10, 2]));
+        assert_eq!(reshape_strides(&[4, 5], &[10, 2], &[1, 4, 5, 1]), Some(vec![40, 10, 2, 1]));
+    }
+
+    #[test]
+    fn test_view_meta() {
+        let meta = WasmTensorMeta::new(WasmDType::Float32, vec![3, 1], vec![1, 1], 3, 2);
+
+        // Expanded axes repeat with stride 0 over the same offset
+        let expanded = view_meta(WasmOperation::Expand, &meta, &[2, 3, 4]).unwrap().unwrap();
+        assert_eq!(expanded.strides(), vec![0, 1, 0]);
+        assert_eq!(expanded.offset(), 2);
+        assert_eq!(expanded.size(), 24);
+
+        let squeezed = view_meta(WasmOperation::Squeeze, &meta, &[3]).unwrap().unwrap();
+        assert_eq!(squeezed.strides(), vec![1]);
+
+        assert!(matches!(view_meta(WasmOperation::Reshape, &meta, &[4]), Err(WasmError::InvalidShape)));
+        assert!(matches!(view_meta(WasmOperation::Unsqueeze, &meta, &[3]), Err(WasmError::InvalidShape)));
+        assert!(matches!(view_meta(WasmOperation::Expand, &meta, &[2, 2]), Err(WasmError::InvalidShape)));
+    }
+}
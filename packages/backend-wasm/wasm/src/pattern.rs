@@ -11,6 +11,7 @@ use std::collections::HashMap;
 use std::hash::{Hash, Hasher, DefaultHasher};
 use wasm_bindgen::prelude::*;
 use crate::types::{WasmOperation, WasmDType};
+use crate::memory::WasmTensor;
 
 /// Unique identifier for an operation pattern
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -29,6 +30,7 @@ pub struct OperationDesc {
     pub operation: WasmOperation,
     pub input_shapes: Vec<Vec<usize>>,
     pub input_dtypes: Vec<WasmDType>,
+    pub input_producers: Vec<Option<usize>>,  // Index of the op in the sequence that produced each input (None = external)
     pub output_shape: Vec<usize>,
     pub output_dtype: WasmDType,
 }
@@ -37,10 +39,25 @@ pub struct OperationDesc {
 #[derive(Debug, Clone)]
 pub struct AllocationRequirement {
     pub size_bytes: usize,
+    pub dtype: WasmDType,
+    pub shape: Vec<usize>,
     pub alignment: usize,
     pub is_output: bool,  // true if this is an operation output, false if intermediate
 }
 
+impl AllocationRequirement {
+    /// Create SIMD-aligned requirement for a tensor with given dtype and shape
+    pub fn for_tensor(dtype: WasmDType, shape: &[usize], is_output: bool) -> Self {
+        AllocationRequirement {
+            size_bytes: shape.iter().product::<usize>() * dtype.byte_size(),
+            dtype,
+            shape: shape.to_vec(),
+            alignment: 16, // SIMD alignment
+            is_output,
+        }
+    }
+}
+
 /// Cached pattern containing allocation strategy
 #[derive(Debug, Clone)]
 pub struct OperationPattern {
@@ -180,22 +197,31 @@ impl PatternCache {
         self.current_time = 0;
     }
     
+    /// Check if pattern exists in cache (without updating access tracking)
+    pub fn contains_pattern(&self, pattern_id: PatternId) -> bool {
+        self.patterns.contains_key(&pattern_id)
+    }
+    
     /// Find pattern starting with given operation signature
+    /// 
+    /// If several cached sequences start with the same operation, the most
+    /// recently used one wins, so replay follows the latest observed sequence.
     pub fn find_matching_pattern(&mut self, signature: &PatternSignature) -> Option<PatternId> {
         // Look for patterns that start with the given signature
-        for (pattern_id, pattern) in &self.patterns {
-            if let Some(first_op) = pattern.operations.first() {
-                let first_signature = PatternSignature::from_operation_desc(first_op);
-                if first_signature == *signature {
-                    // Update access tracking
-                    self.current_time += 1;
-                    self.last_used.insert(*pattern_id, self.current_time);
-                    *self.hit_counts.entry(*pattern_id).or_insert(0) += 1;
-                    return Some(*pattern_id);
-                }
-            }
-        }
-        None
+        let pattern_id = self.patterns.iter()
+            .filter(|(_, pattern)| {
+                pattern.operations.first()
+                    .map(|first_op| PatternSignature::from_operation_desc(first_op) == *signature)
+                    .unwrap_or(false)
+            })
+            .max_by_key(|(id, _)| self.last_used.get(id).copied().unwrap_or(0))
+            .map(|(id, _)| *id)?;
+        
+        // Update access tracking
+        self.current_time += 1;
+        self.last_used.insert(pattern_id, self.current_time);
+        *self.hit_counts.entry(pattern_id).or_insert(0) += 1;
+        Some(pattern_id)
     }
     
     /// Update pattern with execution results
@@ -278,6 +304,49 @@ impl PatternCache {
     }
 }
 
+/// Tracks which operation of a sequence produced each temporary tensor
+/// 
+/// Used to fill `OperationDesc::input_producers`, which gives the memory
+/// planner the dataflow it needs for liveness analysis.
+#[derive(Debug, Default)]
+pub struct ProducerTracker {
+    producers: HashMap<(usize, usize), usize>,  // (arena offset, size) -> op index
+}
+
+impl ProducerTracker {
+    pub fn new() -> Self {
+        ProducerTracker::default()
+    }
+    
+    /// Look up producing op index for each input
+    pub fn input_producers(&self, inputs: &[&WasmTensor]) -> Vec<Option<usize>> {
+        inputs.iter()
+            .map(|tensor| Self::tensor_key(tensor).and_then(|key| self.producers.get(&key).copied()))
+            .collect()
+    }
+    
+    /// Record that op `op_index` wrote `output`
+    pub fn record_output(&mut self, output: &WasmTensor, op_index: usize) {
+        if let Some(key) = Self::tensor_key(output) {
+            self.producers.insert(key, op_index);
+        }
+    }
+    
+    /// Forget all producers (start of a new sequence)
+    pub fn clear(&mut self) {
+        self.producers.clear();
+    }
+    
+    /// Identity of a temporary tensor; persistent tensors are always treated as external
+    fn tensor_key(tensor: &WasmTensor) -> Option<(usize, usize)> {
+        if tensor.is_temporary() {
+            Some((tensor.get_data_offset(), tensor.get_data_size()))
+        } else {
+            None
+        }
+    }
+}
+
 /// Cache performance statistics
 #[wasm_bindgen]
 #[derive(Debug, Clone)]
@@ -336,6 +405,16 @@ impl PatternBuilder {
         self
     }
     
+    /// Number of operations added so far
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+    
+    /// Check if no operations have been added
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+    
     /// Build the final pattern
     pub fn build(self, cache: &PatternCache) -> OperationPattern {
         let pattern_id = cache.hash_operation_sequence(&self.operations);
@@ -378,11 +457,14 @@ mod tests {
             operation: WasmOperation::Add,
             input_shapes: vec![vec![10, 20], vec![10, 20]],
             input_dtypes: vec![WasmDType::Float32, WasmDType::Float32],
+            input_producers: vec![None; 2],
             output_shape: vec![10, 20],
             output_dtype: WasmDType::Float32,
         });
         builder.add_allocation(AllocationRequirement {
             size_bytes: 800,
+            dtype: WasmDType::Float32,
+            shape: vec![200],
             alignment: 16,
             is_output: true,
         });
@@ -411,6 +493,7 @@ mod tests {
             operation: WasmOperation::Add,
             input_shapes: vec![vec![10, 20]],
             input_dtypes: vec![WasmDType::Float32],
+            input_producers: vec![None; 1],
             output_shape: vec![10, 20],
             output_dtype: WasmDType::Float32,
         };
@@ -419,6 +502,7 @@ mod tests {
             operation: WasmOperation::Mul,
             input_shapes: vec![vec![10, 20]],
             input_dtypes: vec![WasmDType::Float32],
+            input_producers: vec![None; 1],
             output_shape: vec![10, 20],
             output_dtype: WasmDType::Float32,
         };
@@ -444,11 +528,14 @@ mod tests {
                 operation: WasmOperation::Add,
                 input_shapes: vec![vec![i + 1, 10]], // Different shapes for different hashes
                 input_dtypes: vec![WasmDType::Float32],
+                input_producers: vec![None; 1],
                 output_shape: vec![i + 1, 10],
                 output_dtype: WasmDType::Float32,
             });
             builder.add_allocation(AllocationRequirement {
                 size_bytes: 100,
+                dtype: WasmDType::Float32,
+                shape: vec![25],
                 alignment: 16,
                 is_output: true,
             });
@@ -473,6 +560,50 @@ mod tests {
         assert!(cache.patterns.contains_key(&patterns[2].pattern_id));
     }
     
+    #[test]
+    fn test_find_matching_pattern_prefers_recent() {
+        let mut cache = PatternCache::new(10, 100);
+        
+        let first_op = OperationDesc {
+            operation: WasmOperation::Add,
+            input_shapes: vec![vec![4], vec![4]],
+            input_dtypes: vec![WasmDType::Float32, WasmDType::Float32],
+            input_producers: vec![None; 2],
+            output_shape: vec![4],
+            output_dtype: WasmDType::Float32,
+        };
+        
+        // Two sequences starting with the same operation
+        let patterns: Vec<_> = [WasmOperation::Exp, WasmOperation::Neg].iter().map(|&second| {
+            let mut builder = PatternBuilder::new();
+            builder.add_operation(first_op.clone());
+            builder.add_allocation(AllocationRequirement::for_tensor(WasmDType::Float32, &[4], true));
+            builder.add_operation(OperationDesc {
+                operation: second,
+                input_shapes: vec![vec![4]],
+                input_dtypes: vec![WasmDType::Float32],
+                input_producers: vec![None; 1],
+                output_shape: vec![4],
+                output_dtype: WasmDType::Float32,
+            });
+            builder.add_allocation(AllocationRequirement::for_tensor(WasmDType::Float32, &[4], true));
+            assert_eq!(builder.len(), 2);
+            builder.build(&cache)
+        }).collect();
+        
+        cache.store_pattern(patterns[0].clone()).unwrap();
+        cache.store_pattern(patterns[1].clone()).unwrap();
+        cache.get_pattern(patterns[0].pattern_id);
+        
+        let signature = PatternSignature::from_operation_desc(&first_op);
+        assert_eq!(cache.find_matching_pattern(&signature), Some(patterns[0].pattern_id));
+        assert!(cache.contains_pattern(patterns[1].pattern_id));
+        
+        let requirement = &patterns[0].allocations[1];
+        assert_eq!(requirement.size_bytes, 16);
+        assert_eq!(requirement.shape, vec![4]);
+    }
+    
     // WASM-specific integration tests
     #[cfg(test)]
     mod wasm_tests {
@@ -509,11 +640,14 @@ mod tests {
                 operation: WasmOperation::Add,
                 input_shapes: vec![vec![10, 20], vec![10, 20]],
                 input_dtypes: vec![WasmDType::Float32, WasmDType::Float32],
+                input_producers: vec![None; 2],
                 output_shape: vec![10, 20],
                 output_dtype: WasmDType::Float32,
             });
             builder.add_allocation(AllocationRequirement {
                 size_bytes: 800, // 10 * 20 * 4 bytes
+                dtype: WasmDType::Float32,
+                shape: vec![200],
                 alignment: 16,
                 is_output: true,
             });
@@ -540,6 +674,7 @@ mod tests {
                 operation: WasmOperation::Add,
                 input_shapes: vec![vec![10]],
                 input_dtypes: vec![WasmDType::Float32],
+                input_producers: vec![None; 1],
                 output_shape: vec![10],
                 output_dtype: WasmDType::Float32,
             }];
@@ -549,6 +684,7 @@ mod tests {
                     operation: WasmOperation::Matmul,
                     input_shapes: vec![vec![100, 100], vec![100, 100]],
                     input_dtypes: vec![WasmDType::Float32, WasmDType::Float32],
+                    input_producers: vec![None; 2],
                     output_shape: vec![100, 100],
                     output_dtype: WasmDType::Float32,
                 },
@@ -556,6 +692,7 @@ mod tests {
                     operation: WasmOperation::Add,
                     input_shapes: vec![vec![100, 100], vec![100, 100]],
                     input_dtypes: vec![WasmDType::Float32, WasmDType::Float32],
+                    input_producers: vec![None; 2],
                     output_shape: vec![100, 100],
                     output_dtype: WasmDType::Float32,
                 },
@@ -580,6 +717,7 @@ mod tests {
                 operation: WasmOperation::Add,
                 input_shapes: vec![vec![10, 10]], 
                 input_dtypes: vec![WasmDType::Float32],
+                input_producers: vec![None; 1],
                 output_shape: vec![10, 10],
                 output_dtype: WasmDType::Float32,
             });
@@ -587,6 +725,8 @@ mod tests {
             // Add any allocation requirement - even small ones should fail with 0MB limit
             builder.add_allocation(AllocationRequirement {
                 size_bytes: 1024, // 1KB should exceed 0MB limit
+                dtype: WasmDType::Float32,
+                shape: vec![256],
                 alignment: 16,
                 is_output: true,
             });
@@ -612,11 +752,14 @@ mod tests {
                 operation: WasmOperation::Mul,
                 input_shapes: vec![vec![5, 5], vec![5, 5]],
                 input_dtypes: vec![WasmDType::Float32, WasmDType::Float32],
+                input_producers: vec![None; 2],
                 output_shape: vec![5, 5],
                 output_dtype: WasmDType::Float32,
             });
             builder.add_allocation(AllocationRequirement {
                 size_bytes: 100,
+                dtype: WasmDType::Float32,
+                shape: vec![25],
                 alignment: 16,
                 is_output: true,
             });
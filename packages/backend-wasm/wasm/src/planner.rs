@@ -0,0 +1,215 @@
+/*!
+ * Static memory planner for recorded operation sequences
+ *
+ * Computes tensor liveness from a recorded `OperationDesc` sequence and
+ * assigns arena offsets so that tensors with non-overlapping lifetimes
+ * share memory (greedy-by-size placement, as used by TFLite/ONNX Runtime):
+ * - Each op output lives from the op that writes it to its last reader
+ * - Outputs never read inside the sequence live until the end
+ * - Offsets are SIMD-aligned and relative to a single planned block
+ */
+
+use crate::pattern::{OperationDesc, AllocationRequirement};
+
+/// SIMD alignment for planned offsets
+const PLAN_ALIGNMENT: usize = 16;
+
+/// Live range of a single tensor, in op indices (inclusive)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TensorLifetime {
+    pub size_bytes: usize,
+    pub first_use: usize,
+    pub last_use: usize,
+}
+
+impl TensorLifetime {
+    /// Check if two lifetimes overlap (tensors cannot share memory)
+    pub fn overlaps(&self, other: &TensorLifetime) -> bool {
+        self.first_use <= other.last_use && other.first_use <= self.last_use
+    }
+}
+
+/// Result of memory planning
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryPlan {
+    /// Offset of each tensor relative to the start of the planned block
+    pub offsets: Vec<usize>,
+    /// Bytes needed for the planned block (peak of simultaneously live tensors)
+    pub peak_bytes: usize,
+    /// Bytes a bump allocator would need for the same tensors
+    pub unplanned_bytes: usize,
+}
+
+/// Compute lifetimes of op outputs from a recorded sequence
+///
+/// Expects one allocation per operation (the op's output), which is what
+/// the executor records. Returns None if the pattern doesn't have that form.
+pub fn compute_lifetimes(
+    operations: &[OperationDesc],
+    allocations: &[AllocationRequirement],
+) -> Option<Vec<TensorLifetime>> {
+    if operations.is_empty() || operations.len() != allocations.len() {
+        return None;
+    }
+
+    let end = operations.len() - 1;
+    let mut last_reader: Vec<Option<usize>> = vec![None; operations.len()];
+
+    for (op_index, op) in operations.iter().enumerate() {
+        for &producer in op.input_producers.iter().flatten() {
+            if producer < op_index {
+                last_reader[producer] = Some(op_index);
+            }
+        }
+    }
+
+    let lifetimes = allocations.iter()
+        .zip(last_reader)
+        .enumerate()
+        .map(|(index, (allocation, last_reader))| TensorLifetime {
+            size_bytes: allocation.size_bytes,
+            first_use: index,
+            last_use: last_reader.unwrap_or(end),
+        })
+        .collect();
+
+    Some(lifetimes)
+}
+
+/// Assign offsets so tensors with disjoint lifetimes reuse memory
+///
+/// Tensors are placed largest first; each goes into the smallest gap left
+/// between already-placed tensors whose lifetimes overlap with it.
+pub fn plan_memory(lifetimes: &[TensorLifetime]) -> MemoryPlan {
+    let mut order: Vec<usize> = (0..lifetimes.len()).collect();
+    order.sort_by(|&a, &b| {
+        lifetimes[b].size_bytes.cmp(&lifetimes[a].size_bytes)
+            .then(lifetimes[a].first_use.cmp(&lifetimes[b].first_use))
+    });
+
+    let mut offsets = vec![0; lifetimes.len()];
+    let mut placed: Vec<usize> = Vec::with_capacity(lifetimes.len());
+    let mut peak_bytes = 0;
+
+    for index in order {
+        let lifetime = &lifetimes[index];
+        let size = align_up(lifetime.size_bytes, PLAN_ALIGNMENT);
+
+        // Already-placed tensors that are live at the same time, by offset
+        let mut conflicts: Vec<(usize, usize)> = placed.iter()
+            .filter(|&&other| lifetimes[other].overlaps(lifetime))
+            .map(|&other| (offsets[other], align_up(lifetimes[other].size_bytes, PLAN_ALIGNMENT)))
+            .collect();
+        conflicts.sort_unstable();
+
+        // Best-fit gap between conflicting tensors, else after the last one
+        let mut best: Option<(usize, usize)> = None; // (offset, gap size)
+        let mut gap_start = 0;
+        for &(offset, conflict_size) in &conflicts {
+            if offset >= gap_start {
+                let gap = offset - gap_start;
+                if gap >= size && best.is_none_or(|(_, best_gap)| gap < best_gap) {
+                    best = Some((gap_start, gap));
+                }
+            }
+            gap_start = gap_start.max(offset + conflict_size);
+        }
+
+        let offset = best.map_or(gap_start, |(offset, _)| offset);
+        offsets[index] = offset;
+        peak_bytes = peak_bytes.max(offset + size);
+        placed.push(index);
+    }
+
+    let unplanned_bytes = lifetimes.iter()
+        .map(|lifetime| align_up(lifetime.size_bytes, PLAN_ALIGNMENT))
+        .sum();
+
+    MemoryPlan {
+        offsets,
+        peak_bytes,
+        unplanned_bytes,
+    }
+}
+
+/// Align value up to boundary
+#[inline]
+fn align_up(value: usize, boundary: usize) -> usize {
+    (value + boundary - 1) & !(boundary - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{WasmOperation, WasmDType};
+
+    fn unary_op(producer: Option<usize>, len: usize) -> OperationDesc {
+        OperationDesc {
+            operation: WasmOperation::Exp,
+            input_shapes: vec![vec![len]],
+            input_dtypes: vec![WasmDType::Float32],
+            input_producers: vec![producer],
+            output_shape: vec![len],
+            output_dtype: WasmDType::Float32,
+        }
+    }
+
+    #[test]
+    fn test_chain_lifetimes() {
+        // x -> t0 -> t1 -> t2 -> t3
+        let operations: Vec<_> = (0..4)
+            .map(|i| unary_op(if i == 0 { None } else { Some(i - 1) }, 256))
+            .collect();
+        let allocations = vec![AllocationRequirement::for_tensor(WasmDType::Float32, &[256], true); 4];
+
+        let lifetimes = compute_lifetimes(&operations, &allocations).unwrap();
+        assert_eq!(lifetimes[0], TensorLifetime { size_bytes: 1024, first_use: 0, last_use: 1 });
+        assert_eq!(lifetimes[2], TensorLifetime { size_bytes: 1024, first_use: 2, last_use: 3 });
+        // Final output is live until the end
+        assert_eq!(lifetimes[3].last_use, 3);
+    }
+
+    #[test]
+    fn test_chain_reuses_two_buffers() {
+        let operations: Vec<_> = (0..6)
+            .map(|i| unary_op(if i == 0 { None } else { Some(i - 1) }, 256))
+            .collect();
+        let allocations = vec![AllocationRequirement::for_tensor(WasmDType::Float32, &[256], true); 6];
+
+        let lifetimes = compute_lifetimes(&operations, &allocations).unwrap();
+        let plan = plan_memory(&lifetimes);
+
+        // A chain only ever needs the current input and output
+        assert_eq!(plan.peak_bytes, 2 * 1024);
+        assert_eq!(plan.unplanned_bytes, 6 * 1024);
+
+        // Tensors that are live together never overlap
+        for (i, a) in lifetimes.iter().enumerate() {
+            for (j, b) in lifetimes.iter().enumerate() {
+                if i != j && a.overlaps(b) {
+                    let (start_a, start_b) = (plan.offsets[i], plan.offsets[j]);
+                    assert!(start_a + a.size_bytes <= start_b || start_b + b.size_bytes <= start_a);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_unread_outputs_are_not_reused() {
+        // Two independent ops whose outputs are both sequence outputs
+        let operations = vec![unary_op(None, 4), unary_op(None, 4)];
+        let allocations = vec![AllocationRequirement::for_tensor(WasmDType::Float32, &[4], true); 2];
+
+        let lifetimes = compute_lifetimes(&operations, &allocations).unwrap();
+        let plan = plan_memory(&lifetimes);
+
+        assert_eq!(plan.peak_bytes, 32);
+        assert_ne!(plan.offsets[0], plan.offsets[1]);
+    }
+
+    #[test]
+    fn test_mismatched_pattern_is_not_planned() {
+        let operations = vec![unary_op(None, 4)];
+        assert!(compute_lifetimes(&operations, &[]).is_none());
+    }
+}
@@ -0,0 +1,185 @@
+/*!
+ * DType promotion rules for WebAssembly backend
+ *
+ * Follows `packages/core/src/dtype/promotion.ts` so that kernels produce
+ * the same result dtypes the TypeScript side computes, with one deliberate
+ * divergence: Floor/Ceil/Round keep float inputs float (see
+ * `unary_result_dtype`).
+ */
+
+use crate::types::{WasmDType, WasmOperation};
+
+/// Result dtype of an element-wise unary operation
+///
+/// Matches `computeUnaryResultType`: most ops preserve the input dtype,
+/// while math functions on integer (and bool) inputs produce floats -
+/// Float32 for types of 2 bytes or less, Float64 otherwise. Logical not
+/// always produces Bool.
+///
+/// Unlike `computeUnaryResultType`, which maps float32 -> int32 and other
+/// floats -> int64, Floor/Ceil/Round preserve every input dtype: the float
+/// result keeps NaN, +-inf and values beyond the integer range, and the
+/// TypeScript side allocates the output with the op's own dtype.
+pub fn unary_result_dtype(operation: WasmOperation, input: WasmDType) -> WasmDType {
+    if is_bool_producing(operation) {
+        WasmDType::Bool
+    } else if is_float_producing_unary(operation) && input.is_integer() {
+        if input.byte_size() <= 2 {
+            WasmDType::Float32
+        } else {
+            WasmDType::Float64
+        }
+    } else {
+        input
+    }
+}
+
+/// Result dtype of an element-wise binary operation
+///
+/// Matches `PROMOTION_TABLE`:
+/// - Bool promotes to the other operand's dtype
+/// - Same-signedness integers promote to the wider type
+/// - Mixed-signedness integers promote to a signed type wide enough for
+///   both (e.g. int8 + uint8 -> int16), or Float64 when uint64 is involved
+/// - Float32 with an integer wider than 2 bytes promotes to Float64
+///
+/// Float16/BFloat16 (not in the table) follow the same precision rule: they
+/// absorb 1-byte integers, which they represent exactly, and promote to
+/// Float32 otherwise (Float64 for integers wider than 2 bytes). Mixing the
+/// two half formats gives Float32.
+pub fn binary_result_dtype(a: WasmDType, b: WasmDType) -> WasmDType {
+    use WasmDType::*;
+
+    if a == b || b == Bool {
+        return a;
+    }
+    if a == Bool {
+        return b;
+    }
+
+    match (a.is_float(), b.is_float()) {
+        (true, true) => {
+            if a == Float64 || b == Float64 { Float64 } else { Float32 }
+        }
+        (true, false) | (false, true) => {
+            let (float, int) = if a.is_float() { (a, b) } else { (b, a) };
+            match float {
+                Float16 | BFloat16 if int.byte_size() == 1 => float,
+                Float16 | BFloat16 | Float32 if int.byte_size() <= 2 => Float32,
+                _ => Float64,
+            }
+        }
+        (false, false) if a.is_signed() == b.is_signed() => {
+            if a.byte_size() >= b.byte_size() { a } else { b }
+        }
+        (false, false) => {
+            let (signed, unsigned) = if a.is_signed() { (a, b) } else { (b, a) };
+            if signed.byte_size() > unsigned.byte_size() {
+                signed
+            } else {
+                match unsigned {
+                    Uint8 => Int16,
+                    Uint16 => Int32,
+                    Uint32 => BigInt64,
+                    _ => Float64,
+                }
+            }
+        }
+    }
+}
+
+/// Check if unary operation always produces floating-point results
+pub fn is_float_producing_unary(operation: WasmOperation) -> bool {
+    matches!(operation,
+        WasmOperation::Sqrt | WasmOperation::Exp | WasmOperation::Log |
+        WasmOperation::Sin | WasmOperation::Cos | WasmOperation::Tan |
+        WasmOperation::Tanh | WasmOperation::Sigmoid | WasmOperation::LeakyRelu |
+        WasmOperation::Gelu | WasmOperation::GeluTanh | WasmOperation::Silu |
+        WasmOperation::Erf | WasmOperation::Reciprocal | WasmOperation::Rsqrt |
+        WasmOperation::Log1p | WasmOperation::Expm1
+    )
+}
+
+/// Check if operation is a comparison or logical operation (always Bool output)
+///
+/// Binary ones compute in `binary_result_dtype` of their inputs, then write Bool.
+pub fn is_bool_producing(operation: WasmOperation) -> bool {
+    matches!(operation,
+        WasmOperation::Eq | WasmOperation::Ne | WasmOperation::Lt | WasmOperation::Le |
+        WasmOperation::Gt | WasmOperation::Ge | WasmOperation::LogicalAnd |
+        WasmOperation::LogicalOr | WasmOperation::LogicalXor | WasmOperation::LogicalNot
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unary_result_dtype() {
+        // Type-preserving operations
+        assert_eq!(unary_result_dtype(WasmOperation::Neg, WasmDType::Int8), WasmDType::Int8);
+        assert_eq!(unary_result_dtype(WasmOperation::Abs, WasmDType::BigInt64), WasmDType::BigInt64);
+        assert_eq!(unary_result_dtype(WasmOperation::Square, WasmDType::Uint16), WasmDType::Uint16);
+        assert_eq!(unary_result_dtype(WasmOperation::Exp, WasmDType::Float32), WasmDType::Float32);
+
+        // Math functions promote integers by byte size
+        assert_eq!(unary_result_dtype(WasmOperation::Sqrt, WasmDType::Bool), WasmDType::Float32);
+        assert_eq!(unary_result_dtype(WasmOperation::Exp, WasmDType::Int16), WasmDType::Float32);
+        assert_eq!(unary_result_dtype(WasmOperation::Log, WasmDType::Int32), WasmDType::Float64);
+        assert_eq!(unary_result_dtype(WasmOperation::Sin, WasmDType::BigUint64), WasmDType::Float64);
+        assert_eq!(unary_result_dtype(WasmOperation::Sigmoid, WasmDType::Uint8), WasmDType::Float32);
+        assert_eq!(unary_result_dtype(WasmOperation::Relu, WasmDType::Int32), WasmDType::Int32);
+        assert_eq!(unary_result_dtype(WasmOperation::Round, WasmDType::Int8), WasmDType::Int8);
+
+        // Rounding keeps floats float (core maps them to int32 / int64)
+        for op in [WasmOperation::Floor, WasmOperation::Ceil, WasmOperation::Round] {
+            assert_eq!(unary_result_dtype(op, WasmDType::Float32), WasmDType::Float32);
+            assert_eq!(unary_result_dtype(op, WasmDType::Float64), WasmDType::Float64);
+            assert_eq!(unary_result_dtype(op, WasmDType::Float16), WasmDType::Float16);
+            assert_eq!(unary_result_dtype(op, WasmDType::Int32), WasmDType::Int32);
+        }
+
+        // Logical not is always Bool
+        assert_eq!(unary_result_dtype(WasmOperation::LogicalNot, WasmDType::Float16), WasmDType::Bool);
+    }
+
+    #[test]
+    fn test_binary_result_dtype() {
+        use WasmDType::*;
+
+        let cases = [
+            (Bool, Bool, Bool),
+            (Bool, Int16, Int16),
+            (Uint32, Bool, Uint32),
+            (Int8, Int32, Int32),
+            (Uint8, Uint16, Uint16),
+            (Int8, Uint8, Int16),
+            (Uint16, Int8, Int32),
+            (Int8, Uint32, BigInt64),
+            (Int16, Uint8, Int16),
+            (Int32, Uint32, BigInt64),
+            (BigInt64, Uint32, BigInt64),
+            (Int8, BigUint64, Float64),
+            (BigInt64, BigUint64, Float64),
+            (Uint8, Float32, Float32),
+            (Int16, Float32, Float32),
+            (Int32, Float32, Float64),
+            (Float32, Uint32, Float64),
+            (BigUint64, Float32, Float64),
+            (Uint8, Float64, Float64),
+            (Float32, Float64, Float64),
+            (Float16, Int8, Float16),
+            (Uint8, BFloat16, BFloat16),
+            (Float16, Int16, Float32),
+            (BFloat16, Uint32, Float64),
+            (Float16, BFloat16, Float32),
+            (BFloat16, Float32, Float32),
+            (Float16, Float64, Float64),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(binary_result_dtype(a, b), expected, "{:?} + {:?}", a, b);
+            assert_eq!(binary_result_dtype(b, a), expected, "{:?} + {:?}", b, a);
+        }
+    }
+}
@@ -548,6 +548,138 @@ pub mod float32 {
             output[i] = crate::utils::safe_div_f32(va, vb);
         }
     }
+    
+    /// SIMD-optimized dot product for f32 arrays
+    #[inline]
+    pub fn simd_dot(a: &[f32], b: &[f32]) -> f32 {
+        debug_assert_eq!(a.len(), b.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_dot_impl(a, b)
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            a.iter().zip(b).map(|(&va, &vb)| va * vb).sum()
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_dot_impl(a: &[f32], b: &[f32]) -> f32 {
+        use std::arch::wasm32::*;
+        
+        let chunks = a.len() / 4;
+        let mut acc = f32x4_splat(0.0);
+        
+        for i in 0..chunks {
+            let base_idx = i * 4;
+            
+            unsafe {
+                let va = v128_load(a.as_ptr().add(base_idx) as *const v128);
+                let vb = v128_load(b.as_ptr().add(base_idx) as *const v128);
+                acc = f32x4_add(acc, f32x4_mul(va, vb));
+            }
+        }
+        
+        let mut sum = f32x4_extract_lane::<0>(acc) + f32x4_extract_lane::<1>(acc)
+            + f32x4_extract_lane::<2>(acc) + f32x4_extract_lane::<3>(acc);
+        for (&va, &vb) in a[chunks * 4..].iter().zip(&b[chunks * 4..]) {
+            sum += va * vb;
+        }
+        sum
+    }
+    
+    /// NaN-propagating element-wise maximum (`-0.0 < +0.0`), like `f32x4.max`
+    #[inline]
+    pub fn maximum(a: f32, b: f32) -> f32 {
+        if a > b || (a == b && b.is_sign_negative()) {
+            a
+        } else if b > a || a == b {
+            b
+        } else {
+            a + b // At least one NaN
+        }
+    }
+    
+    /// NaN-propagating element-wise minimum (`-0.0 < +0.0`), like `f32x4.min`
+    #[inline]
+    pub fn minimum(a: f32, b: f32) -> f32 {
+        if a < b || (a == b && a.is_sign_negative()) {
+            a
+        } else if b < a || a == b {
+            b
+        } else {
+            a + b // At least one NaN
+        }
+    }
+    
+    /// SIMD-optimized element-wise maximum for f32 arrays (see `maximum`)
+    #[inline]
+    pub fn simd_maximum(a: &[f32], b: &[f32], output: &mut [f32]) {
+        debug_assert_eq!(a.len(), b.len());
+        debug_assert_eq!(a.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_extremum_impl(a, b, output, std::arch::wasm32::f32x4_max, maximum);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for ((out, &va), &vb) in output.iter_mut().zip(a).zip(b) {
+                *out = maximum(va, vb);
+            }
+        }
+    }
+    
+    /// SIMD-optimized element-wise minimum for f32 arrays (see `minimum`)
+    #[inline]
+    pub fn simd_minimum(a: &[f32], b: &[f32], output: &mut [f32]) {
+        debug_assert_eq!(a.len(), b.len());
+        debug_assert_eq!(a.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_extremum_impl(a, b, output, std::arch::wasm32::f32x4_min, minimum);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for ((out, &va), &vb) in output.iter_mut().zip(a).zip(b) {
+                *out = minimum(va, vb);
+            }
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_extremum_impl(
+        a: &[f32],
+        b: &[f32],
+        output: &mut [f32],
+        lanes: fn(std::arch::wasm32::v128, std::arch::wasm32::v128) -> std::arch::wasm32::v128,
+        scalar: fn(f32, f32) -> f32,
+    ) {
+        use std::arch::wasm32::*;
+        
+        let chunks = a.len() / 4;
+        for i in 0..chunks {
+            let base_idx = i * 4;
+            
+            unsafe {
+                let va = v128_load(a.as_ptr().add(base_idx) as *const v128);
+                let vb = v128_load(b.as_ptr().add(base_idx) as *const v128);
+                v128_store(output.as_mut_ptr().add(base_idx) as *mut v128, lanes(va, vb));
+            }
+        }
+        
+        let start_idx = chunks * 4;
+        for ((out, &va), &vb) in output[start_idx..].iter_mut().zip(&a[start_idx..]).zip(&b[start_idx..]) {
+            *out = scalar(va, vb);
+        }
+    }
 }
 
 /// SIMD-optimized operations for f64 arrays (processes 2 elements at a time)
@@ -719,4 +851,566 @@ pub mod float64 {
             output[i] = val.sqrt();
         }
     }
-}
\ No newline at end of file
+    
+    /// NaN-propagating element-wise maximum (`-0.0 < +0.0`), like `f64x2.max`
+    #[inline]
+    pub fn maximum(a: f64, b: f64) -> f64 {
+        if a > b || (a == b && b.is_sign_negative()) {
+            a
+        } else if b > a || a == b {
+            b
+        } else {
+            a + b // At least one NaN
+        }
+    }
+    
+    /// NaN-propagating element-wise minimum (`-0.0 < +0.0`), like `f64x2.min`
+    #[inline]
+    pub fn minimum(a: f64, b: f64) -> f64 {
+        if a < b || (a == b && a.is_sign_negative()) {
+            a
+        } else if b < a || a == b {
+            b
+        } else {
+            a + b // At least one NaN
+        }
+    }
+    
+    /// SIMD-optimized element-wise maximum for f64 arrays (see `maximum`)
+    #[inline]
+    pub fn simd_maximum(a: &[f64], b: &[f64], output: &mut [f64]) {
+        debug_assert_eq!(a.len(), b.len());
+        debug_assert_eq!(a.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_extremum_impl(a, b, output, std::arch::wasm32::f64x2_max, maximum);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for ((out, &va), &vb) in output.iter_mut().zip(a).zip(b) {
+                *out = maximum(va, vb);
+            }
+        }
+    }
+    
+    /// SIMD-optimized element-wise minimum for f64 arrays (see `minimum`)
+    #[inline]
+    pub fn simd_minimum(a: &[f64], b: &[f64], output: &mut [f64]) {
+        debug_assert_eq!(a.len(), b.len());
+        debug_assert_eq!(a.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_extremum_impl(a, b, output, std::arch::wasm32::f64x2_min, minimum);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for ((out, &va), &vb) in output.iter_mut().zip(a).zip(b) {
+                *out = minimum(va, vb);
+            }
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_extremum_impl(
+        a: &[f64],
+        b: &[f64],
+        output: &mut [f64],
+        lanes: fn(std::arch::wasm32::v128, std::arch::wasm32::v128) -> std::arch::wasm32::v128,
+        scalar: fn(f64, f64) -> f64,
+    ) {
+        use std::arch::wasm32::*;
+        
+        let chunks = a.len() / 2;
+        for i in 0..chunks {
+            let base_idx = i * 2;
+            
+            unsafe {
+                let va = v128_load(a.as_ptr().add(base_idx) as *const v128);
+                let vb = v128_load(b.as_ptr().add(base_idx) as *const v128);
+                v128_store(output.as_mut_ptr().add(base_idx) as *mut v128, lanes(va, vb));
+            }
+        }
+        
+        let start_idx = chunks * 2;
+        for ((out, &va), &vb) in output[start_idx..].iter_mut().zip(&a[start_idx..]).zip(&b[start_idx..]) {
+            *out = scalar(va, vb);
+        }
+    }
+}
+/// SIMD-optimized dtype conversions for the common cast paths
+///
+/// Each kernel matches the scalar `as` conversion exactly: float -> int
+/// conversions saturate (NaN becomes 0), all others round to nearest.
+pub mod convert {
+    use crate::types::WasmRoundingMode;
+    
+    /// Round f32 values and convert to i32 with saturation
+    #[inline]
+    pub fn f32_to_i32(input: &[f32], output: &mut [i32], rounding: WasmRoundingMode) {
+        debug_assert_eq!(input.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_f32_to_i32_impl(input, output, rounding);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = rounding.apply(val as f64) as i32;
+            }
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_f32_to_i32_impl(input: &[f32], output: &mut [i32], rounding: WasmRoundingMode) {
+        use std::arch::wasm32::*;
+        
+        let chunks = input.len() / 4;
+        
+        for i in 0..chunks {
+            let base_idx = i * 4;
+            
+            unsafe {
+                let a = v128_load(input.as_ptr().add(base_idx) as *const v128);
+                
+                // Round to an integral value first; the conversion itself truncates
+                let rounded = match rounding {
+                    WasmRoundingMode::Truncate => a,
+                    WasmRoundingMode::Nearest => f32x4_nearest(a),
+                    WasmRoundingMode::Floor => f32x4_floor(a),
+                    WasmRoundingMode::Ceil => f32x4_ceil(a),
+                };
+                
+                v128_store(output.as_mut_ptr().add(base_idx) as *mut v128, i32x4_trunc_sat_f32x4(rounded));
+            }
+        }
+        
+        for (out, &val) in output[chunks * 4..].iter_mut().zip(&input[chunks * 4..]) {
+            *out = rounding.apply(val as f64) as i32;
+        }
+    }
+    
+    /// Convert i32 values to f32
+    #[inline]
+    pub fn i32_to_f32(input: &[i32], output: &mut [f32]) {
+        debug_assert_eq!(input.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_i32_to_f32_impl(input, output);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = val as f32;
+            }
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_i32_to_f32_impl(input: &[i32], output: &mut [f32]) {
+        use std::arch::wasm32::*;
+        
+        let chunks = input.len() / 4;
+        
+        for i in 0..chunks {
+            let base_idx = i * 4;
+            
+            unsafe {
+                let a = v128_load(input.as_ptr().add(base_idx) as *const v128);
+                v128_store(output.as_mut_ptr().add(base_idx) as *mut v128, f32x4_convert_i32x4(a));
+            }
+        }
+        
+        for (out, &val) in output[chunks * 4..].iter_mut().zip(&input[chunks * 4..]) {
+            *out = val as f32;
+        }
+    }
+    
+    /// Widen f32 values to f64
+    #[inline]
+    pub fn f32_to_f64(input: &[f32], output: &mut [f64]) {
+        debug_assert_eq!(input.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_f32_to_f64_impl(input, output);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = val as f64;
+            }
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_f32_to_f64_impl(input: &[f32], output: &mut [f64]) {
+        use std::arch::wasm32::*;
+        
+        let chunks = input.len() / 4;
+        
+        for i in 0..chunks {
+            let base_idx = i * 4;
+            
+            unsafe {
+                let a = v128_load(input.as_ptr().add(base_idx) as *const v128);
+                
+                // Promote lanes 0-1, then move lanes 2-3 down and promote those
+                let low = f64x2_promote_low_f32x4(a);
+                let high = f64x2_promote_low_f32x4(i64x2_shuffle::<1, 1>(a, a));
+                
+                v128_store(output.as_mut_ptr().add(base_idx) as *mut v128, low);
+                v128_store(output.as_mut_ptr().add(base_idx + 2) as *mut v128, high);
+            }
+        }
+        
+        for (out, &val) in output[chunks * 4..].iter_mut().zip(&input[chunks * 4..]) {
+            *out = val as f64;
+        }
+    }
+    
+    /// Narrow f64 values to f32 (round to nearest)
+    #[inline]
+    pub fn f64_to_f32(input: &[f64], output: &mut [f32]) {
+        debug_assert_eq!(input.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_f64_to_f32_impl(input, output);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = val as f32;
+            }
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_f64_to_f32_impl(input: &[f64], output: &mut [f32]) {
+        use std::arch::wasm32::*;
+        
+        let chunks = input.len() / 4;
+        
+        for i in 0..chunks {
+            let base_idx = i * 4;
+            
+            unsafe {
+                let a = v128_load(input.as_ptr().add(base_idx) as *const v128);
+                let b = v128_load(input.as_ptr().add(base_idx + 2) as *const v128);
+                
+                // Each demotion fills lanes 0-1; combine both halves into one vector
+                let low = f32x4_demote_f64x2_zero(a);
+                let high = f32x4_demote_f64x2_zero(b);
+                
+                v128_store(output.as_mut_ptr().add(base_idx) as *mut v128, i64x2_shuffle::<0, 2>(low, high));
+            }
+        }
+        
+        for (out, &val) in output[chunks * 4..].iter_mut().zip(&input[chunks * 4..]) {
+            *out = val as f32;
+        }
+    }
+    
+    /// Convert u8 values to f32 (exact)
+    #[inline]
+    pub fn u8_to_f32(input: &[u8], output: &mut [f32]) {
+        debug_assert_eq!(input.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_u8_to_f32_impl(input, output);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = val as f32;
+            }
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_u8_to_f32_impl(input: &[u8], output: &mut [f32]) {
+        use std::arch::wasm32::*;
+        
+        let chunks = input.len() / 16;
+        
+        for i in 0..chunks {
+            let base_idx = i * 16;
+            
+            unsafe {
+                let bytes = v128_load(input.as_ptr().add(base_idx) as *const v128);
+                
+                // u8x16 -> 2 x u16x8 -> 4 x u32x4 -> 4 x f32x4
+                let low = u16x8_extend_low_u8x16(bytes);
+                let high = u16x8_extend_high_u8x16(bytes);
+                let quarters = [
+                    u32x4_extend_low_u16x8(low),
+                    u32x4_extend_high_u16x8(low),
+                    u32x4_extend_low_u16x8(high),
+                    u32x4_extend_high_u16x8(high),
+                ];
+                
+                for (q, &quarter) in quarters.iter().enumerate() {
+                    v128_store(output.as_mut_ptr().add(base_idx + q * 4) as *mut v128, f32x4_convert_u32x4(quarter));
+                }
+            }
+        }
+        
+        for (out, &val) in output[chunks * 16..].iter_mut().zip(&input[chunks * 16..]) {
+            *out = val as f32;
+        }
+    }
+    
+    /// Widen bfloat16 bit patterns to f32 (exact: bf16 is the upper half of an f32)
+    #[inline]
+    pub fn bf16_to_f32(input: &[u16], output: &mut [f32]) {
+        debug_assert_eq!(input.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_bf16_to_f32_impl(input, output);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            for (out, &val) in output.iter_mut().zip(input) {
+                *out = f32::from_bits((val as u32) << 16);
+            }
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_bf16_to_f32_impl(input: &[u16], output: &mut [f32]) {
+        use std::arch::wasm32::*;
+        
+        let chunks = input.len() / 8;
+        
+        for i in 0..chunks {
+            let base_idx = i * 8;
+            
+            unsafe {
+                let halves = v128_load(input.as_ptr().add(base_idx) as *const v128);
+                
+                // Zero-extend each u16 to u32, then move it into the upper half
+                let low = i32x4_shl(u32x4_extend_low_u16x8(halves), 16);
+                let high = i32x4_shl(u32x4_extend_high_u16x8(halves), 16);
+                
+                v128_store(output.as_mut_ptr().add(base_idx) as *mut v128, low);
+                v128_store(output.as_mut_ptr().add(base_idx + 4) as *mut v128, high);
+            }
+        }
+        
+        for (out, &val) in output[chunks * 8..].iter_mut().zip(&input[chunks * 8..]) {
+            *out = f32::from_bits((val as u32) << 16);
+        }
+    }
+}
+
+/// SIMD-optimized element-wise comparisons producing 0/1 bytes
+///
+/// Lane masks from four f32x4/i32x4 compares are narrowed to one u8x16 of
+/// 0/1 values, so each iteration writes 16 Bool elements.
+pub mod compare {
+    /// Element-wise comparison predicate
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Comparison {
+        Eq,
+        Ne,
+        Lt,
+        Le,
+        Gt,
+        Ge,
+    }
+    
+    impl Comparison {
+        /// Apply to a pair of scalars (IEEE semantics: NaN only satisfies `Ne`)
+        #[inline]
+        pub fn apply<T: PartialOrd>(self, a: T, b: T) -> bool {
+            match self {
+                Comparison::Eq => a == b,
+                Comparison::Ne => a != b,
+                Comparison::Lt => a < b,
+                Comparison::Le => a <= b,
+                Comparison::Gt => a > b,
+                Comparison::Ge => a >= b,
+            }
+        }
+    }
+    
+    /// Compare two arrays element-wise, writing 1 where the predicate holds and 0 elsewhere
+    #[inline]
+    pub fn scalar_compare<T: PartialOrd + Copy>(comparison: Comparison, a: &[T], b: &[T], output: &mut [u8]) {
+        debug_assert_eq!(a.len(), b.len());
+        debug_assert_eq!(a.len(), output.len());
+        
+        for ((out, &x), &y) in output.iter_mut().zip(a).zip(b) {
+            *out = comparison.apply(x, y) as u8;
+        }
+    }
+    
+    /// Compare f32 arrays element-wise into 0/1 bytes
+    #[inline]
+    pub fn f32_compare(comparison: Comparison, a: &[f32], b: &[f32], output: &mut [u8]) {
+        debug_assert_eq!(a.len(), b.len());
+        debug_assert_eq!(a.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            use std::arch::wasm32::*;
+            let lanes = match comparison {
+                Comparison::Eq => f32x4_eq,
+                Comparison::Ne => f32x4_ne,
+                Comparison::Lt => f32x4_lt,
+                Comparison::Le => f32x4_le,
+                Comparison::Gt => f32x4_gt,
+                Comparison::Ge => f32x4_ge,
+            };
+            simd_compare_impl(comparison, a, b, output, lanes);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            scalar_compare(comparison, a, b, output);
+        }
+    }
+    
+    /// Compare i32 arrays element-wise into 0/1 bytes
+    #[inline]
+    pub fn i32_compare(comparison: Comparison, a: &[i32], b: &[i32], output: &mut [u8]) {
+        debug_assert_eq!(a.len(), b.len());
+        debug_assert_eq!(a.len(), output.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            use std::arch::wasm32::*;
+            let lanes = match comparison {
+                Comparison::Eq => i32x4_eq,
+                Comparison::Ne => i32x4_ne,
+                Comparison::Lt => i32x4_lt,
+                Comparison::Le => i32x4_le,
+                Comparison::Gt => i32x4_gt,
+                Comparison::Ge => i32x4_ge,
+            };
+            simd_compare_impl(comparison, a, b, output, lanes);
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            scalar_compare(comparison, a, b, output);
+        }
+    }
+    
+    /// Lane-wise compare producing all-ones/all-zeros 32-bit masks
+    #[cfg(target_feature = "simd128")]
+    type LaneCompare = fn(std::arch::wasm32::v128, std::arch::wasm32::v128) -> std::arch::wasm32::v128;
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_compare_impl<T: PartialOrd + Copy>(
+        comparison: Comparison,
+        a: &[T],
+        b: &[T],
+        output: &mut [u8],
+        lanes: LaneCompare,
+    ) {
+        use std::arch::wasm32::*;
+        
+        // 4-byte lanes only: 16 elements are four vectors
+        debug_assert_eq!(std::mem::size_of::<T>(), 4);
+        let chunks = a.len() / 16;
+        let one = u8x16_splat(1);
+        
+        for i in 0..chunks {
+            let base_idx = i * 16;
+            
+            unsafe {
+                let mut masks = [i32x4_splat(0); 4];
+                for (q, mask) in masks.iter_mut().enumerate() {
+                    let va = v128_load(a.as_ptr().add(base_idx + q * 4) as *const v128);
+                    let vb = v128_load(b.as_ptr().add(base_idx + q * 4) as *const v128);
+                    *mask = lanes(va, vb);
+                }
+                
+                // All-ones lanes are -1, which signed saturating narrows keep as -1
+                let low = i16x8_narrow_i32x4(masks[0], masks[1]);
+                let high = i16x8_narrow_i32x4(masks[2], masks[3]);
+                let bytes = v128_and(i8x16_narrow_i16x8(low, high), one);
+                v128_store(output.as_mut_ptr().add(base_idx) as *mut v128, bytes);
+            }
+        }
+        
+        let tail = chunks * 16;
+        scalar_compare(comparison, &a[tail..], &b[tail..], &mut output[tail..]);
+    }
+}
+
+/// SIMD-optimized integer dot products for quantized matmul
+pub mod int8 {
+    /// Dot product of two i16 vectors with wrapping i32 accumulation
+    ///
+    /// Inputs hold widened int8 values, so each pairwise product sum fits
+    /// comfortably in an i32 lane.
+    #[inline]
+    pub fn dot_i16(a: &[i16], b: &[i16]) -> i32 {
+        debug_assert_eq!(a.len(), b.len());
+        
+        #[cfg(target_feature = "simd128")]
+        {
+            simd_dot_i16_impl(a, b)
+        }
+        
+        #[cfg(not(target_feature = "simd128"))]
+        {
+            a.iter().zip(b).fold(0i32, |acc, (&x, &y)| acc.wrapping_add(x as i32 * y as i32))
+        }
+    }
+    
+    #[cfg(target_feature = "simd128")]
+    #[inline]
+    fn simd_dot_i16_impl(a: &[i16], b: &[i16]) -> i32 {
+        use std::arch::wasm32::*;
+        
+        let chunks = a.len() / 8;
+        let mut acc = i32x4_splat(0);
+        
+        for i in 0..chunks {
+            let base_idx = i * 8;
+            
+            unsafe {
+                let va = v128_load(a.as_ptr().add(base_idx) as *const v128);
+                let vb = v128_load(b.as_ptr().add(base_idx) as *const v128);
+                
+                // Multiply 8 i16 pairs and add adjacent products into 4 i32 lanes
+                acc = i32x4_add(acc, i32x4_dot_i16x8(va, vb));
+            }
+        }
+        
+        let mut sum = i32x4_extract_lane::<0>(acc)
+            .wrapping_add(i32x4_extract_lane::<1>(acc))
+            .wrapping_add(i32x4_extract_lane::<2>(acc))
+            .wrapping_add(i32x4_extract_lane::<3>(acc));
+        
+        for (&x, &y) in a[chunks * 8..].iter().zip(&b[chunks * 8..]) {
+            sum = sum.wrapping_add(x as i32 * y as i32);
+        }
+        sum
+    }
+}
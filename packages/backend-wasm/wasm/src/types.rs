@@ -23,6 +23,8 @@ pub enum WasmDType {
     Float64 = 8,
     BigInt64 = 9,
     BigUint64 = 10,
+    Float16 = 11,  // IEEE 754 half precision, computed in f32
+    BFloat16 = 12, // Upper 16 bits of an f32, computed in f32
 }
 
 impl WasmDType {
@@ -30,7 +32,7 @@ impl WasmDType {
     pub fn byte_size(&self) -> usize {
         match self {
             WasmDType::Bool | WasmDType::Int8 | WasmDType::Uint8 => 1,
-            WasmDType::Int16 | WasmDType::Uint16 => 2,
+            WasmDType::Int16 | WasmDType::Uint16 | WasmDType::Float16 | WasmDType::BFloat16 => 2,
             WasmDType::Int32 | WasmDType::Uint32 | WasmDType::Float32 => 4,
             WasmDType::Float64 | WasmDType::BigInt64 | WasmDType::BigUint64 => 8,
         }
@@ -38,7 +40,12 @@ impl WasmDType {
 
     /// Check if this is a floating-point type
     pub fn is_float(&self) -> bool {
-        matches!(self, WasmDType::Float32 | WasmDType::Float64)
+        matches!(self, WasmDType::Float16 | WasmDType::BFloat16 | WasmDType::Float32 | WasmDType::Float64)
+    }
+
+    /// Check if this is a half-precision storage type
+    pub fn is_half(&self) -> bool {
+        matches!(self, WasmDType::Float16 | WasmDType::BFloat16)
     }
 
     /// Check if this is an integer type
@@ -48,7 +55,7 @@ impl WasmDType {
 
     /// Check if this is a signed type
     pub fn is_signed(&self) -> bool {
-        matches!(self, WasmDType::Int8 | WasmDType::Int16 | WasmDType::Int32 | WasmDType::BigInt64 | WasmDType::Float32 | WasmDType::Float64)
+        matches!(self, WasmDType::Int8 | WasmDType::Int16 | WasmDType::Int32 | WasmDType::BigInt64 | WasmDType::Float16 | WasmDType::BFloat16 | WasmDType::Float32 | WasmDType::Float64)
     }
 
     /// Check if this is a BigInt type
@@ -95,6 +102,9 @@ pub enum WasmOperation {
     
     // Matrix operations
     Matmul = 30,
+    QuantizedMatmul = 31,
+    Q4Matmul = 32,
+    QuantizeQ4 = 33,
     
     // Activation functions
     Softmax = 40,
@@ -106,10 +116,125 @@ pub enum WasmOperation {
     Max = 52,
     Min = 53,
     Prod = 54,
+    ArgMax = 55,
+    ArgMin = 56,
     
     // Einops operations
     Rearrange = 60,
     Reduce = 61,
+    
+    // Fused element-wise program
+    Fused = 70,
+    
+    // Type conversion
+    Cast = 80,
+    
+    // Comparison operations (Bool output)
+    Eq = 90,
+    Ne = 91,
+    Lt = 92,
+    Le = 93,
+    Gt = 94,
+    Ge = 95,
+    
+    // Logical operations (Bool output, nonzero is true)
+    LogicalAnd = 96,
+    LogicalOr = 97,
+    LogicalXor = 98,
+    LogicalNot = 99,
+    
+    // Selection operations
+    Where = 100,
+    
+    // Activations, rounding and extended math (unary)
+    Tan = 110,
+    Tanh = 111,
+    Sigmoid = 112,
+    Relu = 113,
+    LeakyRelu = 114,
+    Gelu = 115,
+    GeluTanh = 116,
+    Silu = 117,
+    Erf = 118,
+    Reciprocal = 119,
+    Rsqrt = 120,
+    Floor = 121,
+    Ceil = 122,
+    Round = 123,
+    Sign = 124,
+    Log1p = 125,
+    Expm1 = 126,
+    
+    // Extended binary operations
+    Pow = 130,
+    Maximum = 131,
+    Minimum = 132,
+    Mod = 133,
+    
+    // Statistical reductions
+    Var = 140,
+    Std = 141,
+    NormL1 = 142,
+    NormL2 = 143,
+    NormLinf = 144,
+    LogSumExp = 145,
+    
+    // Cumulative scans
+    CumSum = 150,
+    CumProd = 151,
+    CumMax = 152,
+    CumMin = 153,
+    
+    // Boolean reductions
+    Any = 160,
+    All = 161,
+    CountNonzero = 162,
+}
+
+/// Rounding applied when casting floats to integer dtypes
+#[wasm_bindgen]
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WasmRoundingMode {
+    /// Round toward zero (JS typed-array semantics)
+    #[default]
+    Truncate = 0,
+    /// Round to nearest, ties to even
+    Nearest = 1,
+    /// Round toward negative infinity
+    Floor = 2,
+    /// Round toward positive infinity
+    Ceil = 3,
+}
+
+impl WasmRoundingMode {
+    /// Round value to an integral float
+    #[inline]
+    pub fn apply(self, value: f64) -> f64 {
+        match self {
+            WasmRoundingMode::Truncate => value.trunc(),
+            WasmRoundingMode::Nearest => value.round_ties_even(),
+            WasmRoundingMode::Floor => value.floor(),
+            WasmRoundingMode::Ceil => value.ceil(),
+        }
+    }
+}
+
+/// Accuracy of transcendental f32 functions (exp, log, sin, cos, activations, softmax)
+///
+/// Only Float32 computation is affected (which includes Float16/BFloat16 and
+/// small integers promoted to Float32); Float64 always uses accurate math.
+/// Fast approximations are opt-in; error bounds per function are listed in
+/// `fast_math`.
+#[wasm_bindgen]
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WasmPrecision {
+    /// `fast_math` polynomial approximations with f32x4 kernels
+    Fast = 0,
+    /// libm, within 1 ULP (f32 `expf` / `logf`, f64 evaluation rounded to f32 otherwise)
+    #[default]
+    Accurate = 1,
 }
 
 /// Memory layout flags for tensors
@@ -244,12 +369,16 @@ mod tests {
         assert_eq!(WasmDType::Float64.byte_size(), 8);
         assert_eq!(WasmDType::BigInt64.byte_size(), 8);
         assert_eq!(WasmDType::BigUint64.byte_size(), 8);
+        assert_eq!(WasmDType::Float16.byte_size(), 2);
+        assert_eq!(WasmDType::BFloat16.byte_size(), 2);
     }
 
     #[test]
     fn test_dtype_properties() {
         assert!(WasmDType::Float32.is_float());
         assert!(!WasmDType::Int32.is_float());
+        assert!(WasmDType::BFloat16.is_float() && WasmDType::BFloat16.is_half());
+        assert!(!WasmDType::Float32.is_half());
         assert!(WasmDType::Int32.is_signed());
         assert!(!WasmDType::Uint32.is_signed());
         assert!(WasmDType::BigInt64.is_bigint());